@@ -149,6 +149,12 @@ impl Application {
                     app.quit();
                 })
                 .build(),
+            // Registry accounts
+            gio::ActionEntry::builder("registry-accounts")
+                .activate(|app: &Self, _, _| {
+                    view::RegistryAccountsDialog::default().present(Some(&app.main_window()));
+                })
+                .build(),
             // About
             gio::ActionEntry::builder("about")
                 .activate(|app: &Self, _, _| {
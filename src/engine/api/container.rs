@@ -4,6 +4,8 @@ use std::time::Duration;
 use bytes::Bytes;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use futures::future;
+use futures::stream;
 use futures::stream::BoxStream;
 use http_body_util::Either;
 use http_body_util::Full;
@@ -13,19 +15,73 @@ use crate::engine::dto::ContainerInspection;
 
 pub(crate) enum Container {
     Docker { docker: bollard::Docker, id: String },
-    Podman(podman_api::api::Container),
+    Podman(podman_api::api::Container, engine::libpod::Libpod),
 }
 
 impl Container {
     pub(crate) fn id(&self) -> &str {
         match &self {
             Self::Docker { id, .. } => id,
-            Self::Podman(container) => container.id().as_ref(),
+            Self::Podman(container, _) => container.id().as_ref(),
         }
     }
 }
 
 impl Container {
+    pub(crate) async fn changes(&self) -> anyhow::Result<Vec<engine::dto::ContainerChange>> {
+        match self {
+            Self::Docker { docker, id } => docker
+                .container_changes(id)
+                .await
+                .map_err(anyhow::Error::from)
+                .map(|changes| {
+                    changes
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(Into::into)
+                        .collect()
+                }),
+            Self::Podman(container, _) => container
+                .changes(&Default::default())
+                .await
+                .map_err(anyhow::Error::from)
+                .map(|changes| changes.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    pub(crate) async fn checkpoint(
+        &self,
+        opts: engine::opts::ContainerCheckpointOpts,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Docker { .. } => {
+                anyhow::bail!("checkpoints are not supported by the Docker API")
+            }
+            Self::Podman(container, _) => container
+                .checkpoint(&opts.into())
+                .await
+                .map_err(anyhow::Error::from)
+                .map(|_| ()),
+        }
+    }
+
+    pub(crate) fn checkpoint_export(
+        &self,
+        opts: engine::opts::ContainerCheckpointOpts,
+    ) -> BoxStream<'_, anyhow::Result<Bytes>> {
+        match self {
+            Self::Docker { .. } => stream::once(future::ready(Err(anyhow::anyhow!(
+                "checkpoints are not supported by the Docker API"
+            ))))
+            .boxed(),
+            Self::Podman(container, _) => container
+                .checkpoint_export(&opts.into())
+                .map_err(anyhow::Error::from)
+                .map_ok(Into::into)
+                .boxed(),
+        }
+    }
+
     pub(crate) async fn commit(
         &self,
         opts: engine::opts::ContainerCommitOpts,
@@ -42,7 +98,7 @@ impl Container {
                 .await
                 .map_err(anyhow::Error::from)
                 .map(|_| ()),
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .commit(&opts.into())
                 .await
                 .map_err(anyhow::Error::from),
@@ -59,7 +115,7 @@ impl Container {
                 .map_err(anyhow::Error::from)
                 .boxed(),
 
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .copy_from(path)
                 .map_err(anyhow::Error::from)
                 .map_ok(Into::into)
@@ -81,13 +137,39 @@ impl Container {
                 .await
                 .map_err(anyhow::Error::from),
 
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .copy_to(path, buf.into())
                 .await
                 .map_err(anyhow::Error::from),
         }
     }
 
+    /// Like [`Self::copy_to`], but the archive is streamed in chunks.
+    pub(crate) async fn copy_to_stream(
+        &self,
+        path: String,
+        body: BoxStream<'static, std::io::Result<Bytes>>,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Docker { docker, id } => docker
+                .upload_to_container(
+                    id,
+                    Some(bollard::query_parameters::UploadToContainerOptions {
+                        path,
+                        ..Default::default()
+                    }),
+                    bollard::body_try_stream(body),
+                )
+                .await
+                .map_err(anyhow::Error::from),
+
+            Self::Podman(container, _) => container
+                .copy_to(path, containers_api::conn::hyper::Body::wrap_stream(body))
+                .await
+                .map_err(anyhow::Error::from),
+        }
+    }
+
     pub(crate) async fn create_exec(
         &self,
         opts: engine::opts::ExecCreateOpts,
@@ -102,7 +184,7 @@ impl Container {
                     id: res.id,
                 }),
 
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .create_exec(&opts.into())
                 .await
                 .map_err(anyhow::Error::from)
@@ -110,24 +192,62 @@ impl Container {
         }
     }
 
+    pub(crate) fn export(&self) -> BoxStream<'_, anyhow::Result<Bytes>> {
+        match self {
+            Self::Docker { docker, id } => docker
+                .export_container(id)
+                .map_err(anyhow::Error::from)
+                .boxed(),
+
+            Self::Podman(container, _) => container
+                .export()
+                .map_err(anyhow::Error::from)
+                .map_ok(Into::into)
+                .boxed(),
+        }
+    }
+
     pub(crate) async fn generate_kube_yaml(&self, service: bool) -> anyhow::Result<String> {
         match self {
             Self::Docker { .. } => {
                 anyhow::bail!("kube generation is not supported by the Docker API")
             }
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .generate_kube_yaml(service)
                 .await
                 .map_err(anyhow::Error::from),
         }
     }
 
+    pub(crate) async fn generate_systemd_units(
+        &self,
+    ) -> anyhow::Result<Vec<engine::dto::UnitFile>> {
+        match self {
+            Self::Docker { .. } => {
+                anyhow::bail!("systemd generation is not supported by the Docker API")
+            }
+            Self::Podman(container, _) => {
+                let units = container
+                    .generate_systemd_units(
+                        &podman_api::opts::SystemdUnitsOpts::builder()
+                            .use_name(true)
+                            .build(),
+                    )
+                    .await?;
+
+                Ok(engine::quadlet::systemd_unit_files(serde_json::from_value(
+                    units,
+                )?))
+            }
+        }
+    }
+
     pub(crate) async fn healthcheck(&self) -> anyhow::Result<()> {
         match self {
             Self::Docker { .. } => {
                 anyhow::bail!("manual health checks are not supported by the Docker API")
             }
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .healthcheck()
                 .await
                 .map_err(anyhow::Error::from)
@@ -159,7 +279,7 @@ impl Container {
                     .map_err(anyhow::Error::from)
                     .map(|inspection| ContainerInspection::from_docker(summary.image, inspection))
             }
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .inspect()
                 .await
                 .map_err(anyhow::Error::from)
@@ -180,7 +300,7 @@ impl Container {
                     serde_json::to_string_pretty(&response).map_err(anyhow::Error::from)
                 }),
 
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .inspect()
                 .await
                 .map_err(anyhow::Error::from)
@@ -196,7 +316,7 @@ impl Container {
                 .kill_container(id, None)
                 .await
                 .map_err(anyhow::Error::from),
-            Self::Podman(container) => container.kill().await.map_err(anyhow::Error::from),
+            Self::Podman(container, _) => container.kill().await.map_err(anyhow::Error::from),
         }
     }
 
@@ -210,7 +330,7 @@ impl Container {
                 .map_err(anyhow::Error::from)
                 .map_ok(Into::into)
                 .boxed(),
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .logs(&opts.into())
                 .map_err(anyhow::Error::from)
                 .map_ok(Into::into)
@@ -224,7 +344,7 @@ impl Container {
                 .pause_container(id)
                 .await
                 .map_err(anyhow::Error::from),
-            Self::Podman(container) => container.pause().await.map_err(anyhow::Error::from),
+            Self::Podman(container, _) => container.pause().await.map_err(anyhow::Error::from),
         }
     }
 
@@ -241,7 +361,7 @@ impl Container {
                 .await
                 .map_err(anyhow::Error::from),
 
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .delete(
                     &podman_api::opts::ContainerDeleteOpts::builder()
                         .force(force)
@@ -258,7 +378,7 @@ impl Container {
                 .start_container(id, None)
                 .await
                 .map_err(anyhow::Error::from),
-            Self::Podman(container) => container.start(None).await.map_err(anyhow::Error::from),
+            Self::Podman(container, _) => container.start(None).await.map_err(anyhow::Error::from),
         }
     }
 
@@ -268,7 +388,7 @@ impl Container {
                 .stop_container(id, None)
                 .await
                 .map_err(anyhow::Error::from),
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .stop(&Default::default())
                 .await
                 .map_err(anyhow::Error::from),
@@ -299,7 +419,7 @@ impl Container {
             .map_err(anyhow::Error::from)
             .map_ok(Into::into)
             .boxed(),
-            Self::Podman(container) => container
+            Self::Podman(container, _) => container
                 .top_stream(
                     &podman_api::opts::ContainerTopOpts::builder()
                         .delay(delay)
@@ -323,7 +443,7 @@ impl Container {
                 )
                 .await
                 .map_err(anyhow::Error::from),
-            Self::Podman(container) => container.rename(name).await.map_err(anyhow::Error::from),
+            Self::Podman(container, _) => container.rename(name).await.map_err(anyhow::Error::from),
         }
     }
 
@@ -340,7 +460,7 @@ impl Container {
                 .await
                 .map_err(anyhow::Error::from),
 
-            Self::Podman(container) => if force {
+            Self::Podman(container, _) => if force {
                 container.restart().await
             } else {
                 container.restart_with_timeout(0).await
@@ -355,7 +475,35 @@ impl Container {
                 .unpause_container(id)
                 .await
                 .map_err(anyhow::Error::from),
-            Self::Podman(container) => container.unpause().await.map_err(anyhow::Error::from),
+            Self::Podman(container, _) => container.unpause().await.map_err(anyhow::Error::from),
+        }
+    }
+
+    pub(crate) async fn update(
+        &self,
+        opts: engine::opts::ContainerUpdateOpts,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Docker { docker, id } => docker
+                .update_container(id, opts.into())
+                .await
+                .map_err(anyhow::Error::from),
+            // podman-api has no means to update a container.
+            Self::Podman(container, libpod) => {
+                let query = containers_api::url::encoded_pair(
+                    "restartPolicy",
+                    podman_api::opts::ContainerRestartPolicy::from(opts.restart_policy),
+                );
+                let body = serde_json::to_vec(&podman_api::models::LinuxResources::from(opts))?;
+
+                libpod
+                    .post(
+                        &format!("/libpod/containers/{}/update", container.id()),
+                        Some(query),
+                        engine::libpod::Body::Raw(body),
+                    )
+                    .await
+            }
         }
     }
 }
@@ -12,7 +12,7 @@ use crate::engine;
 
 pub enum Containers {
     Docker(bollard::Docker),
-    Podman(podman_api::api::Containers),
+    Podman(podman_api::api::Containers, engine::libpod::Libpod),
 }
 
 impl Containers {
@@ -22,7 +22,9 @@ impl Containers {
                 docker: docker.to_owned(),
                 id: id.into(),
             },
-            Self::Podman(containers) => engine::api::Container::Podman(containers.get(id.into())),
+            Self::Podman(containers, libpod) => {
+                engine::api::Container::Podman(containers.get(id.into()), libpod.to_owned())
+            }
         }
     }
 }
@@ -41,7 +43,7 @@ impl Containers {
                     .map_err(anyhow::Error::from)
                     .map(|response| response.id)
             }
-            Self::Podman(containers) => containers
+            Self::Podman(containers, _) => containers
                 .create(&opts.into())
                 .await
                 .map_err(anyhow::Error::from)
@@ -65,7 +67,7 @@ impl Containers {
                         .map(engine::dto::Container::Summary)
                         .collect()
                 }),
-            Self::Podman(containers) => containers
+            Self::Podman(containers, _) => containers
                 .list(
                     &podman_api::opts::ContainerListOpts::builder()
                         .all(true)
@@ -109,7 +111,7 @@ impl Containers {
                 .await
                 .map_err(anyhow::Error::from)
                 .map(Into::into),
-            Self::Podman(containers) => containers
+            Self::Podman(containers, _) => containers
                 .prune(&opts.into())
                 .await
                 .map_err(anyhow::Error::from)
@@ -117,6 +119,48 @@ impl Containers {
         }
     }
 
+    /// Restores a checkpointed container in place or imports one from a checkpoint archive, which
+    /// is streamed to the engine.
+    pub(crate) fn restore(
+        &self,
+        opts: engine::opts::ContainerRestoreOpts,
+    ) -> BoxStream<'_, anyhow::Result<engine::dto::UploadReport<String>>> {
+        match self {
+            Self::Docker(_) => stream::once(future::ready(Err(anyhow::anyhow!(
+                "checkpoints are not supported by the Docker API"
+            ))))
+            .boxed(),
+            Self::Podman(containers, libpod) => match opts.source {
+                engine::opts::ContainerRestoreSource::Container(ref id) => {
+                    let container = containers.get(id);
+                    stream::once(async move {
+                        container
+                            .restore(&(&opts).into())
+                            .await
+                            .map_err(anyhow::Error::from)
+                            .map(|_| {
+                                engine::dto::UploadReport::Finished(container.id().to_string())
+                            })
+                    })
+                    .boxed()
+                }
+                engine::opts::ContainerRestoreSource::Archive(ref path) => {
+                    engine::conn::upload(path.into(), |body| async move {
+                        // The container name in the path is ignored when importing.
+                        libpod
+                            .post_json(
+                                "/libpod/containers/import/restore",
+                                podman_api::opts::ContainerRestoreOpts::from(&opts).serialize(),
+                                engine::libpod::Body::Tar(body),
+                            )
+                            .await
+                            .map(|report: RestoreReport| report.id)
+                    })
+                }
+            },
+        }
+    }
+
     pub(crate) fn stats_stream(
         &self,
         interval: usize,
@@ -177,7 +221,7 @@ impl Containers {
                 }
             }
             .boxed(),
-            Self::Podman (containers) => containers
+            Self::Podman(containers, _) => containers
                 .stats_stream(
                     &podman_api::opts::ContainerStatsOpts::builder()
                         .interval(interval)
@@ -190,3 +234,9 @@ impl Containers {
         }
     }
 }
+
+#[derive(serde::Deserialize)]
+struct RestoreReport {
+    #[serde(rename = "Id")]
+    id: String,
+}
@@ -1,13 +1,25 @@
+use std::path::PathBuf;
+
+use bytes::Bytes;
 use futures::StreamExt;
+use futures::TryStreamExt;
 use futures::stream::BoxStream;
-use http_body_util::Either;
-use http_body_util::Full;
+use serde::Deserialize;
 
 use crate::engine;
 
+const REGISTRY_AUTH_HEADER: &str = "x-registry-auth";
+
+/// A line of the build output of Podman.
+#[derive(Deserialize)]
+struct LibpodBuildReport {
+    stream: Option<String>,
+    error: Option<String>,
+}
+
 pub(crate) enum Images {
     Docker(bollard::Docker),
-    Podman(podman_api::api::Images),
+    Podman(podman_api::api::Images, engine::libpod::Libpod),
 }
 
 impl Images {
@@ -17,34 +29,90 @@ impl Images {
                 docker: docker.clone(),
                 id: id.into(),
             },
-            Self::Podman(images) => engine::api::Image::Podman(images.get(id.into())),
+            Self::Podman(images, _) => engine::api::Image::Podman(images.get(id.into())),
         }
     }
 }
 
 impl Images {
+    /// Builds an image. As the engines only apply one tag during the build, the remaining tags are
+    /// added once the build has finished.
     pub(crate) fn build(
+        &self,
+        mut opts: engine::opts::ImageBuildOpts,
+    ) -> anyhow::Result<BoxStream<'_, anyhow::Result<engine::dto::ImageBuildReport>>> {
+        let additional_tags = opts.tags.split_off(opts.tags.len().min(1));
+        let mut stream = self.build_stream(opts)?;
+
+        if additional_tags.is_empty() {
+            return Ok(stream);
+        }
+
+        Ok(async_stream::stream! {
+            while let Some(report) = stream.next().await {
+                yield match report {
+                    Ok(engine::dto::ImageBuildReport::Finished { image_id }) => self
+                        .tag_all(&image_id, &additional_tags)
+                        .await
+                        .map(|_| engine::dto::ImageBuildReport::Finished { image_id }),
+                    other => other,
+                };
+            }
+        }
+        .boxed())
+    }
+
+    async fn tag_all(&self, image_id: &str, repo_tags: &[String]) -> anyhow::Result<()> {
+        let image = self.get(image_id);
+
+        for repo_tag in repo_tags {
+            let (repo, tag) = match repo_tag.rsplit_once(':') {
+                Some((repo, tag)) if !tag.contains('/') => (repo, tag),
+                _ => (repo_tag.as_str(), "latest"),
+            };
+            image.tag(repo.to_owned(), tag.to_owned()).await?;
+        }
+
+        Ok(())
+    }
+
+    fn build_stream(
         &self,
         opts: engine::opts::ImageBuildOpts,
     ) -> anyhow::Result<BoxStream<'_, anyhow::Result<engine::dto::ImageBuildReport>>> {
         match self {
             Self::Docker(docker) => {
-                // TODO: as podman_api builds the body itself with blocking methods we have to do the same here
-                let body = {
-                    let mut builder = tar::Builder::new(Vec::new());
-
-                    builder.append_dir_all(".", &opts.path)?;
-                    builder.finish()?;
-                    builder.into_inner()?
-                };
+                let path = opts.path.clone();
+                let opts = bollard::query_parameters::BuildImageOptions::try_from(opts)?;
 
                 Ok(async_stream::stream! {
+                    let context = match engine::build_context::BuildContext::scan(
+                        path.into(),
+                        &opts.dockerfile,
+                    )
+                    .await
+                    {
+                        Ok(context) => context,
+                        Err(e) => {
+                            yield Err(e);
+                            return;
+                        }
+                    };
+
+                    yield Ok(engine::dto::ImageBuildReport::Context {
+                        files: context.files(),
+                        size: context.size(),
+                    });
+
                     let mut image_id = None;
                     // after Docker sends the "aux" elem there are 2 elems remaining before we can close the stream
                     let mut elem_countdown = 2;
 
-                    let mut stream =
-                        docker.build_image(opts, None, Some(Either::Left(Full::new(body.into()))));
+                    let mut stream = docker.build_image(
+                        opts,
+                        None,
+                        Some(bollard::body_try_stream(context.into_tar_stream())),
+                    );
 
                     while let Some(item) = stream.next().await {
                         match item {
@@ -78,37 +146,119 @@ impl Images {
                 }
                 .boxed())
             }
-            Self::Podman(images) => {
-                images
-                    .build(&opts.into())
-                    .map_err(anyhow::Error::from)
-                    .map(|mut stream| {
-                        async_stream::stream! {
-                            let mut last_line = None;
-
-                            while let Some(item) = stream.next().await {
-                                match item {
-                                    Ok(item) => {
-                                        last_line = Some(item.stream.clone());
-                                        yield Ok(engine::dto::ImageBuildReport::Streaming {
-                                            line: item.stream,
-                                        })
-                                    }
-                                    Err(e) => yield Err(anyhow::Error::from(e)),
-                                }
-                            }
+            // podman-api neither applies ignore files nor knows pull policies and build secrets.
+            Self::Podman(_, libpod) => Ok(async_stream::stream! {
+                let mut context = match engine::build_context::BuildContext::scan(
+                    opts.path.clone().into(),
+                    &opts.dockerfile,
+                )
+                .await
+                {
+                    Ok(context) => context,
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                };
 
-                            if let Some(mut last_line) = last_line {
-                                last_line.truncate(last_line.trim_end().len());
+                yield Ok(engine::dto::ImageBuildReport::Context {
+                    files: context.files(),
+                    size: context.size(),
+                });
 
-                                yield Ok(engine::dto::ImageBuildReport::Finished {
-                                    image_id: last_line,
-                                })
-                            }
+                opts.secrets.iter().enumerate().for_each(|(index, secret)| {
+                    context.append_file(
+                        secret.path.clone(),
+                        engine::opts::ImageBuildSecret::context_name(index).into(),
+                    )
+                });
+
+                let query = match opts.into_libpod_query() {
+                    Ok(query) => query,
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                };
+
+                let mut last_line = None;
+                let mut failed = false;
+
+                let mut stream = libpod.post_into_stream::<LibpodBuildReport>(
+                    "/libpod/build",
+                    Some(query),
+                    engine::libpod::Body::Tar(context.into_tar_stream().boxed()),
+                );
+
+                while let Some(item) = stream.next().await {
+                    match item {
+                        Ok(LibpodBuildReport { error: Some(message), .. }) => {
+                            failed = true;
+                            yield Ok(engine::dto::ImageBuildReport::Error { message })
                         }
-                        .boxed()
+                        Ok(LibpodBuildReport { stream: Some(line), .. }) => {
+                            last_line = Some(line.clone());
+                            yield Ok(engine::dto::ImageBuildReport::Streaming { line })
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            failed = true;
+                            yield Err(e)
+                        }
+                    }
+                }
+
+                // The last line only carries the image ID if the build has succeeded.
+                if !failed && let Some(mut last_line) = last_line {
+                    last_line.truncate(last_line.trim_end().len());
+
+                    yield Ok(engine::dto::ImageBuildReport::Finished {
+                        image_id: last_line,
                     })
+                }
             }
+            .boxed()),
+        }
+    }
+
+    /// Creates an image from a filesystem tarball and yields the ID of the new image.
+    pub(crate) fn import(
+        &self,
+        opts: engine::opts::ImageImportOpts,
+        path: PathBuf,
+    ) -> BoxStream<'_, anyhow::Result<engine::dto::UploadReport<String>>> {
+        match self {
+            Self::Docker(docker) => engine::conn::upload(path, |body| async move {
+                let mut image_id = None;
+
+                let mut stream =
+                    docker.create_image(Some(opts), Some(bollard::body_try_stream(body)), None);
+
+                while let Some(info) = stream.next().await {
+                    let info = info?;
+                    if let Some(error_detail) = info.error_detail {
+                        anyhow::bail!(error_detail.message.unwrap_or_default());
+                    }
+                    // The last status line carries the ID of the imported image.
+                    if info.status.is_some() {
+                        image_id = info.status;
+                    }
+                }
+
+                image_id.ok_or_else(|| anyhow::anyhow!("the engine did not report an image ID"))
+            }),
+            Self::Podman(_, libpod) => engine::conn::upload(path, |body| async move {
+                libpod
+                    .post_json(
+                        "/libpod/images/import",
+                        podman_api::opts::ImageImportOpts::from(opts).serialize(),
+                        engine::libpod::Body::Tar(body),
+                    )
+                    .await
+                    .map(|report: podman_api::models::ImageImportReport| {
+                        report.id.unwrap_or_default()
+                    })
+            }),
         }
     }
 
@@ -122,7 +272,7 @@ impl Images {
                 .await
                 .map_err(anyhow::Error::from)
                 .map(|summaries| summaries.into_iter().map(Into::into).collect()),
-            Self::Podman(images) => images
+            Self::Podman(images, _) => images
                 .list(&podman_api::opts::ImageListOpts::builder().all(true).build())
                 .await
                 .map_err(anyhow::Error::from)
@@ -130,6 +280,49 @@ impl Images {
         }
     }
 
+    pub(crate) fn load(
+        &self,
+        path: PathBuf,
+    ) -> BoxStream<'_, anyhow::Result<engine::dto::UploadReport<Vec<String>>>> {
+        match self {
+            Self::Docker(docker) => engine::conn::upload(path, |body| async move {
+                let mut names = Vec::new();
+
+                let mut stream = docker.import_image_stream(
+                    bollard::query_parameters::ImportImageOptions::default(),
+                    body,
+                    None,
+                );
+
+                while let Some(item) = stream.next().await {
+                    let item = item?;
+                    if let Some(error_detail) = item.error_detail {
+                        anyhow::bail!(error_detail.message.unwrap_or_default());
+                    }
+                    if let Some(line) = item.stream {
+                        let line = line.trim_end();
+                        if let Some(name) = line
+                            .strip_prefix("Loaded image: ")
+                            .or_else(|| line.strip_prefix("Loaded image ID: "))
+                        {
+                            names.push(name.to_owned());
+                        }
+                    }
+                }
+
+                Ok(names)
+            }),
+            Self::Podman(_, libpod) => engine::conn::upload(path, |body| async move {
+                libpod
+                    .post_json("/libpod/images/load", None, engine::libpod::Body::Tar(body))
+                    .await
+                    .map(|report: podman_api::models::ImageLoadReport| {
+                        report.names.unwrap_or_default()
+                    })
+            }),
+        }
+    }
+
     pub(crate) async fn prune(
         &self,
         opts: engine::opts::ImagesPruneOpts,
@@ -140,7 +333,7 @@ impl Images {
                 .await
                 .map_err(anyhow::Error::from)
                 .map(Into::into),
-            Self::Podman(images) => images
+            Self::Podman(images, _) => images
                 .prune(&opts.into())
                 .await
                 .map_err(anyhow::Error::from)
@@ -150,13 +343,14 @@ impl Images {
 
     pub(crate) fn pull(
         &self,
-        opts: engine::opts::ImagePullOpts,
+        mut opts: engine::opts::ImagePullOpts,
     ) -> BoxStream<'_, anyhow::Result<engine::dto::ImagePullReport>> {
         match self {
             Self::Docker(docker) => async_stream::stream! {
                 let mut image_id = None;
 
-                let mut stream = docker.create_image(Some(opts), None, None);
+                let credentials = opts.credentials.take();
+                let mut stream = docker.create_image(Some(opts), None, credentials.map(Into::into));
 
                 while let Some(item) = stream.next().await {
                     match item {
@@ -187,7 +381,7 @@ impl Images {
             }
             .boxed(),
 
-            Self::Podman(images) => async_stream::stream! {
+            Self::Podman(images, _) => async_stream::stream! {
                 let mut stream = images.pull(&opts.into());
 
                 while let Some(item) = stream.next().await {
@@ -210,27 +404,79 @@ impl Images {
         }
     }
 
+    pub(crate) fn save(
+        &self,
+        opts: engine::opts::ImagesSaveOpts,
+    ) -> BoxStream<'_, anyhow::Result<Bytes>> {
+        match self {
+            Self::Docker(docker) => {
+                let references = opts
+                    .references
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>();
+
+                docker
+                    .export_images(&references)
+                    .map_err(anyhow::Error::from)
+                    .boxed()
+            }
+            Self::Podman(images, _) => images
+                .export(&opts.into())
+                .map_err(anyhow::Error::from)
+                .map_ok(Into::into)
+                .boxed(),
+        }
+    }
+
     pub(crate) async fn search(
         &self,
         term: String,
+        credentials: Option<engine::auth::Credentials>,
     ) -> anyhow::Result<Vec<engine::dto::ImageSearchResponseItem>> {
+        // Neither client passes credentials to the search endpoint, so they are sent as the same
+        // header that pulls use.
+        let auth = credentials
+            .map(podman_api::opts::RegistryAuth::from)
+            .map(|auth| auth.serialize());
+
         match self {
-            Self::Docker(docker) => docker
-                .search_images(bollard::query_parameters::SearchImagesOptions {
-                    term,
-                    ..Default::default()
-                })
-                .await
-                .map_err(anyhow::Error::from)
-                .map(|items| items.into_iter().map(Into::into).collect()),
-            Self::Podman(images) => images
-                .search(
-                    &podman_api::opts::ImageSearchOpts::builder()
+            Self::Docker(docker) => {
+                let docker = match auth {
+                    Some(auth) => {
+                        let auth = hyper::header::HeaderValue::from_str(&auth)?;
+                        docker.clone().with_request_modifier(move |mut request| {
+                            request.headers_mut().insert(
+                                hyper::header::HeaderName::from_static(REGISTRY_AUTH_HEADER),
+                                auth.clone(),
+                            );
+                            request
+                        })
+                    }
+                    None => docker.clone(),
+                };
+
+                docker
+                    .search_images(bollard::query_parameters::SearchImagesOptions {
+                        term,
+                        ..Default::default()
+                    })
+                    .await
+                    .map_err(anyhow::Error::from)
+                    .map(|items| items.into_iter().map(Into::into).collect())
+            }
+            Self::Podman(_, libpod) => libpod
+                .get_json::<Vec<podman_api::models::RegistrySearchResponse>>(
+                    "/libpod/images/search",
+                    podman_api::opts::ImageSearchOpts::builder()
                         .term(term)
-                        .build(),
+                        .build()
+                        .serialize(),
+                    &auth
+                        .map(|auth| vec![(REGISTRY_AUTH_HEADER, auth)])
+                        .unwrap_or_default(),
                 )
                 .await
-                .map_err(anyhow::Error::from)
                 .map(|items| items.into_iter().map(Into::into).collect()),
         }
     }
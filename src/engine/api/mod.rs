@@ -3,8 +3,12 @@ mod containers;
 mod exec;
 mod image;
 mod images;
+mod network;
+mod networks;
 mod pod;
 mod pods;
+mod secret;
+mod secrets;
 mod volume;
 mod volumes;
 
@@ -13,7 +17,11 @@ pub(crate) use containers::Containers;
 pub(crate) use exec::Exec;
 pub(crate) use image::Image;
 pub(crate) use images::Images;
+pub(crate) use network::Network;
+pub(crate) use networks::Networks;
 pub(crate) use pod::Pod;
 pub(crate) use pods::Pods;
+pub(crate) use secret::Secret;
+pub(crate) use secrets::Secrets;
 pub(crate) use volume::Volume;
 pub(crate) use volumes::Volumes;
@@ -0,0 +1,139 @@
+use crate::engine;
+
+#[derive(Debug)]
+pub(crate) enum Network {
+    Docker {
+        docker: bollard::Docker,
+        name: String,
+    },
+    Podman(podman_api::api::Network),
+}
+
+impl Network {
+    pub(crate) async fn inspect(&self) -> anyhow::Result<engine::dto::Network> {
+        match self {
+            Self::Docker { docker, name } => docker
+                .inspect_network(
+                    name,
+                    Option::<bollard::query_parameters::InspectNetworkOptions>::None,
+                )
+                .await
+                .map_err(anyhow::Error::from)
+                .map(Into::into),
+            Self::Podman(network) => network
+                .inspect()
+                .await
+                .map_err(anyhow::Error::from)
+                .map(Into::into),
+        }
+    }
+
+    pub(crate) async fn json(&self) -> anyhow::Result<String> {
+        match self {
+            Self::Docker { docker, name } => docker
+                .inspect_network(
+                    name,
+                    Option::<bollard::query_parameters::InspectNetworkOptions>::None,
+                )
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|response| {
+                    serde_json::to_string_pretty(&response).map_err(anyhow::Error::from)
+                }),
+            Self::Podman(network) => network
+                .inspect()
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|response| {
+                    serde_json::to_string_pretty(&response).map_err(anyhow::Error::from)
+                }),
+        }
+    }
+
+    pub(crate) async fn remove(&self, force: bool) -> anyhow::Result<()> {
+        match self {
+            Self::Docker { docker, name } => {
+                // Docker has no forced removal, so connected containers are disconnected first.
+                if force {
+                    let containers = docker
+                        .inspect_network(
+                            name,
+                            Option::<bollard::query_parameters::InspectNetworkOptions>::None,
+                        )
+                        .await?
+                        .containers
+                        .unwrap_or_default();
+
+                    for container in containers.into_keys() {
+                        docker
+                            .disconnect_network(
+                                name,
+                                bollard::plugin::NetworkDisconnectRequest {
+                                    container,
+                                    force: Some(true),
+                                },
+                            )
+                            .await?;
+                    }
+                }
+
+                docker
+                    .remove_network(name)
+                    .await
+                    .map_err(anyhow::Error::from)
+            }
+            Self::Podman(network) => if force {
+                network.remove().await.map(|_| ())
+            } else {
+                network.delete().await.map(|_| ())
+            }
+            .map_err(anyhow::Error::from),
+        }
+    }
+
+    pub(crate) async fn connect(
+        &self,
+        opts: engine::opts::NetworkConnectOpts,
+    ) -> anyhow::Result<()> {
+        match self {
+            Self::Docker { docker, name } => docker
+                .connect_network(name, opts.into())
+                .await
+                .map_err(anyhow::Error::from),
+            Self::Podman(network) => network
+                .connect_container(&opts.into())
+                .await
+                .map_err(anyhow::Error::from),
+        }
+    }
+
+    pub(crate) async fn disconnect(
+        &self,
+        container: impl Into<String>,
+        force: bool,
+    ) -> anyhow::Result<()> {
+        let container = container.into();
+
+        match self {
+            Self::Docker { docker, name } => docker
+                .disconnect_network(
+                    name,
+                    bollard::plugin::NetworkDisconnectRequest {
+                        container,
+                        force: Some(force),
+                    },
+                )
+                .await
+                .map_err(anyhow::Error::from),
+            Self::Podman(network) => network
+                .disconnect_container(
+                    &podman_api::opts::NetworkDisconnectOpts::builder()
+                        .container(container)
+                        .force(force)
+                        .build(),
+                )
+                .await
+                .map_err(anyhow::Error::from),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::engine::{self};
+
+pub(crate) enum Networks {
+    Docker(bollard::Docker),
+    Podman(podman_api::api::Networks),
+}
+
+impl Networks {
+    pub(crate) fn get(&self, name: impl Into<String>) -> engine::api::Network {
+        match self {
+            Self::Docker(docker) => engine::api::Network::Docker {
+                docker: docker.clone(),
+                name: name.into(),
+            },
+            Self::Podman(networks) => engine::api::Network::Podman(networks.get(name.into())),
+        }
+    }
+}
+
+impl Networks {
+    pub(crate) async fn create(
+        &self,
+        opts: engine::opts::NetworkCreateOpts,
+    ) -> anyhow::Result<String> {
+        match self {
+            Self::Docker(docker) => {
+                let name = opts.name.clone();
+                docker
+                    .create_network(opts.into())
+                    .await
+                    .map_err(anyhow::Error::from)
+                    .map(|_| name)
+            }
+            Self::Podman(networks) => networks
+                .create(&opts.into())
+                .await
+                .map_err(anyhow::Error::from)
+                .map(|network| network.name.unwrap()),
+        }
+    }
+
+    pub(crate) async fn list(&self) -> anyhow::Result<Vec<engine::dto::Network>> {
+        match self {
+            Self::Docker(docker) => docker
+                .list_networks(Option::<bollard::query_parameters::ListNetworksOptions>::None)
+                .await
+                .map_err(anyhow::Error::from)
+                .map(|networks| networks.into_iter().map(Into::into).collect()),
+            Self::Podman(networks) => networks
+                .list(&Default::default())
+                .await
+                .map_err(anyhow::Error::from)
+                .map(|networks| networks.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    pub(crate) async fn prune(
+        &self,
+        opts: engine::opts::NetworksPruneOpts,
+    ) -> anyhow::Result<engine::dto::PruneReport> {
+        match self {
+            Self::Docker(docker) => docker
+                .prune_networks(Some(opts))
+                .await
+                .map_err(anyhow::Error::from)
+                .map(Into::into),
+            Self::Podman(networks) => networks
+                .prune(&opts.into())
+                .await
+                .map_err(anyhow::Error::from)
+                .map(Into::into),
+        }
+    }
+}
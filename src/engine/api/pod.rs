@@ -24,6 +24,27 @@ impl Pod {
         }
     }
 
+    pub(crate) async fn generate_systemd_units(
+        &self,
+    ) -> anyhow::Result<Vec<engine::dto::UnitFile>> {
+        match self {
+            Self::Docker => anyhow::bail!("pods are not supported by the Docker API"),
+            Self::Podman(pod) => {
+                let units = pod
+                    .generate_systemd_units(
+                        &podman_api::opts::SystemdUnitsOpts::builder()
+                            .use_name(true)
+                            .build(),
+                    )
+                    .await?;
+
+                Ok(engine::quadlet::systemd_unit_files(serde_json::from_value(
+                    units,
+                )?))
+            }
+        }
+    }
+
     pub(crate) async fn inspect(&self) -> anyhow::Result<engine::dto::PodInspection> {
         match self {
             Self::Docker => anyhow::bail!("pods are not supported by the Docker API"),
@@ -3,14 +3,14 @@ use crate::engine;
 #[allow(clippy::large_enum_variant)]
 pub(crate) enum Pods {
     Docker,
-    Podman(podman_api::api::Pods),
+    Podman(podman_api::Podman, engine::libpod::Libpod),
 }
 
 impl Pods {
     pub(crate) fn get(&self, id: impl Into<String>) -> engine::api::Pod {
         match self {
             Self::Docker => engine::api::Pod::Docker,
-            Self::Podman(pods) => engine::api::Pod::Podman(pods.get(id.into())),
+            Self::Podman(podman, _) => engine::api::Pod::Podman(podman.pods().get(id.into())),
         }
     }
 }
@@ -19,7 +19,8 @@ impl Pods {
     pub(crate) async fn create(&self, opts: engine::opts::PodCreateOpts) -> anyhow::Result<String> {
         match self {
             Self::Docker => anyhow::bail!("pods are not supported by the Docker API"),
-            Self::Podman(pods) => pods
+            Self::Podman(podman, _) => podman
+                .pods()
                 .create(&opts.into())
                 .await
                 .map_err(anyhow::Error::from)
@@ -30,7 +31,8 @@ impl Pods {
     pub(crate) async fn list(&self) -> anyhow::Result<Vec<engine::dto::PodSummary>> {
         match self {
             Self::Docker => anyhow::bail!("pods are not supported by the Docker API"),
-            Self::Podman(pods) => pods
+            Self::Podman(podman, _) => podman
+                .pods()
                 .list(&podman_api::opts::PodListOpts::builder().build())
                 .await
                 .map_err(anyhow::Error::from)
@@ -38,10 +40,61 @@ impl Pods {
         }
     }
 
+    /// Creates the pods described by a Kubernetes YAML or tears them down again.
+    pub(crate) async fn play_kube(
+        &self,
+        opts: engine::opts::PodsPlayKubeOpts,
+    ) -> anyhow::Result<engine::dto::PodsPlayKubeReport> {
+        match self {
+            Self::Docker => anyhow::bail!("pods are not supported by the Docker API"),
+            Self::Podman(podman, libpod) => {
+                let yaml = tokio::fs::read_to_string(&opts.file).await?;
+
+                // Podman stops and removes the pods itself, including those of workload resources.
+                let removed_pods = if opts.down || opts.replace {
+                    let report = libpod
+                        .delete_json::<podman_api::models::PlayKubeReport>(
+                            "/libpod/play/kube",
+                            yaml.clone().into_bytes(),
+                        )
+                        .await?;
+
+                    let mut removed_pods = Vec::new();
+                    for rm_report in report.rm_report.unwrap_or_default() {
+                        if let Some(err) = rm_report.err {
+                            anyhow::bail!(err);
+                        }
+                        removed_pods.extend(rm_report.id);
+                    }
+                    removed_pods
+                } else {
+                    Vec::new()
+                };
+
+                if opts.down {
+                    return Ok(engine::dto::PodsPlayKubeReport {
+                        removed_pods,
+                        ..Default::default()
+                    });
+                }
+
+                podman
+                    .play_kubernetes_yaml(&opts.into(), yaml)
+                    .await
+                    .map_err(anyhow::Error::from)
+                    .map(|report| engine::dto::PodsPlayKubeReport {
+                        removed_pods,
+                        ..report.into()
+                    })
+            }
+        }
+    }
+
     pub(crate) async fn prune(&self) -> anyhow::Result<engine::dto::PodsPruneReport> {
         match self {
             Self::Docker => anyhow::bail!("pods are not supported by the Docker API"),
-            Self::Podman(pods) => pods
+            Self::Podman(podman, _) => podman
+                .pods()
                 .prune()
                 .await
                 .map_err(anyhow::Error::from)
@@ -0,0 +1,46 @@
+use crate::engine;
+
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Secret {
+    Docker,
+    Podman(podman_api::api::Secret),
+}
+
+impl Secret {
+    pub(crate) async fn inspect(&self) -> anyhow::Result<engine::dto::Secret> {
+        match self {
+            Self::Docker => {
+                anyhow::bail!("secrets are only supported in swarm mode by the Docker API")
+            }
+            Self::Podman(secret) => secret
+                .inspect()
+                .await
+                .map_err(anyhow::Error::from)
+                .map(Into::into),
+        }
+    }
+
+    pub(crate) async fn json(&self) -> anyhow::Result<String> {
+        match self {
+            Self::Docker => {
+                anyhow::bail!("secrets are only supported in swarm mode by the Docker API")
+            }
+            Self::Podman(secret) => secret
+                .inspect()
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|response| {
+                    serde_json::to_string_pretty(&response).map_err(anyhow::Error::from)
+                }),
+        }
+    }
+
+    pub(crate) async fn remove(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Docker => {
+                anyhow::bail!("secrets are only supported in swarm mode by the Docker API")
+            }
+            Self::Podman(secret) => secret.delete().await.map_err(anyhow::Error::from),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::engine;
+
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Secrets {
+    Docker,
+    Podman(podman_api::api::Secrets, engine::libpod::Libpod),
+}
+
+impl Secrets {
+    pub(crate) fn get(&self, id: impl Into<String>) -> engine::api::Secret {
+        match self {
+            Self::Docker => engine::api::Secret::Docker,
+            Self::Podman(secrets, _) => engine::api::Secret::Podman(secrets.get(id.into())),
+        }
+    }
+}
+
+impl Secrets {
+    pub(crate) async fn create(
+        &self,
+        opts: engine::opts::SecretCreateOpts,
+    ) -> anyhow::Result<String> {
+        match self {
+            Self::Docker => {
+                anyhow::bail!("secrets are only supported in swarm mode by the Docker API")
+            }
+            // podman-api would send the data as a JSON string, which alters the secret.
+            Self::Podman(_, libpod) => {
+                let data = opts.data.clone();
+                libpod
+                    .post_json(
+                        "/libpod/secrets/create",
+                        podman_api::opts::SecretCreateOpts::from(opts).serialize(),
+                        engine::libpod::Body::Raw(data),
+                    )
+                    .await
+                    .map(|response: podman_api::models::SecretCreateResponse| {
+                        response.id.unwrap_or_default()
+                    })
+            }
+        }
+    }
+
+    pub(crate) async fn list(&self) -> anyhow::Result<Vec<engine::dto::Secret>> {
+        match self {
+            Self::Docker => {
+                anyhow::bail!("secrets are only supported in swarm mode by the Docker API")
+            }
+            Self::Podman(secrets, _) => secrets
+                .list()
+                .await
+                .map_err(anyhow::Error::from)
+                .map(|secrets| secrets.into_iter().map(Into::into).collect()),
+        }
+    }
+}
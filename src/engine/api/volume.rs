@@ -9,6 +9,15 @@ pub(crate) enum Volume {
     Podman(podman_api::api::Volume),
 }
 
+impl Volume {
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Self::Docker { name, .. } => name,
+            Self::Podman(volume) => volume.name().as_ref(),
+        }
+    }
+}
+
 impl Volume {
     pub(crate) async fn inspect(&self) -> anyhow::Result<engine::dto::Volume> {
         match self {
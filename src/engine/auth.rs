@@ -1,6 +1,14 @@
-#[derive(Clone)]
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Registry assumed by the engines for image references without an explicit registry.
+pub(crate) const DEFAULT_REGISTRY: &str = "docker.io";
+
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) enum Credentials {
+    #[serde(rename = "Basic")]
     BasicAuth { username: String, password: String },
+    #[serde(rename = "Token")]
     IdentityToken(String),
 }
 
@@ -31,3 +39,27 @@ impl From<Credentials> for podman_api::opts::RegistryAuth {
         }
     }
 }
+
+/// Returns the host of the registry an image reference points to. The first path component is
+/// only a registry host if it looks like one, otherwise the reference belongs to Docker Hub.
+pub(crate) fn registry_host(reference: &str) -> &str {
+    match reference.split_once('/') {
+        Some((host, _)) if host.contains(['.', ':']) || host == "localhost" => host,
+        _ => DEFAULT_REGISTRY,
+    }
+}
+
+/// Whether an error message returned by a registry operation means that the registry denied
+/// access because of missing or wrong credentials.
+pub(crate) fn is_unauthorized(message: &str) -> bool {
+    let message = message.to_lowercase();
+
+    [
+        "unauthorized",
+        "authentication required",
+        "status code 401",
+        "requested access to the resource is denied",
+    ]
+    .iter()
+    .any(|pattern| message.contains(pattern))
+}
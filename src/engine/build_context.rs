@@ -0,0 +1,315 @@
+//! Creation of image build contexts honoring `.containerignore` and `.dockerignore` files.
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use bytes::BytesMut;
+use futures::Stream;
+use tokio::io::AsyncReadExt;
+
+/// Size of the chunks the tar archive is streamed in.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The files of a build context directory that are sent to the engine.
+pub(crate) struct BuildContext {
+    root: PathBuf,
+    /// paths relative to the root in the order they are added to the archive
+    entries: Vec<PathBuf>,
+    /// files from outside of the context directory and their paths within the archive
+    extra_files: Vec<(PathBuf, PathBuf)>,
+    files: usize,
+    size: u64,
+}
+
+impl BuildContext {
+    /// Collects the files of the build context directory. The Containerfile specific ignore file
+    /// takes precedence over `.containerignore`, which takes precedence over `.dockerignore`.
+    pub(crate) async fn scan(root: PathBuf, dockerfile: &str) -> anyhow::Result<Self> {
+        let dockerfiles = if dockerfile.is_empty() {
+            vec![PathBuf::from("Containerfile"), PathBuf::from("Dockerfile")]
+        } else {
+            vec![PathBuf::from(dockerfile)]
+        };
+
+        let mut ignore_file = None;
+        for candidate in dockerfiles
+            .iter()
+            .flat_map(|dockerfile| {
+                ["containerignore", "dockerignore"].map(|extension| {
+                    let mut candidate = dockerfile.clone().into_os_string();
+                    candidate.push(".");
+                    candidate.push(extension);
+                    PathBuf::from(candidate)
+                })
+            })
+            .chain([
+                PathBuf::from(".containerignore"),
+                PathBuf::from(".dockerignore"),
+            ])
+        {
+            match tokio::fs::read_to_string(root.join(&candidate)).await {
+                Ok(content) => {
+                    ignore_file = Some((candidate, IgnorePatterns::parse(&content)));
+                    break;
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let (ignore_file, patterns) = ignore_file.unzip();
+        let patterns = patterns.unwrap_or_default();
+
+        let mut context = Self {
+            root,
+            entries: Vec::new(),
+            extra_files: Vec::new(),
+            files: 0,
+            size: 0,
+        };
+
+        let mut dirs = vec![PathBuf::new()];
+        while let Some(dir) = dirs.pop() {
+            let mut read_dir = tokio::fs::read_dir(context.root.join(&dir)).await?;
+
+            let mut children = Vec::new();
+            while let Some(entry) = read_dir.next_entry().await? {
+                children.push(entry);
+            }
+            children.sort_by_key(|entry| entry.file_name());
+
+            for entry in children {
+                let path = dir.join(entry.file_name());
+                let metadata = entry.metadata().await?;
+
+                // Like the engines do, the Containerfile and the ignore file are always sent.
+                let excluded = patterns.is_excluded(&path)
+                    && !dockerfiles.contains(&path)
+                    && ignore_file.as_ref() != Some(&path);
+
+                if metadata.is_dir() {
+                    // Exceptions may re-include files below an excluded directory.
+                    if !excluded || patterns.has_exceptions() {
+                        dirs.push(path.clone());
+                    }
+                    if excluded {
+                        continue;
+                    }
+                } else if excluded {
+                    continue;
+                } else if metadata.is_file() {
+                    context.files += 1;
+                    context.size += metadata.len();
+                }
+
+                context.entries.push(path);
+            }
+        }
+
+        Ok(context)
+    }
+
+    /// Number of regular files in the context.
+    pub(crate) fn files(&self) -> usize {
+        self.files
+    }
+
+    /// Accumulated size of the regular files in the context.
+    pub(crate) fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Adds the file at `path` to the archive as `name`. It doesn't count as part of the context.
+    pub(crate) fn append_file(&mut self, path: PathBuf, name: PathBuf) {
+        self.extra_files.push((path, name));
+    }
+
+    /// Streams the context as uncompressed tar archive without buffering it as a whole.
+    pub(crate) fn into_tar_stream(self) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static {
+        async_stream::try_stream! {
+            let (mut reader, writer) = tokio::io::duplex(CHUNK_SIZE);
+            let writer = tokio::spawn(self.write_tar(writer));
+
+            loop {
+                let mut buf = BytesMut::with_capacity(CHUNK_SIZE);
+                if reader.read_buf(&mut buf).await? == 0 {
+                    break;
+                }
+                yield buf.freeze();
+            }
+
+            writer.await.map_err(io::Error::other)??;
+        }
+    }
+
+    async fn write_tar(self, writer: tokio::io::DuplexStream) -> io::Result<()> {
+        let mut builder = tokio_tar::Builder::new(writer);
+        builder.follow_symlinks(false);
+
+        for entry in &self.entries {
+            builder
+                .append_path_with_name(self.root.join(entry), entry)
+                .await?;
+        }
+        for (path, name) in &self.extra_files {
+            let mut file = tokio::fs::File::open(path).await?;
+            builder.append_file(name, &mut file).await?;
+        }
+
+        builder.into_inner().await.map(drop)
+    }
+}
+
+#[derive(Default)]
+struct IgnorePatterns(Vec<IgnorePattern>);
+
+struct IgnorePattern {
+    segments: Vec<String>,
+    /// patterns prefixed with `!` re-include previously excluded paths
+    exception: bool,
+}
+
+impl IgnorePatterns {
+    fn parse(content: &str) -> Self {
+        Self(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .filter_map(|line| {
+                    let (exception, pattern) = match line.strip_prefix('!') {
+                        Some(pattern) => (true, pattern.trim()),
+                        None => (false, line),
+                    };
+
+                    let segments = pattern
+                        .split('/')
+                        .filter(|segment| !segment.is_empty() && *segment != ".")
+                        .map(str::to_owned)
+                        .collect::<Vec<_>>();
+
+                    (!segments.is_empty()).then_some(IgnorePattern {
+                        segments,
+                        exception,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn has_exceptions(&self) -> bool {
+        self.0.iter().any(|pattern| pattern.exception)
+    }
+
+    /// A path is excluded if the last pattern matching it or one of its parents is not an
+    /// exception.
+    fn is_excluded(&self, path: &Path) -> bool {
+        let components = path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>();
+        let components = components.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+
+        self.0.iter().fold(false, |excluded, pattern| {
+            if (1..=components.len())
+                .any(|len| match_segments(&pattern.segments, &components[..len]))
+            {
+                !pattern.exception
+            } else {
+                excluded
+            }
+        })
+    }
+}
+
+fn match_segments(segments: &[String], components: &[&str]) -> bool {
+    match segments.split_first() {
+        None => components.is_empty(),
+        Some((segment, segments)) if segment == "**" => {
+            (0..=components.len()).any(|skip| match_segments(segments, &components[skip..]))
+        }
+        Some((segment, segments)) => match components.split_first() {
+            Some((component, components)) => {
+                match_segment(
+                    &segment.chars().collect::<Vec<_>>(),
+                    &component.chars().collect::<Vec<_>>(),
+                ) && match_segments(segments, components)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Matches a single path component against a glob supporting `*`, `?` and character classes.
+fn match_segment(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', pattern)) => (0..=name.len()).any(|skip| match_segment(pattern, &name[skip..])),
+        Some(('?', pattern)) => !name.is_empty() && match_segment(pattern, &name[1..]),
+        Some(('[', rest)) => match (name.split_first(), rest.iter().position(|c| *c == ']')) {
+            (Some((c, name)), Some(end)) => {
+                let (negated, class) = match rest[..end].split_first() {
+                    Some(('^' | '!', class)) => (true, class),
+                    _ => (false, &rest[..end]),
+                };
+                match_class(class, *c) != negated && match_segment(&rest[end + 1..], name)
+            }
+            _ => false,
+        },
+        Some(('\\', [escaped, pattern @ ..])) => {
+            name.first() == Some(escaped) && match_segment(pattern, &name[1..])
+        }
+        Some((expected, pattern)) => {
+            name.first() == Some(expected) && match_segment(pattern, &name[1..])
+        }
+    }
+}
+
+fn match_class(class: &[char], c: char) -> bool {
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == '-' {
+            if (class[i]..=class[i + 2]).contains(&c) {
+                return true;
+            }
+            i += 3;
+        } else {
+            if class[i] == c {
+                return true;
+            }
+            i += 1;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ignore_patterns() {
+        let patterns = IgnorePatterns::parse(
+            "# comment\n\
+             node_modules\n\
+             /target\n\
+             **/*.log\n\
+             !important.log\n\
+             docs/[a-c]?.md\n",
+        );
+
+        assert!(patterns.is_excluded(Path::new("node_modules")));
+        assert!(patterns.is_excluded(Path::new("node_modules/foo/index.js")));
+        assert!(!patterns.is_excluded(Path::new("src/node_modules")));
+        assert!(patterns.is_excluded(Path::new("target/debug/pods")));
+        assert!(patterns.is_excluded(Path::new("build.log")));
+        assert!(patterns.is_excluded(Path::new("logs/build.log")));
+        assert!(!patterns.is_excluded(Path::new("important.log")));
+        assert!(patterns.is_excluded(Path::new("docs/a1.md")));
+        assert!(!patterns.is_excluded(Path::new("docs/d1.md")));
+        assert!(!patterns.is_excluded(Path::new("src/main.rs")));
+        assert!(patterns.has_exceptions());
+    }
+}
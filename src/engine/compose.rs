@@ -0,0 +1,1340 @@
+//! Support for a subset of the Compose specification, see <https://compose-spec.io>.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::engine;
+use crate::keyring;
+
+pub(crate) const PROJECT_LABEL: &str = "com.docker.compose.project";
+pub(crate) const SERVICE_LABEL: &str = "com.docker.compose.service";
+
+impl engine::Engine {
+    /// Creates all networks, volumes, the pod and containers described by a compose file.
+    pub(crate) fn compose_up(
+        &self,
+        opts: engine::opts::ComposeUpOpts,
+    ) -> BoxStream<'_, anyhow::Result<engine::dto::ComposeReport>> {
+        async_stream::try_stream! {
+            let project = ComposeProject::load(&opts, matches!(self, Self::Podman(..))).await?;
+
+            yield engine::dto::ComposeReport::Planned {
+                project: project.name.clone(),
+                steps: project.steps(opts.start),
+            };
+
+            for warning in project.warnings {
+                yield engine::dto::ComposeReport::Streaming {
+                    line: format!("Warning: {warning}\n"),
+                };
+            }
+
+            let networks = self.networks();
+            for network_opts in project.networks {
+                yield engine::dto::ComposeReport::Step {
+                    description: format!("Creating network {}", network_opts.name),
+                };
+
+                if networks.get(network_opts.name.as_str()).inspect().await.is_ok() {
+                    yield engine::dto::ComposeReport::Streaming {
+                        line: "Network already exists\n".to_owned(),
+                    };
+                } else {
+                    networks.create(network_opts).await?;
+                }
+            }
+
+            let volumes = self.volumes();
+            for volume_opts in project.volumes {
+                let name = volume_opts.name.clone().unwrap_or_default();
+
+                yield engine::dto::ComposeReport::Step {
+                    description: format!("Creating volume {name}"),
+                };
+
+                if volumes.get(name.as_str()).inspect().await.is_ok() {
+                    yield engine::dto::ComposeReport::Streaming {
+                        line: "Volume already exists\n".to_owned(),
+                    };
+                } else {
+                    volumes.create(volume_opts).await?;
+                }
+            }
+
+            let images = self.images();
+            let mut pulled = HashSet::new();
+            for (image, platform) in project
+                .services
+                .iter()
+                .map(|(_, opts)| (opts.image.as_str(), opts.platform.as_ref()))
+            {
+                if !pulled.insert((image, platform)) {
+                    continue;
+                }
+
+                yield engine::dto::ComposeReport::Step {
+                    description: format!("Pulling image {image}"),
+                };
+
+                if !opts.pull_latest
+                    && platform.is_none()
+                    && images.get(image).inspect().await.is_ok()
+                {
+                    yield engine::dto::ComposeReport::Streaming {
+                        line: "Image already exists\n".to_owned(),
+                    };
+                    continue;
+                }
+
+                let mut stream = keyring::pull_image(
+                    &images,
+                    engine::opts::ImagePullOpts {
+                        platform: platform.cloned(),
+                        reference: image.to_owned(),
+                        ..Default::default()
+                    },
+                );
+                while let Some(report) = stream.next().await {
+                    match report? {
+                        engine::dto::ImagePullReport::Streaming { line } => {
+                            yield engine::dto::ComposeReport::Streaming { line };
+                        }
+                        engine::dto::ImagePullReport::Error { message } => {
+                            Err::<(), _>(anyhow::anyhow!(message))?;
+                        }
+                        engine::dto::ImagePullReport::Finished { .. } => break,
+                    }
+                }
+            }
+
+            let pod_id = match project.pod {
+                Some(pod_opts) => {
+                    yield engine::dto::ComposeReport::Step {
+                        description: format!("Creating pod {}", pod_opts.name),
+                    };
+
+                    Some(self.pods().create(pod_opts).await?)
+                }
+                None => None,
+            };
+
+            let containers = self.containers();
+            let mut container_ids = Vec::with_capacity(project.services.len());
+            for (service, container_opts) in project.services {
+                yield engine::dto::ComposeReport::Step {
+                    description: format!("Creating container for service {service}"),
+                };
+
+                let name = container_opts.name.clone();
+                container_ids.push((service, containers.create(container_opts).await?));
+
+                yield engine::dto::ComposeReport::Streaming {
+                    line: format!("Created container {name}\n"),
+                };
+            }
+
+            if opts.start {
+                for (service, id) in container_ids {
+                    yield engine::dto::ComposeReport::Step {
+                        description: format!("Starting service {service}"),
+                    };
+
+                    containers.get(id).start().await?;
+                }
+            }
+
+            yield engine::dto::ComposeReport::Finished { pod_id };
+        }
+        .boxed()
+    }
+}
+
+/// A compose file resolved into the options needed to create its resources.
+struct ComposeProject {
+    name: String,
+    networks: Vec<engine::opts::NetworkCreateOpts>,
+    volumes: Vec<engine::opts::VolumeCreateOpts>,
+    pod: Option<engine::opts::PodCreateOpts>,
+    /// services in the order they must be created
+    services: Vec<(String, engine::opts::ContainerCreateOpts)>,
+    warnings: Vec<String>,
+}
+
+impl ComposeProject {
+    async fn load(opts: &engine::opts::ComposeUpOpts, pods: bool) -> anyhow::Result<Self> {
+        let file = Path::new(&opts.file);
+        let dir = file
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let mut vars = read_env_file(&dir.join(".env")).await.unwrap_or_default();
+        vars.extend(std::env::vars());
+
+        let content = tokio::fs::read_to_string(file)
+            .await
+            .map_err(|e| anyhow::anyhow!("error on reading {}: {e}", file.display()))?;
+        let mut compose = serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&content)?;
+        interpolate_value(&mut compose, &vars)?;
+        let compose = serde_yaml_ng::from_value::<ComposeFile>(compose)?;
+
+        let name = normalize_project_name(
+            opts.project_name
+                .as_deref()
+                .or(compose.name.as_deref())
+                .or_else(|| dir.file_name().and_then(|name| name.to_str()))
+                .unwrap_or("compose"),
+        );
+        if name.is_empty() {
+            anyhow::bail!("invalid project name");
+        }
+        if compose.services.is_empty() {
+            anyhow::bail!("compose file does not define any services");
+        }
+
+        let pod = pods && opts.pod;
+        let labels = HashMap::from([(PROJECT_LABEL.to_owned(), name.clone())]);
+        let mut warnings = Vec::new();
+
+        let volume_names = compose
+            .volumes
+            .iter()
+            .map(|(key, volume)| {
+                let volume = volume.clone().unwrap_or_default();
+                // External resources are looked up by their plain name.
+                let volume_name = volume.name.unwrap_or_else(|| {
+                    if volume.external {
+                        key.to_owned()
+                    } else {
+                        format!("{name}_{key}")
+                    }
+                });
+                (key.to_owned(), (volume_name, volume.external))
+            })
+            .collect::<HashMap<_, _>>();
+        let volumes = compose
+            .volumes
+            .iter()
+            .map(|(key, volume)| (&volume_names[key].0, volume.clone().unwrap_or_default()))
+            .filter(|(_, volume)| !volume.external)
+            .map(|(name, volume)| engine::opts::VolumeCreateOpts {
+                name: Some(name.to_owned()),
+                driver: volume.driver,
+                options: volume.driver_opts,
+                labels: volume
+                    .labels
+                    .as_ref()
+                    .map(ListOrDict::to_map)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(labels.clone())
+                    .collect(),
+            })
+            .collect();
+
+        let network_names = if pod {
+            if !compose.networks.is_empty() {
+                warnings.push(
+                    "networks are ignored because all services share the network of the pod"
+                        .to_owned(),
+                );
+            }
+            IndexMap::new()
+        } else {
+            let mut network_names = compose
+                .networks
+                .iter()
+                .map(|(key, network)| {
+                    let network = network.clone().unwrap_or_default();
+                    let network_name = network.name.clone().unwrap_or_else(|| {
+                        if network.external {
+                            key.to_owned()
+                        } else {
+                            format!("{name}_{key}")
+                        }
+                    });
+                    (key.to_owned(), (network_name, network))
+                })
+                .collect::<IndexMap<_, _>>();
+            network_names
+                .entry("default".to_owned())
+                .or_insert_with(|| (format!("{name}_default"), ComposeNetwork::default()));
+            network_names
+        };
+
+        let mut services = Vec::with_capacity(compose.services.len());
+        for service in sort_services(&compose.services)? {
+            let mut env = HashMap::new();
+            for env_file in compose.services[&service]
+                .env_file
+                .clone()
+                .map(StringOrList::into_vec)
+                .unwrap_or_default()
+            {
+                env.extend(read_env_file(&dir.join(env_file)).await?);
+            }
+
+            let opts = compose.services[&service].to_opts(
+                &service,
+                &name,
+                &dir,
+                pod,
+                env,
+                &vars,
+                &compose.services,
+                &volume_names,
+                &network_names,
+                &mut warnings,
+            )?;
+            services.push((service, opts));
+        }
+
+        // Only create networks that are actually used by a service.
+        let used_networks = services
+            .iter()
+            .flat_map(|(_, opts)| opts.networks.iter().map(|network| network.network.as_str()))
+            .collect::<HashSet<_>>();
+        let networks = network_names
+            .values()
+            .filter(|(network_name, network)| {
+                !network.external && used_networks.contains(network_name.as_str())
+            })
+            .map(|(network_name, network)| engine::opts::NetworkCreateOpts {
+                name: network_name.to_owned(),
+                driver: network.driver.clone(),
+                internal: network.internal,
+                ipv6_enabled: network.enable_ipv6,
+                labels: labels.clone(),
+                ..Default::default()
+            })
+            .collect();
+
+        let pod = if pod {
+            Some(engine::opts::PodCreateOpts {
+                name: name.clone(),
+                labels: labels.clone(),
+                // Containers of a pod share the network namespace, so service names resolve to the
+                // loopback interface.
+                host_management: engine::opts::PodHostManagement::Pod {
+                    hosts: services
+                        .iter()
+                        .map(|(service, _)| engine::opts::PodHost {
+                            ip: "127.0.0.1".to_owned(),
+                            name: service.to_owned(),
+                        })
+                        .collect(),
+                },
+                port_mappings: services
+                    .iter()
+                    .map(|(service, _)| compose.services[service].port_mappings())
+                    .collect::<anyhow::Result<Vec<_>>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+                ..Default::default()
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            name,
+            networks,
+            volumes,
+            pod,
+            services,
+            warnings,
+        })
+    }
+
+    fn steps(&self, start: bool) -> u32 {
+        let images = self
+            .services
+            .iter()
+            .map(|(_, opts)| opts.image.as_str())
+            .collect::<HashSet<_>>()
+            .len();
+
+        (self.networks.len()
+            + self.volumes.len()
+            + images
+            + usize::from(self.pod.is_some())
+            + self.services.len() * if start { 2 } else { 1 }) as u32
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ComposeFile {
+    name: Option<String>,
+    services: IndexMap<String, ComposeService>,
+    networks: IndexMap<String, Option<ComposeNetwork>>,
+    volumes: IndexMap<String, Option<ComposeVolume>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct ComposeNetwork {
+    name: Option<String>,
+    driver: Option<String>,
+    external: bool,
+    internal: bool,
+    enable_ipv6: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct ComposeVolume {
+    name: Option<String>,
+    driver: Option<String>,
+    driver_opts: HashMap<String, String>,
+    external: bool,
+    labels: Option<ListOrDict>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ComposeService {
+    build: Option<serde_yaml_ng::Value>,
+    cap_add: Vec<String>,
+    cap_drop: Vec<String>,
+    command: Option<StringOrList>,
+    container_name: Option<String>,
+    cpu_shares: Option<u64>,
+    cpus: Option<Scalar>,
+    cpuset: Option<String>,
+    depends_on: Option<ListOrDict>,
+    dns: Option<StringOrList>,
+    entrypoint: Option<StringOrList>,
+    env_file: Option<StringOrList>,
+    environment: Option<ListOrDict>,
+    extra_hosts: Option<ListOrDict>,
+    hostname: Option<String>,
+    image: Option<String>,
+    init: bool,
+    labels: Option<ListOrDict>,
+    mem_limit: Option<Scalar>,
+    network_mode: Option<String>,
+    networks: Option<ListOrDict>,
+    pids_limit: Option<i64>,
+    platform: Option<String>,
+    ports: Vec<ComposePort>,
+    privileged: bool,
+    read_only: bool,
+    restart: Option<String>,
+    security_opt: Vec<String>,
+    shm_size: Option<Scalar>,
+    tty: bool,
+    user: Option<String>,
+    volumes: Vec<ComposeServiceVolume>,
+    working_dir: Option<String>,
+}
+
+impl ComposeService {
+    #[allow(clippy::too_many_arguments)]
+    fn to_opts(
+        &self,
+        service: &str,
+        project: &str,
+        dir: &Path,
+        pod: bool,
+        mut env: HashMap<String, String>,
+        vars: &HashMap<String, String>,
+        services: &IndexMap<String, ComposeService>,
+        volume_names: &HashMap<String, (String, bool)>,
+        network_names: &IndexMap<String, (String, ComposeNetwork)>,
+        warnings: &mut Vec<String>,
+    ) -> anyhow::Result<engine::opts::ContainerCreateOpts> {
+        let Some(image) = self.image.clone() else {
+            anyhow::bail!(if self.build.is_some() {
+                format!("service {service}: building images is not supported, specify an image")
+            } else {
+                format!("service {service}: no image specified")
+            });
+        };
+
+        env.extend(
+            self.environment
+                .as_ref()
+                .map(|environment| environment.to_env(vars))
+                .unwrap_or_default(),
+        );
+
+        let mut labels = self
+            .labels
+            .as_ref()
+            .map(ListOrDict::to_map)
+            .unwrap_or_default();
+        labels.insert(PROJECT_LABEL.to_owned(), project.to_owned());
+        labels.insert(SERVICE_LABEL.to_owned(), service.to_owned());
+
+        let mut mounts = Vec::new();
+        let mut volumes = Vec::new();
+        for volume in &self.volumes {
+            let (source, target, read_only) = volume.parse()?;
+            let selinux = volume.selinux();
+
+            match source {
+                None => warnings.push(format!(
+                    "service {service}: anonymous volume {target} is not supported"
+                )),
+                Some(source)
+                    if source.starts_with('.')
+                        || source.starts_with('/')
+                        || source.starts_with('~') =>
+                {
+                    let host_path = match source.strip_prefix('~') {
+                        Some(rest) => PathBuf::from(std::env::var("HOME").unwrap_or_default())
+                            .join(rest.trim_start_matches('/')),
+                        None => dir.join(source),
+                    };
+                    mounts.push(engine::opts::ContainerCreateMountOpts {
+                        container_path: target,
+                        host_path: host_path.to_string_lossy().into_owned(),
+                        read_only,
+                        selinux,
+                    });
+                }
+                Some(source) => volumes.push(engine::opts::ContainerCreateVolumeOpts {
+                    container_path: target,
+                    read_only,
+                    selinux,
+                    volume: volume_names
+                        .get(&source)
+                        .map(|(name, _)| name.to_owned())
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("service {service}: undefined volume {source}")
+                        })?,
+                }),
+            }
+        }
+
+        let (network_mode, networks) = if pod {
+            (
+                engine::opts::ContainerCreateNetworkMode::Default,
+                Vec::new(),
+            )
+        } else {
+            match self.network_mode.as_deref() {
+                Some("host") => (engine::opts::ContainerCreateNetworkMode::Host, Vec::new()),
+                Some("none") => (engine::opts::ContainerCreateNetworkMode::None, Vec::new()),
+                Some(mode) if mode.starts_with("container:") || mode.starts_with("service:") => {
+                    let (kind, target) = mode.split_once(':').unwrap();
+                    let container = if kind == "service" {
+                        services
+                            .get(target)
+                            .map(|other| other.container_name(target, project))
+                            .ok_or_else(|| {
+                                anyhow::anyhow!("service {service}: undefined service {target}")
+                            })?
+                    } else {
+                        target.to_owned()
+                    };
+                    (
+                        engine::opts::ContainerCreateNetworkMode::Container(container),
+                        Vec::new(),
+                    )
+                }
+                _ => (
+                    engine::opts::ContainerCreateNetworkMode::Default,
+                    self.network_opts(service, network_names)?,
+                ),
+            }
+        };
+
+        Ok(engine::opts::ContainerCreateOpts {
+            cap_add: self.cap_add.clone(),
+            cap_drop: self.cap_drop.clone(),
+            cmd: self.command.clone().map(StringOrList::into_command),
+            cpu_shares: self.cpu_shares,
+            cpus: self
+                .cpus
+                .as_ref()
+                .map(|cpus| cpus.to_string().parse())
+                .transpose()
+                .map_err(|_| anyhow::anyhow!("service {service}: invalid cpus"))?,
+            cpuset_cpus: self.cpuset.clone(),
+            dns: self
+                .dns
+                .clone()
+                .map(StringOrList::into_vec)
+                .unwrap_or_default(),
+            entrypoint: self.entrypoint.clone().map(StringOrList::into_command),
+            env,
+            extra_hosts: if pod {
+                Vec::new()
+            } else {
+                self.extra_hosts
+                    .as_ref()
+                    .map(ListOrDict::to_map)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(name, ip)| engine::opts::PodHost { ip, name })
+                    .collect()
+            },
+            hostname: self.hostname.clone(),
+            image,
+            init: self.init,
+            labels,
+            memory_limit: self.mem_limit.as_ref().map(parse_bytes).transpose()?,
+            mounts,
+            name: self.container_name(service, project),
+            network_mode,
+            networks,
+            pids_limit: self.pids_limit,
+            platform: self
+                .platform
+                .as_deref()
+                .map(str::parse)
+                .transpose()
+                .map_err(|e| anyhow::anyhow!("service {service}: {e}"))?,
+            pod: pod.then(|| project.to_owned()),
+            port_mappings: if pod {
+                Vec::new()
+            } else {
+                self.port_mappings()?
+            },
+            privileged: self.privileged,
+            read_only_rootfs: self.read_only,
+            restart_policy: match self.restart.as_deref() {
+                None | Some("no") => engine::dto::RestartPolicy::No,
+                Some("always") => engine::dto::RestartPolicy::Always,
+                Some("unless-stopped") => engine::dto::RestartPolicy::UnlessStopped,
+                Some(policy) if policy.starts_with("on-failure") => {
+                    engine::dto::RestartPolicy::OnFailure
+                }
+                Some(policy) => {
+                    anyhow::bail!("service {service}: unknown restart policy {policy}")
+                }
+            },
+            security_opts: self.security_opt.clone(),
+            shm_size: self.shm_size.as_ref().map(parse_bytes).transpose()?,
+            terminal: self.tty,
+            user: self.user.clone(),
+            volumes,
+            workdir: self.working_dir.clone(),
+            ..Default::default()
+        })
+    }
+
+    fn container_name(&self, service: &str, project: &str) -> String {
+        self.container_name
+            .clone()
+            .unwrap_or_else(|| format!("{project}-{service}-1"))
+    }
+
+    fn network_opts(
+        &self,
+        service: &str,
+        network_names: &IndexMap<String, (String, ComposeNetwork)>,
+    ) -> anyhow::Result<Vec<engine::opts::ContainerCreateNetworkOpts>> {
+        let networks = match &self.networks {
+            None => vec![("default".to_owned(), None)],
+            Some(ListOrDict::List(networks)) => networks
+                .iter()
+                .map(|network| (network.to_owned(), None))
+                .collect(),
+            Some(ListOrDict::Dict(networks)) => networks
+                .iter()
+                .map(|(network, value)| {
+                    let attachment = value
+                        .clone()
+                        .map(serde_yaml_ng::from_value::<ComposeServiceNetwork>)
+                        .transpose()?;
+                    Ok((network.to_owned(), attachment))
+                })
+                .collect::<anyhow::Result<_>>()?,
+        };
+
+        networks
+            .into_iter()
+            .map(|(network, attachment)| {
+                let (name, _) = network_names.get(&network).ok_or_else(|| {
+                    anyhow::anyhow!("service {service}: undefined network {network}")
+                })?;
+                let attachment = attachment.unwrap_or_default();
+
+                Ok(engine::opts::ContainerCreateNetworkOpts {
+                    aliases: std::iter::once(service.to_owned())
+                        .chain(attachment.aliases)
+                        .collect(),
+                    ipv4_address: attachment.ipv4_address,
+                    ipv6_address: attachment.ipv6_address,
+                    network: name.to_owned(),
+                })
+            })
+            .collect()
+    }
+
+    fn port_mappings(&self) -> anyhow::Result<Vec<engine::dto::PortMapping>> {
+        self.ports
+            .iter()
+            .map(ComposePort::to_port_mappings)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map(|mappings| mappings.into_iter().flatten().collect())
+    }
+
+    fn dependencies(&self) -> Vec<String> {
+        match &self.depends_on {
+            None => Vec::new(),
+            Some(ListOrDict::List(services)) => services.clone(),
+            Some(ListOrDict::Dict(services)) => services.keys().cloned().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ComposeServiceNetwork {
+    aliases: Vec<String>,
+    ipv4_address: Option<String>,
+    ipv6_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ComposePort {
+    Short(Scalar),
+    Long {
+        target: u16,
+        published: Option<Scalar>,
+        host_ip: Option<String>,
+        protocol: Option<String>,
+    },
+}
+
+impl ComposePort {
+    /// Returns one mapping per port, so ranges like `8000-8010:8000-8010` yield several.
+    fn to_port_mappings(&self) -> anyhow::Result<Vec<engine::dto::PortMapping>> {
+        match self {
+            Self::Short(port) => {
+                let port = port.to_string();
+                let (port, protocol) = port.split_once('/').unwrap_or((&port, "tcp"));
+
+                // [[host_ip:]host_port:]container_port
+                let (host_ip, host_port, container_port) =
+                    match port.rsplitn(3, ':').collect::<Vec<_>>()[..] {
+                        [container_port] => ("", None, container_port),
+                        [container_port, host_port] => ("", Some(host_port), container_port),
+                        [container_port, host_port, host_ip] => {
+                            (host_ip, Some(host_port), container_port)
+                        }
+                        _ => unreachable!(),
+                    };
+
+                let container_ports = parse_port_range(container_port)?;
+                let host_ports = host_port
+                    .filter(|host_port| !host_port.is_empty())
+                    .map(parse_port_range)
+                    .transpose()?;
+                if let Some(host_ports) = &host_ports
+                    && host_ports.len() != container_ports.len()
+                {
+                    anyhow::bail!("port ranges of {port} differ in length");
+                }
+
+                Ok(container_ports
+                    .enumerate()
+                    .map(|(index, container_port)| engine::dto::PortMapping {
+                        container_port,
+                        host_ip: host_ip.trim_matches(['[', ']']).to_owned(),
+                        host_port: host_ports
+                            .as_ref()
+                            .map(|host_ports| host_ports.start() + index as u16),
+                        protocol: protocol.into(),
+                    })
+                    .collect())
+            }
+            Self::Long {
+                target,
+                published,
+                host_ip,
+                protocol,
+            } => Ok(vec![engine::dto::PortMapping {
+                container_port: *target,
+                host_ip: host_ip.clone().unwrap_or_default(),
+                host_port: published
+                    .as_ref()
+                    .map(|published| parse_port(&published.to_string()))
+                    .transpose()?,
+                protocol: protocol.as_deref().into(),
+            }]),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ComposeServiceVolume {
+    Short(String),
+    Long {
+        source: Option<String>,
+        target: String,
+        #[serde(default)]
+        read_only: bool,
+    },
+}
+
+impl ComposeServiceVolume {
+    /// Returns the source, the target and whether the volume is mounted read-only.
+    fn parse(&self) -> anyhow::Result<(Option<String>, String, bool)> {
+        match self {
+            Self::Short(volume) => match volume.split(':').collect::<Vec<_>>()[..] {
+                [target] => Ok((None, target.to_owned(), false)),
+                [source, target] => Ok((Some(source.to_owned()), target.to_owned(), false)),
+                [source, target, mode] => Ok((
+                    Some(source.to_owned()),
+                    target.to_owned(),
+                    mode.split(',').any(|option| option == "ro"),
+                )),
+                _ => anyhow::bail!("invalid volume {volume}"),
+            },
+            Self::Long {
+                source,
+                target,
+                read_only,
+            } => Ok((source.clone(), target.clone(), *read_only)),
+        }
+    }
+
+    fn selinux(&self) -> engine::opts::SELinux {
+        let Self::Short(volume) = self else {
+            return engine::opts::SELinux::NoLabel;
+        };
+
+        match volume.rsplit_once(':').map(|(_, mode)| mode) {
+            Some(mode) if mode.split(',').any(|option| option == "Z") => {
+                engine::opts::SELinux::Private
+            }
+            Some(mode) if mode.split(',').any(|option| option == "z") => {
+                engine::opts::SELinux::Shared
+            }
+            _ => engine::opts::SELinux::NoLabel,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum StringOrList {
+    String(String),
+    List(Vec<String>),
+}
+
+impl StringOrList {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::String(string) => vec![string],
+            Self::List(list) => list,
+        }
+    }
+
+    fn into_command(self) -> Vec<String> {
+        match self {
+            Self::String(string) => string.split_whitespace().map(ToOwned::to_owned).collect(),
+            Self::List(list) => list,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum ListOrDict {
+    List(Vec<String>),
+    Dict(IndexMap<String, Option<serde_yaml_ng::Value>>),
+}
+
+impl ListOrDict {
+    /// Converts `KEY=VALUE`/`KEY:VALUE` lists and mappings into a map.
+    fn to_map(&self) -> HashMap<String, String> {
+        match self {
+            Self::List(list) => list
+                .iter()
+                .map(|entry| {
+                    let (key, value) = entry
+                        .split_once('=')
+                        .or_else(|| entry.split_once(':'))
+                        .unwrap_or((entry, ""));
+                    (key.trim().to_owned(), value.trim().to_owned())
+                })
+                .collect(),
+            Self::Dict(dict) => dict
+                .iter()
+                .map(|(key, value)| {
+                    let value = value.as_ref().map(scalar_to_string).unwrap_or_default();
+                    (key.to_owned(), value)
+                })
+                .collect(),
+        }
+    }
+
+    /// Converts an `environment` section into a map. Unlike [`Self::to_map`], only `=` separates
+    /// keys and values, and variables without a value are taken from `vars` or left out if unset.
+    fn to_env(&self, vars: &HashMap<String, String>) -> HashMap<String, String> {
+        match self {
+            Self::List(list) => list
+                .iter()
+                .filter_map(|entry| match entry.split_once('=') {
+                    Some((key, value)) => Some((key.trim().to_owned(), value.to_owned())),
+                    None => {
+                        let key = entry.trim();
+                        vars.get(key)
+                            .map(|value| (key.to_owned(), value.to_owned()))
+                    }
+                })
+                .collect(),
+            Self::Dict(dict) => dict
+                .iter()
+                .filter_map(|(key, value)| match value {
+                    Some(value) => Some((key.to_owned(), scalar_to_string(value))),
+                    None => vars
+                        .get(key)
+                        .map(|value| (key.to_owned(), value.to_owned())),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn scalar_to_string(value: &serde_yaml_ng::Value) -> String {
+    match value {
+        serde_yaml_ng::Value::String(value) => value.to_owned(),
+        serde_yaml_ng::Value::Number(value) => value.to_string(),
+        serde_yaml_ng::Value::Bool(value) => value.to_string(),
+        _ => String::new(),
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    Int(u64),
+    Float(f64),
+    String(String),
+}
+
+impl std::fmt::Display for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Orders the services so that every service comes after its dependencies.
+fn sort_services(services: &IndexMap<String, ComposeService>) -> anyhow::Result<Vec<String>> {
+    let mut sorted = Vec::with_capacity(services.len());
+    let mut visited = HashSet::new();
+    let mut visiting = HashSet::new();
+
+    fn visit<'a>(
+        name: &'a str,
+        services: &'a IndexMap<String, ComposeService>,
+        visited: &mut HashSet<&'a str>,
+        visiting: &mut HashSet<&'a str>,
+        sorted: &mut Vec<String>,
+    ) -> anyhow::Result<()> {
+        if visited.contains(name) {
+            return Ok(());
+        }
+        if !visiting.insert(name) {
+            anyhow::bail!("cyclic dependency involving service {name}");
+        }
+
+        let (name, service) = services
+            .get_key_value(name)
+            .ok_or_else(|| anyhow::anyhow!("undefined service {name}"))?;
+        for dependency in service.dependencies() {
+            let (dependency, _) = services.get_key_value(&dependency).ok_or_else(|| {
+                anyhow::anyhow!("service {name} depends on undefined service {dependency}")
+            })?;
+            visit(dependency, services, visited, visiting, sorted)?;
+        }
+
+        visiting.remove(name.as_str());
+        visited.insert(name.as_str());
+        sorted.push(name.to_owned());
+
+        Ok(())
+    }
+
+    for name in services.keys() {
+        visit(name, services, &mut visited, &mut visiting, &mut sorted)?;
+    }
+
+    Ok(sorted)
+}
+
+/// Interpolates the string values of a parsed compose file in place. Keys, comments and the
+/// structure of the document are left untouched.
+fn interpolate_value(
+    value: &mut serde_yaml_ng::Value,
+    vars: &HashMap<String, String>,
+) -> anyhow::Result<()> {
+    match value {
+        serde_yaml_ng::Value::String(string) => *string = interpolate(string, vars)?,
+        serde_yaml_ng::Value::Sequence(sequence) => {
+            for value in sequence {
+                interpolate_value(value, vars)?;
+            }
+        }
+        serde_yaml_ng::Value::Mapping(mapping) => {
+            for (_, value) in mapping.iter_mut() {
+                interpolate_value(value, vars)?;
+            }
+        }
+        serde_yaml_ng::Value::Tagged(tagged) => interpolate_value(&mut tagged.value, vars)?,
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Replaces `$VAR`, `${VAR}`, `${VAR:-default}`, `${VAR-default}`, `${VAR:?error}` and
+/// `${VAR?error}` with the values of `vars`. `$$` escapes a literal `$`.
+fn interpolate(text: &str, vars: &HashMap<String, String>) -> anyhow::Result<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('$') => {
+                chars.next();
+                out.push('$');
+            }
+            Some('{') => {
+                chars.next();
+
+                let mut expr = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => expr.push(c),
+                        None => anyhow::bail!("unterminated variable substitution ${{{expr}"),
+                    }
+                }
+
+                out.push_str(&substitute(&expr, vars)?);
+            }
+            Some(c) if c.is_ascii_alphabetic() || *c == '_' => {
+                let mut name = String::new();
+                while let Some(c) = chars
+                    .peek()
+                    .filter(|c| c.is_ascii_alphanumeric() || **c == '_')
+                {
+                    name.push(*c);
+                    chars.next();
+                }
+
+                out.push_str(vars.get(&name).map(String::as_str).unwrap_or_default());
+            }
+            _ => out.push('$'),
+        }
+    }
+
+    Ok(out)
+}
+
+fn substitute(expr: &str, vars: &HashMap<String, String>) -> anyhow::Result<String> {
+    let set = |name: &str| vars.get(name).cloned();
+    let non_empty = |name: &str| set(name).filter(|value| !value.is_empty());
+
+    if let Some((name, default)) = expr.split_once(":-") {
+        Ok(non_empty(name).unwrap_or_else(|| default.to_owned()))
+    } else if let Some((name, error)) = expr.split_once(":?") {
+        non_empty(name).ok_or_else(|| anyhow::anyhow!("{name}: {error}"))
+    } else if let Some((name, default)) = expr.split_once('-') {
+        Ok(set(name).unwrap_or_else(|| default.to_owned()))
+    } else if let Some((name, error)) = expr.split_once('?') {
+        set(name).ok_or_else(|| anyhow::anyhow!("{name}: {error}"))
+    } else {
+        Ok(set(expr).unwrap_or_default())
+    }
+}
+
+async fn read_env_file(path: &Path) -> anyhow::Result<HashMap<String, String>> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| anyhow::anyhow!("error on reading {}: {e}", path.display()))?;
+
+    Ok(parse_env_file(&content))
+}
+
+fn parse_env_file(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| {
+            let key = key.trim().trim_start_matches("export ").trim();
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .or_else(|| {
+                    value
+                        .strip_prefix('\'')
+                        .and_then(|value| value.strip_suffix('\''))
+                })
+                .unwrap_or(value);
+            (key.to_owned(), value.to_owned())
+        })
+        .collect()
+}
+
+/// Project names may only contain lowercase letters, digits, dashes and underscores.
+fn normalize_project_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect::<String>()
+        .trim_start_matches(['-', '_'])
+        .to_owned()
+}
+
+fn parse_port(port: &str) -> anyhow::Result<u16> {
+    port.parse()
+        .map_err(|_| anyhow::anyhow!("invalid port {port}"))
+}
+
+/// Parses a single port or a range like `8000-8010`.
+fn parse_port_range(ports: &str) -> anyhow::Result<std::ops::RangeInclusive<u16>> {
+    let (start, end) = match ports.split_once('-') {
+        Some((start, end)) => (parse_port(start)?, parse_port(end)?),
+        None => {
+            let port = parse_port(ports)?;
+            (port, port)
+        }
+    };
+    if start > end {
+        anyhow::bail!("invalid port range {ports}");
+    }
+
+    Ok(start..=end)
+}
+
+/// Parses byte values like `512m` or `1g` using binary units.
+fn parse_bytes(value: &Scalar) -> anyhow::Result<u64> {
+    let value = value.to_string().to_lowercase();
+    let value = value.trim_end_matches('b');
+
+    let (number, factor) = match value.char_indices().last() {
+        Some((idx, 'k')) => (&value[..idx], 1 << 10),
+        Some((idx, 'm')) => (&value[..idx], 1 << 20),
+        Some((idx, 'g')) => (&value[..idx], 1 << 30),
+        _ => (value, 1),
+    };
+
+    number
+        .trim()
+        .parse::<u64>()
+        .map(|number| number * factor)
+        .map_err(|_| anyhow::anyhow!("invalid byte value {value}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn vars() -> HashMap<String, String> {
+        HashMap::from([
+            ("TAG".to_owned(), "1.2".to_owned()),
+            ("EMPTY".to_owned(), String::new()),
+        ])
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let vars = vars();
+
+        assert_eq!(interpolate("app:$TAG", &vars).unwrap(), "app:1.2");
+        assert_eq!(interpolate("app:${TAG}-dev", &vars).unwrap(), "app:1.2-dev");
+        assert_eq!(
+            interpolate("$$TAG costs $5", &vars).unwrap(),
+            "$TAG costs $5"
+        );
+        assert_eq!(interpolate("${UNSET}", &vars).unwrap(), "");
+        assert!(interpolate("${TAG", &vars).is_err());
+    }
+
+    #[test]
+    fn test_substitute() {
+        let vars = vars();
+
+        assert_eq!(substitute("TAG:-latest", &vars).unwrap(), "1.2");
+        assert_eq!(substitute("EMPTY:-latest", &vars).unwrap(), "latest");
+        assert_eq!(substitute("EMPTY-latest", &vars).unwrap(), "");
+        assert_eq!(substitute("UNSET-latest", &vars).unwrap(), "latest");
+        assert!(substitute("EMPTY:?required", &vars).is_err());
+        assert_eq!(substitute("EMPTY?required", &vars).unwrap(), "");
+        assert!(substitute("UNSET?required", &vars).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_value() {
+        let mut value = serde_yaml_ng::from_str::<serde_yaml_ng::Value>(
+            "# uses $TAG\nservices:\n  app:\n    image: app:${TAG}\n    command: [echo, $$HOME]\n",
+        )
+        .unwrap();
+        interpolate_value(&mut value, &vars()).unwrap();
+
+        let compose = serde_yaml_ng::from_value::<ComposeFile>(value).unwrap();
+        let app = &compose.services["app"];
+        assert_eq!(app.image.as_deref(), Some("app:1.2"));
+        assert_eq!(
+            app.command.clone().map(StringOrList::into_command),
+            Some(vec!["echo".to_owned(), "$HOME".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_to_env() {
+        let vars = vars();
+
+        let list = ListOrDict::List(vec![
+            "URL=http://localhost:8080".to_owned(),
+            "TAG".to_owned(),
+            "UNSET".to_owned(),
+        ]);
+        assert_eq!(
+            list.to_env(&vars),
+            HashMap::from([
+                ("URL".to_owned(), "http://localhost:8080".to_owned()),
+                ("TAG".to_owned(), "1.2".to_owned()),
+            ])
+        );
+
+        let dict = ListOrDict::Dict(IndexMap::from([
+            ("PORT".to_owned(), Some(serde_yaml_ng::Value::from(8080))),
+            ("TAG".to_owned(), None),
+        ]));
+        assert_eq!(
+            dict.to_env(&vars),
+            HashMap::from([
+                ("PORT".to_owned(), "8080".to_owned()),
+                ("TAG".to_owned(), "1.2".to_owned()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sort_services() {
+        let service = |dependencies: &[&str]| ComposeService {
+            depends_on: Some(ListOrDict::List(
+                dependencies.iter().map(|s| s.to_string()).collect(),
+            )),
+            ..Default::default()
+        };
+
+        let services = IndexMap::from([
+            ("web".to_owned(), service(&["api"])),
+            ("api".to_owned(), service(&["db"])),
+            ("db".to_owned(), service(&[])),
+        ]);
+        assert_eq!(sort_services(&services).unwrap(), ["db", "api", "web"]);
+
+        let services = IndexMap::from([
+            ("a".to_owned(), service(&["b"])),
+            ("b".to_owned(), service(&["a"])),
+        ]);
+        assert!(sort_services(&services).is_err());
+
+        let services = IndexMap::from([("a".to_owned(), service(&["missing"]))]);
+        assert!(sort_services(&services).is_err());
+    }
+
+    #[test]
+    fn test_to_port_mappings() {
+        let ports = |port: &str| {
+            ComposePort::Short(Scalar::String(port.to_owned()))
+                .to_port_mappings()
+                .unwrap()
+                .into_iter()
+                .map(|mapping| (mapping.host_ip, mapping.host_port, mapping.container_port))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ports("80"), [(String::new(), None, 80)]);
+        assert_eq!(ports("8080:80"), [(String::new(), Some(8080), 80)]);
+        assert_eq!(
+            ports("127.0.0.1:8080:80/udp"),
+            [("127.0.0.1".to_owned(), Some(8080), 80)]
+        );
+        assert_eq!(ports("[::1]:8080:80"), [("::1".to_owned(), Some(8080), 80)]);
+        assert_eq!(
+            ports("9000-9002:8000-8002"),
+            [
+                (String::new(), Some(9000), 8000),
+                (String::new(), Some(9001), 8001),
+                (String::new(), Some(9002), 8002),
+            ]
+        );
+        assert_eq!(
+            ports("8000-8001"),
+            [(String::new(), None, 8000), (String::new(), None, 8001)]
+        );
+
+        assert!(
+            ComposePort::Short(Scalar::String("9000-9001:8000-8002".to_owned()))
+                .to_port_mappings()
+                .is_err()
+        );
+        assert!(
+            ComposePort::Short(Scalar::String("8002-8000".to_owned()))
+                .to_port_mappings()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        assert_eq!(parse_bytes(&Scalar::Int(1024)).unwrap(), 1024);
+        assert_eq!(parse_bytes(&Scalar::String("2k".to_owned())).unwrap(), 2048);
+        assert_eq!(
+            parse_bytes(&Scalar::String("512MB".to_owned())).unwrap(),
+            512 << 20
+        );
+        assert_eq!(
+            parse_bytes(&Scalar::String("1g".to_owned())).unwrap(),
+            1 << 30
+        );
+        assert!(parse_bytes(&Scalar::String("1t".to_owned())).is_err());
+    }
+
+    #[test]
+    fn test_parse_env_file() {
+        assert_eq!(
+            parse_env_file(
+                "# comment\n\nexport A=1\nB = \"two words\"\nC='single'\nD=x=y\nINVALID\n"
+            ),
+            HashMap::from([
+                ("A".to_owned(), "1".to_owned()),
+                ("B".to_owned(), "two words".to_owned()),
+                ("C".to_owned(), "single".to_owned()),
+                ("D".to_owned(), "x=y".to_owned()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_normalize_project_name() {
+        assert_eq!(normalize_project_name("My App"), "myapp");
+        assert_eq!(normalize_project_name("_web-1.0"), "web-10");
+        assert_eq!(normalize_project_name("Über"), "ber");
+    }
+}
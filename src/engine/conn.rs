@@ -1,12 +1,18 @@
+use std::path::PathBuf;
 use std::pin::Pin;
+use std::pin::pin;
 
 use bytes::Bytes;
 use futures::AsyncWrite;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
+use futures::stream;
+use futures::stream::BoxStream;
 use tokio_util::compat::TokioAsyncWriteCompatExt;
 
+use crate::engine;
+
 pub(crate) enum ExecStart {
     Attached(Multiplexer),
     Detached,
@@ -115,3 +121,48 @@ impl From<podman_api::conn::TtyChunk> for TtyChunk {
         }
     }
 }
+
+const UPLOAD_CHUNK_SIZE: usize = 256 * 1024;
+
+/// Streams the file at `path` into the request built by `send` instead of reading it into memory
+/// first, reporting the bytes sent until the engine has answered.
+pub(crate) fn upload<'a, T, F, Fut>(
+    path: PathBuf,
+    send: F,
+) -> BoxStream<'a, anyhow::Result<engine::dto::UploadReport<T>>>
+where
+    T: Send + 'a,
+    F: FnOnce(BoxStream<'static, std::io::Result<Bytes>>) -> Fut + Send + 'a,
+    Fut: Future<Output = anyhow::Result<T>> + Send + 'a,
+{
+    async_stream::try_stream! {
+        let file = tokio::fs::File::open(path).await?;
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut sent = 0;
+        let body = tokio_util::io::ReaderStream::with_capacity(file, UPLOAD_CHUNK_SIZE)
+            .inspect_ok(move |chunk| {
+                sent += chunk.len() as u64;
+                let _ = tx.send(sent);
+            })
+            .boxed();
+
+        let mut reports = pin!(stream::select(
+            tokio_stream::wrappers::UnboundedReceiverStream::new(rx)
+                .map(|sent| Ok(engine::dto::UploadReport::Sending { sent })),
+            stream::once(send(body)).map_ok(engine::dto::UploadReport::Finished),
+        ));
+
+        while let Some(report) = reports.next().await {
+            let report = report?;
+            let finished = matches!(report, engine::dto::UploadReport::Finished(_));
+
+            yield report;
+
+            if finished {
+                break;
+            }
+        }
+    }
+    .boxed()
+}
@@ -0,0 +1,231 @@
+//! Browsing of the file system of containers. Directories of running containers are listed by a
+//! shell inside the container. Stopped containers and containers without a shell are read through
+//! the archive endpoint, which has to transfer the whole directory tree.
+
+use std::collections::HashMap;
+use std::io;
+
+use futures::StreamExt;
+use futures::TryStreamExt;
+use gtk::glib;
+use tokio::io::AsyncReadExt;
+use tokio_util::io::StreamReader;
+
+use crate::engine;
+
+/// Prints one `stat` line per directory entry followed by the file types of the entries with
+/// symbolic links resolved. Works with GNU coreutils and BusyBox.
+const LIST_DIR_SCRIPT: &str = r#"cd -- "$1" || exit 1
+shift
+for f in .[!.]* ..?* *; do
+    if [ -e "$f" ] || [ -L "$f" ]; then set -- "$@" "$f"; fi
+done
+[ "$#" -eq 0 ] && exit 0
+stat -c 'S|%f|%s|%u|%g|%U|%G|%Y|%n' -- "$@"
+stat -L -c 'L|%f|%n' -- "$@" 2>/dev/null
+exit 0
+"#;
+
+impl engine::api::Container {
+    /// Lists the entries of a directory with directories first.
+    pub(crate) async fn list_dir(
+        &self,
+        path: String,
+        running: bool,
+    ) -> anyhow::Result<Vec<engine::dto::ContainerFile>> {
+        let mut files = if running {
+            match self.list_dir_with_shell(&path).await {
+                Ok(files) => files,
+                Err(e) => {
+                    log::debug!("Falling back to archive for listing '{path}': {e}");
+                    self.list_dir_from_archive(path).await?
+                }
+            }
+        } else {
+            self.list_dir_from_archive(path).await?
+        };
+
+        files.sort_by(|lhs, rhs| {
+            rhs.is_dir
+                .cmp(&lhs.is_dir)
+                .then_with(|| lhs.name.cmp(&rhs.name))
+        });
+
+        Ok(files)
+    }
+
+    /// Reads a regular file, which must not be larger than `max_size`.
+    pub(crate) async fn read_file(
+        &self,
+        path: String,
+        max_size: u64,
+    ) -> anyhow::Result<(engine::dto::ContainerFile, Vec<u8>)> {
+        let stream = self.copy_from(path).map_err(io::Error::other);
+
+        let mut archive = tokio_tar::Archive::new(StreamReader::new(stream));
+        let mut entries = archive.entries()?;
+
+        let mut entry = entries
+            .next()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("archive is empty"))??;
+
+        let name = entry.path()?.to_string_lossy().into_owned();
+        let file = engine::dto::ContainerFile::from_tar_header(name, entry.header())?;
+
+        if file.kind != engine::dto::ContainerFileKind::File {
+            anyhow::bail!("not a regular file");
+        }
+        if file.size > max_size {
+            anyhow::bail!("file is larger than {max_size} bytes");
+        }
+
+        let mut content = Vec::with_capacity(file.size as usize);
+        entry.read_to_end(&mut content).await?;
+
+        Ok((file, content))
+    }
+
+    /// Replaces the content of a file in `dir` while keeping the mode and ownership given by
+    /// `file`.
+    pub(crate) async fn write_file(
+        &self,
+        dir: String,
+        file: &engine::dto::ContainerFile,
+        content: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        let mut header = tokio_tar::Header::new_gnu();
+        header.set_entry_type(tokio_tar::EntryType::Regular);
+        header.set_size(content.len() as u64);
+        header.set_mode(file.mode);
+        header.set_uid(file.uid);
+        header.set_gid(file.gid);
+        header.set_mtime(glib::real_time() as u64 / 1_000_000);
+
+        let mut builder = tokio_tar::Builder::new(Vec::new());
+        builder
+            .append_data(&mut header, &file.name, content.as_slice())
+            .await?;
+
+        self.copy_to(dir, builder.into_inner().await?).await
+    }
+
+    /// Removes a file or a directory with all its content.
+    pub(crate) async fn remove_path(&self, path: String) -> anyhow::Result<()> {
+        self.exec_output(vec!["rm".into(), "-rf".into(), "--".into(), path])
+            .await
+            .map(drop)
+    }
+
+    pub(crate) async fn rename_path(&self, from: String, to: String) -> anyhow::Result<()> {
+        self.exec_output(vec!["mv".into(), "--".into(), from, to])
+            .await
+            .map(drop)
+    }
+
+    async fn list_dir_with_shell(
+        &self,
+        path: &str,
+    ) -> anyhow::Result<Vec<engine::dto::ContainerFile>> {
+        let output = self
+            .exec_output(vec![
+                "sh".into(),
+                "-c".into(),
+                LIST_DIR_SCRIPT.into(),
+                "sh".into(),
+                path.to_owned(),
+            ])
+            .await?;
+
+        let mut files = Vec::new();
+        let mut resolved_modes = HashMap::new();
+
+        output.lines().for_each(|line| {
+            if let Some(line) = line.strip_prefix("S|") {
+                files.extend(engine::dto::ContainerFile::parse_stat(line));
+            } else if let Some((mode, name)) = line
+                .strip_prefix("L|")
+                .and_then(|line| line.split_once('|'))
+                && let Ok(mode) = u32::from_str_radix(mode, 16)
+            {
+                resolved_modes.insert(name, mode);
+            }
+        });
+
+        files
+            .iter_mut()
+            .filter(|file| file.kind == engine::dto::ContainerFileKind::Symlink)
+            .for_each(|file| {
+                file.is_dir = resolved_modes.get(file.name.as_str()).is_some_and(|mode| {
+                    engine::dto::ContainerFileKind::from_mode(*mode)
+                        == engine::dto::ContainerFileKind::Directory
+                });
+            });
+
+        Ok(files)
+    }
+
+    async fn list_dir_from_archive(
+        &self,
+        path: String,
+    ) -> anyhow::Result<Vec<engine::dto::ContainerFile>> {
+        let stream = self.copy_from(path).map_err(io::Error::other);
+
+        let mut archive = tokio_tar::Archive::new(StreamReader::new(stream));
+        let mut entries = archive.entries()?;
+
+        let mut files = Vec::new();
+        while let Some(entry) = entries.next().await {
+            let entry = entry?;
+
+            // The first component of each path is the listed directory itself.
+            let path = entry.path()?;
+            let mut components = path.components().skip(1);
+            let (Some(name), None) = (components.next(), components.next()) else {
+                continue;
+            };
+
+            files.push(engine::dto::ContainerFile::from_tar_header(
+                name.as_os_str().to_string_lossy().into_owned(),
+                entry.header(),
+            )?);
+        }
+
+        Ok(files)
+    }
+
+    /// Runs a command and returns its standard output. Because the exit code is not available,
+    /// any output on standard error is treated as failure.
+    async fn exec_output(&self, command: Vec<String>) -> anyhow::Result<String> {
+        let exec = self
+            .create_exec(engine::opts::ExecCreateOpts {
+                attach_stderr: Some(true),
+                attach_stdout: Some(true),
+                command,
+                tty: Some(false),
+                ..Default::default()
+            })
+            .await?;
+
+        let Some(multiplexer) = exec.start(false).await?.into_attached() else {
+            anyhow::bail!("exec is not attached");
+        };
+        let (mut reader, _) = multiplexer.split();
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        while let Some(chunk) = reader.try_next().await? {
+            match chunk {
+                engine::conn::TtyChunk::StdOut(buf) => stdout.extend_from_slice(&buf),
+                engine::conn::TtyChunk::StdErr(buf) => stderr.extend_from_slice(&buf),
+                engine::conn::TtyChunk::StdIn(_) => {}
+            }
+        }
+
+        if !stderr.is_empty() {
+            anyhow::bail!("{}", String::from_utf8_lossy(&stderr).trim());
+        }
+
+        Ok(String::from_utf8_lossy(&stdout).into_owned())
+    }
+}
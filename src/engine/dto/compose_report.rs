@@ -0,0 +1,17 @@
+pub(crate) enum ComposeReport {
+    /// The compose file has been resolved into the given number of steps.
+    Planned {
+        project: String,
+        steps: u32,
+    },
+    /// A new step has been started.
+    Step {
+        description: String,
+    },
+    Streaming {
+        line: String,
+    },
+    Finished {
+        pod_id: Option<String>,
+    },
+}
@@ -17,17 +17,31 @@ impl Container {
             Self::Inspection(dto) => &dto.summary.id,
         }
     }
+
+    pub(crate) fn is_volume_helper(&self) -> bool {
+        match self {
+            Self::Summary(dto) => dto.is_volume_helper,
+            Self::Inspection(dto) => dto.summary.is_volume_helper,
+        }
+    }
 }
 
 pub(crate) struct ContainerSummary {
+    /// value of the `com.docker.compose.project` label
+    pub(crate) compose_project: Option<String>,
+    /// value of the `com.docker.compose.service` label
+    pub(crate) compose_service: Option<String>,
     pub(crate) created: i64,
     pub(crate) health_status: engine::dto::HealthStatus,
     pub(crate) id: String,
     pub(crate) image_id: String,
     pub(crate) image_name: Option<String>,
     pub(crate) is_infra: bool,
+    /// whether this is a short-lived container for accessing volume content
+    pub(crate) is_volume_helper: bool,
     pub(crate) mounts: Vec<engine::dto::Mount>,
     pub(crate) name: String,
+    pub(crate) networks: Vec<String>,
     pub(crate) pod_id: Option<String>,
     pub(crate) ports: Vec<engine::dto::PortMapping>,
     pub(crate) status: engine::dto::ContainerStatus,
@@ -35,13 +49,18 @@ pub(crate) struct ContainerSummary {
 
 impl From<bollard::plugin::ContainerSummary> for ContainerSummary {
     fn from(value: bollard::plugin::ContainerSummary) -> Self {
+        let (compose_project, compose_service) = compose_labels(value.labels.as_ref());
+
         Self {
+            compose_project,
+            compose_service,
             created: value.created.unwrap_or_default(),
             health_status: value.health.and_then(|health| health.status).into(),
             id: value.id.unwrap(),
             image_id: value.image_id.unwrap_or_default(),
             image_name: value.image.filter(|name| !name.is_empty()),
             is_infra: false,
+            is_volume_helper: is_volume_helper(value.labels.as_ref()),
             mounts: value
                 .mounts
                 .map(|mounts| mounts.into_iter().map(Into::into).collect())
@@ -62,6 +81,11 @@ impl From<bollard::plugin::ContainerSummary> for ContainerSummary {
                     name
                 })
                 .unwrap_or_default(),
+            networks: value
+                .network_settings
+                .and_then(|settings| settings.networks)
+                .map(|networks| networks.into_keys().collect())
+                .unwrap_or_default(),
             pod_id: None,
             ports: value
                 .ports
@@ -74,7 +98,11 @@ impl From<bollard::plugin::ContainerSummary> for ContainerSummary {
 
 impl From<podman_api::models::ListContainer> for ContainerSummary {
     fn from(value: podman_api::models::ListContainer) -> Self {
+        let (compose_project, compose_service) = compose_labels(value.labels.as_ref());
+
         Self {
+            compose_project,
+            compose_service,
             created: value
                 .created
                 .map(|date_time| date_time.timestamp())
@@ -84,6 +112,7 @@ impl From<podman_api::models::ListContainer> for ContainerSummary {
             image_id: value.image_id.unwrap_or_default(),
             image_name: value.image.filter(|name| !name.is_empty()),
             is_infra: value.is_infra.unwrap_or(false),
+            is_volume_helper: is_volume_helper(value.labels.as_ref()),
             // mounts are missing in a podman summary
             mounts: Vec::new(),
             name: value
@@ -96,6 +125,7 @@ impl From<podman_api::models::ListContainer> for ContainerSummary {
                     }
                 })
                 .unwrap_or_default(),
+            networks: value.networks.unwrap_or_default(),
             pod_id: value.pod,
             ports: value
                 .ports
@@ -106,10 +136,26 @@ impl From<podman_api::models::ListContainer> for ContainerSummary {
     }
 }
 
+fn compose_labels(labels: Option<&HashMap<String, String>>) -> (Option<String>, Option<String>) {
+    labels
+        .map(|labels| {
+            (
+                labels.get(engine::compose::PROJECT_LABEL).cloned(),
+                labels.get(engine::compose::SERVICE_LABEL).cloned(),
+            )
+        })
+        .unwrap_or_default()
+}
+
+fn is_volume_helper(labels: Option<&HashMap<String, String>>) -> bool {
+    labels.is_some_and(|labels| labels.contains_key(engine::volume_files::HELPER_LABEL))
+}
+
 pub(crate) struct ContainerDetails {
     pub(crate) health_config: Option<HealthConfig>,
     pub(crate) health_failing_streak: u32,
     pub(crate) health_check_logs: Vec<HealthCheckLog>,
+    pub(crate) resource_limits: ResourceLimits,
     pub(crate) restart_policy: RestartPolicy,
     pub(crate) size: i64,
     pub(crate) up_since: i64,
@@ -148,8 +194,22 @@ impl ContainerInspection {
             })
             .unwrap_or_default();
 
+        let (networks, ports) = inspection
+            .network_settings
+            .map(|settings| (settings.networks, settings.ports))
+            .unwrap_or_default();
+
+        let labels = inspection
+            .config
+            .as_ref()
+            .and_then(|config| config.labels.as_ref());
+        let (compose_project, compose_service) = compose_labels(labels);
+        let is_volume_helper = is_volume_helper(labels);
+
         Self {
             summary: engine::dto::ContainerSummary {
+                compose_project,
+                compose_service,
                 created: inspection
                     .created
                     .and_then(|created| glib::DateTime::from_iso8601(&created, None).ok())
@@ -160,6 +220,7 @@ impl ContainerInspection {
                 image_id: inspection.image.unwrap_or_default(),
                 image_name: image_name.filter(|name| !name.is_empty()),
                 is_infra: false,
+                is_volume_helper,
                 mounts: inspection
                     .mounts
                     .map(|mounts| mounts.into_iter().map(Into::into).collect())
@@ -173,10 +234,11 @@ impl ContainerInspection {
                         name
                     })
                     .unwrap_or_default(),
+                networks: networks
+                    .map(|networks| networks.into_keys().collect())
+                    .unwrap_or_default(),
                 pod_id: None,
-                ports: inspection
-                    .network_settings
-                    .and_then(|settings| settings.ports)
+                ports: ports
                     .map(PortMappings::from)
                     .map(PortMappings::into_inner)
                     .unwrap_or_default(),
@@ -189,6 +251,11 @@ impl ContainerInspection {
                     .map(Into::into),
                 health_failing_streak,
                 health_check_logs: health_check_logs.into_iter().map(Into::into).collect(),
+                resource_limits: inspection
+                    .host_config
+                    .as_ref()
+                    .map(Into::into)
+                    .unwrap_or_default(),
                 restart_policy: inspection
                     .host_config
                     .and_then(|host_config| host_config.restart_policy)
@@ -228,8 +295,22 @@ impl From<podman_api::models::ContainerInspectResponseLibpod> for ContainerInspe
             })
             .unwrap_or_default();
 
+        let (networks, ports) = value
+            .network_settings
+            .map(|settings| (settings.networks, settings.ports))
+            .unwrap_or_default();
+
+        let labels = value
+            .config
+            .as_ref()
+            .and_then(|config| config.labels.as_ref());
+        let (compose_project, compose_service) = compose_labels(labels);
+        let is_volume_helper = is_volume_helper(labels);
+
         Self {
             summary: engine::dto::ContainerSummary {
+                compose_project,
+                compose_service,
                 created: value
                     .created
                     .map(|date_time| date_time.timestamp())
@@ -239,15 +320,17 @@ impl From<podman_api::models::ContainerInspectResponseLibpod> for ContainerInspe
                 image_id: value.image.unwrap_or_default(),
                 image_name: value.image_name.filter(|name| !name.is_empty()),
                 is_infra: value.is_infra.unwrap_or(false),
+                is_volume_helper,
                 mounts: value
                     .mounts
                     .map(|mounts| mounts.into_iter().map(Into::into).collect())
                     .unwrap_or_default(),
                 name: value.name.unwrap_or_default(),
+                networks: networks
+                    .map(|networks| networks.into_keys().collect())
+                    .unwrap_or_default(),
                 pod_id: value.pod,
-                ports: value
-                    .network_settings
-                    .and_then(|settings| settings.ports)
+                ports: ports
                     .map(PortMappings::from)
                     .map(PortMappings::into_inner)
                     .unwrap_or_default(),
@@ -260,6 +343,11 @@ impl From<podman_api::models::ContainerInspectResponseLibpod> for ContainerInspe
                     .map(Into::into),
                 health_failing_streak,
                 health_check_logs: health_check_logs.into_iter().map(Into::into).collect(),
+                resource_limits: value
+                    .host_config
+                    .as_ref()
+                    .map(Into::into)
+                    .unwrap_or_default(),
                 restart_policy: value
                     .host_config
                     .and_then(|host_config| host_config.restart_policy)
@@ -650,3 +738,46 @@ impl From<RestartPolicy> for podman_api::opts::ContainerRestartPolicy {
         }
     }
 }
+
+/// Resource limits of a container. A value of `0` means that no limit is set.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ResourceLimits {
+    pub(crate) blkio_weight: u16,
+    pub(crate) cpu_period: i64,
+    pub(crate) cpu_quota: i64,
+    pub(crate) cpu_shares: i64,
+    pub(crate) cpuset_cpus: String,
+    pub(crate) memory: i64,
+    pub(crate) memory_swap: i64,
+    pub(crate) pids_limit: i64,
+}
+
+impl From<&bollard::plugin::HostConfig> for ResourceLimits {
+    fn from(value: &bollard::plugin::HostConfig) -> Self {
+        Self {
+            blkio_weight: value.blkio_weight.unwrap_or(0),
+            cpu_period: value.cpu_period.unwrap_or(0),
+            cpu_quota: value.cpu_quota.unwrap_or(0),
+            cpu_shares: value.cpu_shares.unwrap_or(0),
+            cpuset_cpus: value.cpuset_cpus.clone().unwrap_or_default(),
+            memory: value.memory.unwrap_or(0),
+            memory_swap: value.memory_swap.unwrap_or(0),
+            pids_limit: value.pids_limit.unwrap_or(0),
+        }
+    }
+}
+
+impl From<&podman_api::models::InspectContainerHostConfig> for ResourceLimits {
+    fn from(value: &podman_api::models::InspectContainerHostConfig) -> Self {
+        Self {
+            blkio_weight: value.blkio_weight.unwrap_or(0),
+            cpu_period: value.cpu_period.unwrap_or(0) as i64,
+            cpu_quota: value.cpu_quota.unwrap_or(0),
+            cpu_shares: value.cpu_shares.unwrap_or(0) as i64,
+            cpuset_cpus: value.cpuset_cpus.clone().unwrap_or_default(),
+            memory: value.memory.unwrap_or(0),
+            memory_swap: value.memory_swap.unwrap_or(0),
+            pids_limit: value.pids_limit.unwrap_or(0),
+        }
+    }
+}
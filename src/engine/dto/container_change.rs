@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+/// A path of a container that differs from its image.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ContainerChange {
+    pub(crate) path: String,
+    pub(crate) kind: ContainerChangeKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ContainerChangeKind {
+    Changed,
+    Added,
+    Deleted,
+}
+
+impl ContainerChangeKind {
+    /// Maps the numeric kinds used by both engines.
+    fn from_code(code: u8) -> Self {
+        match code {
+            1 => Self::Added,
+            2 => Self::Deleted,
+            _ => Self::Changed,
+        }
+    }
+}
+
+impl From<bollard::models::FilesystemChange> for ContainerChange {
+    fn from(value: bollard::models::FilesystemChange) -> Self {
+        Self {
+            path: value.path,
+            kind: ContainerChangeKind::from_code(value.kind as u8),
+        }
+    }
+}
+
+impl From<podman_api::models::ContainerChangeResponseItem> for ContainerChange {
+    fn from(value: podman_api::models::ContainerChangeResponseItem) -> Self {
+        Self {
+            path: value.path,
+            kind: ContainerChangeKind::from_code(value.kind),
+        }
+    }
+}
+
+/// A component of a path in the tree of changes.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ContainerChangeNode {
+    pub(crate) name: String,
+    /// the change of this path, if it is not only the parent of changed paths
+    pub(crate) kind: Option<ContainerChangeKind>,
+    pub(crate) children: Vec<ContainerChangeNode>,
+}
+
+impl ContainerChangeNode {
+    /// Arranges changes as a tree of path components sorted by name.
+    pub(crate) fn tree<'a>(changes: impl IntoIterator<Item = &'a ContainerChange>) -> Vec<Self> {
+        #[derive(Default)]
+        struct Node<'a> {
+            kind: Option<ContainerChangeKind>,
+            children: BTreeMap<&'a str, Node<'a>>,
+        }
+
+        impl Node<'_> {
+            fn into_children(self) -> Vec<ContainerChangeNode> {
+                self.children
+                    .into_iter()
+                    .map(|(name, node)| ContainerChangeNode {
+                        name: name.to_owned(),
+                        kind: node.kind,
+                        children: node.into_children(),
+                    })
+                    .collect()
+            }
+        }
+
+        let mut root = Node::default();
+        changes.into_iter().for_each(|change| {
+            let node = change
+                .path
+                .split('/')
+                .filter(|component| !component.is_empty())
+                .fold(&mut root, |node, component| {
+                    node.children.entry(component).or_default()
+                });
+            node.kind = Some(change.kind);
+        });
+
+        root.into_children()
+    }
+
+    /// Number of changes in this node and all its descendants.
+    pub(crate) fn count(&self) -> usize {
+        usize::from(self.kind.is_some())
+            + self
+                .children
+                .iter()
+                .map(ContainerChangeNode::count)
+                .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tree() {
+        let change = |path: &str, kind| ContainerChange {
+            path: path.to_owned(),
+            kind,
+        };
+        let changes = [
+            change("/etc", ContainerChangeKind::Changed),
+            change("/etc/nginx/nginx.conf", ContainerChangeKind::Changed),
+            change("/etc/hosts.bak", ContainerChangeKind::Added),
+            change("/tmp/cache", ContainerChangeKind::Deleted),
+        ];
+
+        let tree = ContainerChangeNode::tree(&changes);
+        assert_eq!(tree.len(), 2);
+
+        let etc = &tree[0];
+        assert_eq!(etc.name, "etc");
+        assert_eq!(etc.kind, Some(ContainerChangeKind::Changed));
+        assert_eq!(etc.count(), 3);
+        assert_eq!(
+            etc.children
+                .iter()
+                .map(|node| node.name.as_str())
+                .collect::<Vec<_>>(),
+            ["hosts.bak", "nginx"]
+        );
+        assert_eq!(etc.children[1].kind, None);
+        assert_eq!(etc.children[1].children[0].name, "nginx.conf");
+
+        let tmp = &tree[1];
+        assert_eq!(tmp.kind, None);
+        assert_eq!(tmp.children[0].kind, Some(ContainerChangeKind::Deleted));
+    }
+}
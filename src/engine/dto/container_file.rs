@@ -0,0 +1,162 @@
+use std::io;
+
+/// An entry of a directory inside a container.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ContainerFile {
+    pub(crate) name: String,
+    pub(crate) kind: ContainerFileKind,
+    /// whether the entry is a directory or a symbolic link pointing to one
+    pub(crate) is_dir: bool,
+    pub(crate) size: u64,
+    /// permission bits including the setuid, setgid and sticky bits
+    pub(crate) mode: u32,
+    pub(crate) uid: u64,
+    pub(crate) gid: u64,
+    pub(crate) user: String,
+    pub(crate) group: String,
+    /// unix timestamp of the last modification
+    pub(crate) modified: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ContainerFileKind {
+    Directory,
+    File,
+    Symlink,
+    Other,
+}
+
+impl ContainerFileKind {
+    pub(crate) fn from_mode(mode: u32) -> Self {
+        match mode & 0o170000 {
+            0o040000 => Self::Directory,
+            0o100000 => Self::File,
+            0o120000 => Self::Symlink,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl ContainerFile {
+    /// Parses a line printed by `stat -c '%f|%s|%u|%g|%U|%G|%Y|%n'`.
+    pub(crate) fn parse_stat(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(8, '|');
+
+        let mode = u32::from_str_radix(fields.next()?, 16).ok()?;
+        let size = fields.next()?.parse().ok()?;
+        let uid = fields.next()?.parse().ok()?;
+        let gid = fields.next()?.parse().ok()?;
+        let user = fields.next()?.to_owned();
+        let group = fields.next()?.to_owned();
+        let modified = fields.next()?.parse().ok()?;
+        let name = fields.next()?.to_owned();
+
+        let kind = ContainerFileKind::from_mode(mode);
+
+        Some(Self {
+            name,
+            kind,
+            is_dir: kind == ContainerFileKind::Directory,
+            size,
+            mode: mode & 0o7777,
+            uid,
+            gid,
+            user,
+            group,
+            modified,
+        })
+    }
+
+    pub(crate) fn from_tar_header(name: String, header: &tokio_tar::Header) -> io::Result<Self> {
+        let entry_type = header.entry_type();
+        let kind = if entry_type.is_dir() {
+            ContainerFileKind::Directory
+        } else if entry_type.is_file() {
+            ContainerFileKind::File
+        } else if entry_type.is_symlink() {
+            ContainerFileKind::Symlink
+        } else {
+            ContainerFileKind::Other
+        };
+
+        Ok(Self {
+            name,
+            kind,
+            is_dir: kind == ContainerFileKind::Directory,
+            size: header.size()?,
+            mode: header.mode()? & 0o7777,
+            uid: header.uid()?,
+            gid: header.gid()?,
+            user: match header.username() {
+                Ok(Some(user)) if !user.is_empty() => user.to_owned(),
+                _ => header.uid()?.to_string(),
+            },
+            group: match header.groupname() {
+                Ok(Some(group)) if !group.is_empty() => group.to_owned(),
+                _ => header.gid()?.to_string(),
+            },
+            modified: header.mtime()? as i64,
+        })
+    }
+
+    /// The permissions in the symbolic notation of `ls`, e.g. `drwxr-xr-x`.
+    pub(crate) fn permissions(&self) -> String {
+        let kind = match self.kind {
+            ContainerFileKind::Directory => 'd',
+            ContainerFileKind::Symlink => 'l',
+            ContainerFileKind::File | ContainerFileKind::Other => '-',
+        };
+
+        let special = [(0o4000, 's'), (0o2000, 's'), (0o1000, 't')];
+
+        std::iter::once(kind)
+            .chain((0..3).flat_map(|class| {
+                let bits = (self.mode >> (6 - class * 3)) & 0o7;
+                let (special_bit, special_char) = special[class as usize];
+                let special = self.mode & special_bit != 0;
+
+                [
+                    if bits & 0o4 != 0 { 'r' } else { '-' },
+                    if bits & 0o2 != 0 { 'w' } else { '-' },
+                    match (bits & 0o1 != 0, special) {
+                        (true, true) => special_char,
+                        (false, true) => special_char.to_ascii_uppercase(),
+                        (true, false) => 'x',
+                        (false, false) => '-',
+                    },
+                ]
+            }))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_stat() {
+        let file = ContainerFile::parse_stat("41ed|4096|0|0|root|root|1700000000|my|dir").unwrap();
+        assert_eq!(file.name, "my|dir");
+        assert_eq!(file.kind, ContainerFileKind::Directory);
+        assert!(file.is_dir);
+        assert_eq!(file.mode, 0o755);
+        assert_eq!(file.permissions(), "drwxr-xr-x");
+
+        let file =
+            ContainerFile::parse_stat("81a4|12|1000|1000|app|app|1700000000|README").unwrap();
+        assert_eq!(file.kind, ContainerFileKind::File);
+        assert_eq!(file.size, 12);
+        assert_eq!((file.uid, file.gid), (1000, 1000));
+        assert_eq!(file.permissions(), "-rw-r--r--");
+
+        let file = ContainerFile::parse_stat("a1ff|7|0|0|root|root|1700000000|bin").unwrap();
+        assert_eq!(file.kind, ContainerFileKind::Symlink);
+        assert!(!file.is_dir);
+
+        let file = ContainerFile::parse_stat("43ff|4096|0|0|root|root|1700000000|tmp").unwrap();
+        assert_eq!(file.permissions(), "drwxrwxrwt");
+
+        assert_eq!(ContainerFile::parse_stat("garbage"), None);
+    }
+}
@@ -15,7 +15,9 @@ impl Event {
 pub(crate) enum EventType {
     Container,
     Image,
+    Network,
     Pod,
+    Secret,
     Volume,
     #[default]
     Other,
@@ -29,6 +31,7 @@ impl From<&bollard::plugin::EventMessage> for EventType {
             .map(|event_type| match event_type {
                 bollard::plugin::EventMessageTypeEnum::CONTAINER => Self::Container,
                 bollard::plugin::EventMessageTypeEnum::IMAGE => Self::Image,
+                bollard::plugin::EventMessageTypeEnum::NETWORK => Self::Network,
                 bollard::plugin::EventMessageTypeEnum::VOLUME => Self::Volume,
                 _ => Self::default(),
             })
@@ -41,7 +44,9 @@ impl From<&podman_api::models::Event> for EventType {
         match value.typ.as_str() {
             "container" => Self::Container,
             "image" => Self::Image,
+            "network" => Self::Network,
             "pod" => Self::Pod,
+            "secret" => Self::Secret,
             "volume" => Self::Volume,
             _ => Self::default(),
         }
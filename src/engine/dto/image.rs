@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use gtk::glib;
@@ -56,8 +57,15 @@ pub(crate) struct ImageDetails {
     pub(crate) cmd: Option<String>,
     pub(crate) comment: Option<String>,
     pub(crate) entrypoint: Option<String>,
+    /// environment variables in the form of `KEY=value`
+    pub(crate) env: Vec<String>,
     pub(crate) exposed_ports: Vec<String>,
+    pub(crate) labels: HashMap<String, String>,
+    /// digests of the uncompressed layers from the base layer up
+    pub(crate) layers: Vec<String>,
+    pub(crate) os: Option<String>,
     pub(crate) shared_size: Option<u64>,
+    pub(crate) variant: Option<String>,
     pub(crate) virtual_size: Option<u64>,
 }
 
@@ -68,9 +76,17 @@ pub(crate) struct ImageInspection {
 
 impl From<bollard::plugin::ImageInspect> for ImageInspection {
     fn from(value: bollard::plugin::ImageInspect) -> Self {
-        let (cmd, entry_point, exposed_ports) = value
+        let (cmd, entry_point, env, exposed_ports, labels) = value
             .config
-            .map(|config| (config.cmd, config.entrypoint, config.exposed_ports))
+            .map(|config| {
+                (
+                    config.cmd,
+                    config.entrypoint,
+                    config.env,
+                    config.exposed_ports,
+                    config.labels,
+                )
+            })
             .unwrap_or_default();
 
         Self {
@@ -112,8 +128,16 @@ impl From<bollard::plugin::ImageInspect> for ImageInspection {
                         Some(entry_point.join(" "))
                     }
                 }),
+                env: env.unwrap_or_default(),
                 exposed_ports: exposed_ports.unwrap_or_default(),
+                labels: labels.unwrap_or_default(),
+                layers: value
+                    .root_fs
+                    .and_then(|root_fs| root_fs.layers)
+                    .unwrap_or_default(),
+                os: value.os,
                 shared_size: None,
+                variant: value.variant,
                 virtual_size: None,
             },
         }
@@ -122,9 +146,17 @@ impl From<bollard::plugin::ImageInspect> for ImageInspection {
 
 impl From<podman_api::models::InspectImageResponseLibpod> for ImageInspection {
     fn from(value: podman_api::models::InspectImageResponseLibpod) -> Self {
-        let (cmd, entry_point, exposed_ports) = value
+        let (cmd, entry_point, env, exposed_ports, labels) = value
             .config
-            .map(|config| (config.cmd, config.entrypoint, config.exposed_ports))
+            .map(|config| {
+                (
+                    config.cmd,
+                    config.entrypoint,
+                    config.env,
+                    config.exposed_ports,
+                    config.labels,
+                )
+            })
             .unwrap_or_default();
 
         Self {
@@ -164,10 +196,19 @@ impl From<podman_api::models::InspectImageResponseLibpod> for ImageInspection {
                         Some(entry_point.join(" "))
                     }
                 }),
+                env: env.unwrap_or_default(),
                 exposed_ports: exposed_ports
                     .map(|exposed_ports| exposed_ports.into_keys().collect())
                     .unwrap_or_default(),
+                labels: labels.unwrap_or_default(),
+                layers: value
+                    .root_fs
+                    .and_then(|root_fs| root_fs.layers)
+                    .unwrap_or_default(),
+                os: value.os,
                 shared_size: None,
+                // Podman does not report the variant of an image.
+                variant: None,
                 virtual_size: value
                     .virtual_size
                     .filter(|virtual_size| *virtual_size >= 0)
@@ -1,5 +1,121 @@
 pub(crate) enum ImageBuildReport {
-    Streaming { line: String },
-    Error { message: String },
-    Finished { image_id: String },
+    /// sent before the build context is transferred to the engine
+    Context {
+        files: usize,
+        size: u64,
+    },
+    Streaming {
+        line: String,
+    },
+    Error {
+        message: String,
+    },
+    Finished {
+        image_id: String,
+    },
+}
+
+/// Progress information contained in a line of build output.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ImageBuildProgress {
+    /// A new step starts. Docker prints `Step 2/5 : RUN make`, Podman prints `STEP 2/5: RUN make`
+    /// prefixed with `[1/2] ` for multi-stage builds.
+    Step {
+        stage: Option<String>,
+        number: u32,
+        total: u32,
+        instruction: String,
+    },
+    /// The current step is taken from the build cache.
+    UsingCache,
+    /// The current step produced the layer with the given id.
+    Layer { id: String },
+}
+
+impl ImageBuildProgress {
+    pub(crate) fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+
+        let (stage, step) = match line
+            .strip_prefix('[')
+            .and_then(|line| line.split_once("] "))
+        {
+            Some((stage, step)) => (Some(stage), step),
+            None => (None, line),
+        };
+
+        if let Some(step) = step
+            .strip_prefix("STEP ")
+            .or_else(|| step.strip_prefix("Step "))
+        {
+            let (progress, instruction) = step.split_once(':')?;
+            let (number, total) = progress.trim().split_once('/')?;
+
+            return Some(Self::Step {
+                stage: stage.map(str::to_owned),
+                number: number.parse().ok()?,
+                total: total.parse().ok()?,
+                instruction: instruction.trim().to_owned(),
+            });
+        }
+
+        let arrow = line
+            .strip_prefix("--->")
+            .or_else(|| line.strip_prefix("-->"))?
+            .trim();
+
+        if arrow.starts_with("Using cache") {
+            Some(Self::UsingCache)
+        } else {
+            let id = arrow.split_whitespace().next()?;
+            (id.len() >= 12 && id.chars().all(|c| c.is_ascii_hexdigit()))
+                .then(|| Self::Layer { id: id.to_owned() })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_progress() {
+        assert_eq!(
+            ImageBuildProgress::parse("Step 2/5 : RUN make\n"),
+            Some(ImageBuildProgress::Step {
+                stage: None,
+                number: 2,
+                total: 5,
+                instruction: "RUN make".to_owned(),
+            })
+        );
+        assert_eq!(
+            ImageBuildProgress::parse("[1/2] STEP 3/4: COPY . /src"),
+            Some(ImageBuildProgress::Step {
+                stage: Some("1/2".to_owned()),
+                number: 3,
+                total: 4,
+                instruction: "COPY . /src".to_owned(),
+            })
+        );
+        assert_eq!(
+            ImageBuildProgress::parse(" ---> Using cache"),
+            Some(ImageBuildProgress::UsingCache)
+        );
+        assert_eq!(
+            ImageBuildProgress::parse("--> Using cache 5b2a0f6cd8f3e9b6"),
+            Some(ImageBuildProgress::UsingCache)
+        );
+        assert_eq!(
+            ImageBuildProgress::parse(" ---> 3f2a0b1c2d3e"),
+            Some(ImageBuildProgress::Layer {
+                id: "3f2a0b1c2d3e".to_owned()
+            })
+        );
+        assert_eq!(
+            ImageBuildProgress::parse(" ---> Running in 3f2a0b1c2d3e"),
+            None
+        );
+        assert_eq!(ImageBuildProgress::parse("hello world"), None);
+    }
 }
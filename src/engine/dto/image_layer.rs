@@ -0,0 +1,52 @@
+pub(crate) struct ImageLayerAnalysis {
+    /// layers from the base layer to the topmost one
+    pub(crate) layers: Vec<ImageLayer>,
+    /// files overwritten or removed in a later layer, largest first
+    pub(crate) wasted_files: Vec<ImageWastedFile>,
+    /// accumulated size of the files of all layers
+    pub(crate) total_size: u64,
+    pub(crate) wasted_size: u64,
+}
+
+impl ImageLayerAnalysis {
+    /// Share of the layers' file sizes that is still visible in the final image.
+    pub(crate) fn efficiency(&self) -> f64 {
+        if self.total_size == 0 {
+            1.0
+        } else {
+            (self.total_size - self.wasted_size) as f64 / self.total_size as f64
+        }
+    }
+}
+
+pub(crate) struct ImageLayer {
+    pub(crate) digest: String,
+    /// the instruction that created the layer
+    pub(crate) created_by: Option<String>,
+    pub(crate) size: u64,
+    /// size of the files of this layer that are overwritten or removed in later layers
+    pub(crate) wasted_size: u64,
+    pub(crate) changes: Vec<ImageLayerChange>,
+}
+
+pub(crate) struct ImageLayerChange {
+    pub(crate) path: String,
+    pub(crate) kind: ImageLayerChangeKind,
+    pub(crate) size: u64,
+    /// whether a later layer overwrites or removes the file again
+    pub(crate) overwritten: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ImageLayerChangeKind {
+    Added,
+    Modified,
+    Removed,
+}
+
+pub(crate) struct ImageWastedFile {
+    pub(crate) path: String,
+    /// number of copies of the file that are overwritten or removed later
+    pub(crate) occurrences: u32,
+    pub(crate) size: u64,
+}
@@ -1,20 +1,30 @@
+mod compose_report;
 mod container;
+mod container_change;
+mod container_file;
 mod container_stats;
 mod event;
 mod image;
 mod image_build_report;
 mod image_history_entry;
+mod image_layer;
 mod image_pull_report;
 mod image_push_report;
 mod image_search_response_item;
 mod info;
+mod network;
 mod pod;
+mod pods_play_kube_report;
 mod pods_prune_report;
 mod port_mapping;
 mod prune_report;
+mod secret;
 mod top;
+mod unit_file;
+mod upload_report;
 mod volume;
 
+pub(crate) use compose_report::ComposeReport;
 pub(crate) use container::Container;
 pub(crate) use container::ContainerDetails;
 pub(crate) use container::ContainerInspection;
@@ -25,7 +35,13 @@ pub(crate) use container::HealthConfig;
 pub(crate) use container::HealthStatus;
 pub(crate) use container::Mount;
 pub(crate) use container::PortMappings;
+pub(crate) use container::ResourceLimits;
 pub(crate) use container::RestartPolicy;
+pub(crate) use container_change::ContainerChange;
+pub(crate) use container_change::ContainerChangeKind;
+pub(crate) use container_change::ContainerChangeNode;
+pub(crate) use container_file::ContainerFile;
+pub(crate) use container_file::ContainerFileKind;
 pub(crate) use container_stats::AllContainerStats;
 pub(crate) use container_stats::ContainerStats;
 pub(crate) use container_stats::DockerContainerStats;
@@ -35,22 +51,33 @@ pub(crate) use image::Image;
 pub(crate) use image::ImageDetails;
 pub(crate) use image::ImageInspection;
 pub(crate) use image::ImageSummary;
+pub(crate) use image_build_report::ImageBuildProgress;
 pub(crate) use image_build_report::ImageBuildReport;
 pub(crate) use image_history_entry::ImageHistoryEntry;
+pub(crate) use image_layer::ImageLayer;
+pub(crate) use image_layer::ImageLayerAnalysis;
+pub(crate) use image_layer::ImageLayerChange;
+pub(crate) use image_layer::ImageLayerChangeKind;
+pub(crate) use image_layer::ImageWastedFile;
 pub(crate) use image_pull_report::ImagePullReport;
 pub(crate) use image_push_report::ImagePushReport;
 pub(crate) use image_push_report::PodmanImagePushReport;
 pub(crate) use image_search_response_item::ImageSearchResponseItem;
 pub(crate) use info::Info;
+pub(crate) use network::Network;
 pub(crate) use pod::Pod;
 pub(crate) use pod::PodDetails;
 pub(crate) use pod::PodInspection;
 pub(crate) use pod::PodStatus;
 pub(crate) use pod::PodSummary;
+pub(crate) use pods_play_kube_report::PodsPlayKubeReport;
 pub(crate) use pods_prune_report::PodsPruneReport;
 pub(crate) use port_mapping::PortMapping;
 pub(crate) use port_mapping::PortMappingProtocol;
 pub(crate) use prune_report::PruneReport;
+pub(crate) use secret::Secret;
 pub(crate) use top::Top;
 pub(crate) use top::TopProcess;
+pub(crate) use unit_file::UnitFile;
+pub(crate) use upload_report::UploadReport;
 pub(crate) use volume::Volume;
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use gtk::glib;
+
+#[derive(Debug)]
+pub(crate) struct Network {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) created_at: i64,
+    pub(crate) driver: String,
+    pub(crate) internal: bool,
+    pub(crate) ipv6_enabled: bool,
+    pub(crate) subnets: Vec<String>,
+    pub(crate) labels: HashMap<String, String>,
+}
+
+macro_rules! impl_docker_network {
+    ($docker_model:ty) => {
+        impl From<$docker_model> for Network {
+            fn from(value: $docker_model) -> Self {
+                Self {
+                    id: value.id.unwrap_or_default(),
+                    name: value.name.unwrap_or_default(),
+                    created_at: value
+                        .created
+                        .and_then(|created| glib::DateTime::from_iso8601(&created, None).ok())
+                        .map(|created| created.to_unix())
+                        .unwrap_or(0),
+                    driver: value.driver.unwrap_or_default(),
+                    internal: value.internal.unwrap_or_default(),
+                    ipv6_enabled: value.enable_ipv6.unwrap_or_default(),
+                    subnets: value
+                        .ipam
+                        .and_then(|ipam| ipam.config)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|config| config.subnet)
+                        .collect(),
+                    labels: value.labels.unwrap_or_default(),
+                }
+            }
+        }
+    };
+}
+
+impl_docker_network!(bollard::plugin::Network);
+impl_docker_network!(bollard::plugin::NetworkInspect);
+
+impl From<podman_api::models::Network> for Network {
+    fn from(value: podman_api::models::Network) -> Self {
+        Self {
+            id: value.id.unwrap_or_default(),
+            name: value.name.unwrap_or_default(),
+            created_at: value
+                .created
+                .map(|created| created.timestamp())
+                .unwrap_or(0),
+            driver: value.driver.unwrap_or_default(),
+            internal: value.internal.unwrap_or_default(),
+            ipv6_enabled: value.ipv6_enabled.unwrap_or_default(),
+            subnets: value
+                .subnets
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|subnet| subnet.subnet)
+                .collect(),
+            labels: value.labels.unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#[derive(Default)]
+pub(crate) struct PodsPlayKubeReport {
+    /// ids of the created pods
+    pub(crate) pods: Vec<String>,
+    /// ids of the pods that have been torn down or replaced
+    pub(crate) removed_pods: Vec<String>,
+    /// names of the created volumes
+    pub(crate) volumes: Vec<String>,
+}
+
+impl From<podman_api::models::PlayKubeReport> for PodsPlayKubeReport {
+    fn from(value: podman_api::models::PlayKubeReport) -> Self {
+        Self {
+            pods: value
+                .pods
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|pod| pod.id)
+                .collect(),
+            removed_pods: Vec::new(),
+            volumes: value
+                .volumes
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|volume| volume.name)
+                .collect(),
+        }
+    }
+}
@@ -60,3 +60,25 @@ macro_rules! impl_podman_prune_report {
 
 impl_podman_prune_report!(podman_api::models::PruneReport);
 impl_podman_prune_report!(podman_api::models::ContainersPruneReportLibpod);
+
+impl From<bollard::plugin::NetworkPruneResponse> for PruneReport {
+    fn from(value: bollard::plugin::NetworkPruneResponse) -> Self {
+        Self {
+            deleted: value.networks_deleted.unwrap_or_default(),
+            space_reclaimed: 0,
+        }
+    }
+}
+
+impl From<Vec<podman_api::models::NetworkPruneReport>> for PruneReport {
+    fn from(value: Vec<podman_api::models::NetworkPruneReport>) -> Self {
+        Self {
+            deleted: value
+                .into_iter()
+                .filter(|report| report.error.is_none())
+                .filter_map(|report| report.name)
+                .collect(),
+            space_reclaimed: 0,
+        }
+    }
+}
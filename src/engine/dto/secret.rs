@@ -0,0 +1,38 @@
+#[derive(Debug)]
+pub(crate) struct Secret {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) created_at: i64,
+    pub(crate) updated_at: i64,
+    pub(crate) driver: String,
+}
+
+impl From<podman_api::models::SecretInfoReport> for Secret {
+    fn from(value: podman_api::models::SecretInfoReport) -> Self {
+        let (name, driver) = value
+            .spec
+            .map(|spec| {
+                (
+                    spec.name.unwrap_or_default(),
+                    spec.driver
+                        .and_then(|driver| driver.name)
+                        .unwrap_or_default(),
+                )
+            })
+            .unwrap_or_default();
+
+        Self {
+            id: value.id.unwrap_or_default(),
+            name,
+            created_at: value
+                .created_at
+                .map(|created_at| created_at.timestamp())
+                .unwrap_or(0),
+            updated_at: value
+                .updated_at
+                .map(|updated_at| updated_at.timestamp())
+                .unwrap_or(0),
+            driver,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) struct UnitFile {
+    /// file name including the extension, e.g. `web.container`
+    pub(crate) name: String,
+    pub(crate) content: String,
+}
@@ -0,0 +1,7 @@
+pub(crate) enum UploadReport<T> {
+    /// The number of bytes sent so far
+    Sending {
+        sent: u64,
+    },
+    Finished(T),
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use gtk::glib;
 
 #[derive(Debug)]
@@ -6,6 +8,8 @@ pub(crate) struct Volume {
     pub(crate) created_at: i64,
     pub(crate) driver: String,
     pub(crate) mountpoint: String,
+    pub(crate) options: HashMap<String, String>,
+    pub(crate) labels: HashMap<String, String>,
 }
 
 impl From<bollard::plugin::Volume> for Volume {
@@ -19,6 +23,8 @@ impl From<bollard::plugin::Volume> for Volume {
                 .unwrap_or(0),
             driver: value.driver,
             mountpoint: value.mountpoint,
+            options: value.options,
+            labels: value.labels,
         }
     }
 }
@@ -34,6 +40,8 @@ impl From<podman_api::models::Volume> for Volume {
                 .unwrap_or(0),
             driver: value.driver,
             mountpoint: value.mountpoint,
+            options: value.options,
+            labels: value.labels,
         }
     }
 }
@@ -0,0 +1,471 @@
+//! Analysis of the files changed by each layer of an image. The layers are read from the archive
+//! created by saving the image, which both engines write in the `docker-archive` format. As that
+//! archive is streamed from the engine, it is read with `tokio_tar` like the build contexts.
+
+use std::collections::HashMap;
+use std::io;
+
+use futures::StreamExt;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio_util::io::StreamReader;
+
+use crate::engine;
+
+/// Entries up to this size are read into memory to check whether they are JSON documents.
+const MAX_BUFFERED_ENTRY_SIZE: u64 = 1024 * 1024;
+
+/// Prefix of files that mark lower files as removed.
+const WHITEOUT_PREFIX: &str = ".wh.";
+/// Marks a directory as opaque, which hides the content of lower layers.
+const OPAQUE_WHITEOUT: &str = ".wh..wh..opq";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ManifestEntry {
+    config: String,
+    layers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Config {
+    #[serde(default)]
+    history: Vec<HistoryEntry>,
+}
+
+#[derive(Deserialize)]
+struct HistoryEntry {
+    created_by: Option<String>,
+    #[serde(default)]
+    empty_layer: bool,
+}
+
+struct LayerEntry {
+    path: String,
+    size: u64,
+    is_dir: bool,
+}
+
+impl engine::api::Images {
+    /// Saves the image and reads the file listings of its layers without buffering the archive.
+    pub(crate) async fn analyze_layers(
+        &self,
+        id: String,
+    ) -> anyhow::Result<engine::dto::ImageLayerAnalysis> {
+        let stream = self
+            .save(engine::opts::ImagesSaveOpts {
+                references: vec![id],
+                format: None,
+            })
+            .map_err(io::Error::other);
+
+        let mut archive = tokio_tar::Archive::new(StreamReader::new(stream));
+        let mut entries = archive.entries()?;
+
+        let mut documents = HashMap::new();
+        let mut layers = HashMap::new();
+
+        while let Some(entry) = entries.next().await {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let path = entry.path()?.to_string_lossy().into_owned();
+
+            if entry.header().size()? <= MAX_BUFFERED_ENTRY_SIZE {
+                let mut buf = Vec::new();
+                entry.read_to_end(&mut buf).await?;
+
+                if let Ok(document) = serde_json::from_slice::<serde_json::Value>(&buf) {
+                    documents.insert(path, document);
+                } else if let Ok(layer) = read_layer(buf.as_slice()).await {
+                    layers.insert(path, layer);
+                }
+            } else {
+                layers.insert(path, read_layer(&mut entry).await?);
+            }
+        }
+
+        let manifest = documents
+            .remove("manifest.json")
+            .ok_or_else(|| anyhow::anyhow!("image archive does not contain a manifest"))?;
+        let manifest = serde_json::from_value::<Vec<ManifestEntry>>(manifest)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("image archive manifest is empty"))?;
+
+        let mut history = documents
+            .remove(&manifest.config)
+            .and_then(|config| serde_json::from_value::<Config>(config).ok())
+            .map(|config| config.history)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|entry| !entry.empty_layer)
+            .map(|entry| entry.created_by);
+
+        let layers = manifest
+            .layers
+            .into_iter()
+            .map(|path| {
+                let entries = layers.remove(&path).ok_or_else(|| {
+                    anyhow::anyhow!("image archive does not contain layer {path}")
+                })?;
+                Ok((layer_digest(&path), history.next().flatten(), entries))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(analyze(layers))
+    }
+}
+
+async fn read_layer(reader: impl AsyncRead + Unpin + Send) -> io::Result<Vec<LayerEntry>> {
+    let mut archive = tokio_tar::Archive::new(reader);
+    let mut entries = archive.entries()?;
+
+    let mut layer = Vec::new();
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        let header = entry.header();
+
+        let path = entry.path()?;
+        let path = path.to_string_lossy();
+        let path = path.trim_start_matches("./").trim_end_matches('/');
+        if path.is_empty() || path == "." {
+            continue;
+        }
+
+        layer.push(LayerEntry {
+            path: path.to_owned(),
+            size: header.size()?,
+            is_dir: header.entry_type().is_dir(),
+        });
+    }
+
+    Ok(layer)
+}
+
+/// Extracts the digest from paths like `blobs/sha256/<digest>`, `<digest>/layer.tar` or
+/// `<digest>.tar`.
+fn layer_digest(path: &str) -> String {
+    path.strip_prefix("blobs/sha256/")
+        .or_else(|| path.strip_suffix("/layer.tar"))
+        .or_else(|| path.strip_suffix(".tar"))
+        .unwrap_or(path)
+        .to_owned()
+}
+
+fn analyze(
+    layers: Vec<(String, Option<String>, Vec<LayerEntry>)>,
+) -> engine::dto::ImageLayerAnalysis {
+    // visible file path -> (layer index, change index, size)
+    let mut visible = HashMap::<String, (usize, usize, u64)>::new();
+    let mut wasted_files = HashMap::<String, engine::dto::ImageWastedFile>::new();
+    let mut result = Vec::<engine::dto::ImageLayer>::with_capacity(layers.len());
+
+    let mut hide = |result: &mut Vec<engine::dto::ImageLayer>,
+                    visible: &mut HashMap<String, (usize, usize, u64)>,
+                    path: &str| {
+        let Some((layer, change, size)) = visible.remove(path) else {
+            return;
+        };
+        if let Some(layer) = result.get_mut(layer) {
+            layer.changes[change].overwritten = true;
+            layer.wasted_size += size;
+        }
+
+        let wasted_file =
+            wasted_files
+                .entry(path.to_owned())
+                .or_insert_with(|| engine::dto::ImageWastedFile {
+                    path: path.to_owned(),
+                    occurrences: 0,
+                    size: 0,
+                });
+        wasted_file.occurrences += 1;
+        wasted_file.size += size;
+    };
+
+    for (index, (digest, created_by, entries)) in layers.into_iter().enumerate() {
+        let mut layer = engine::dto::ImageLayer {
+            digest,
+            created_by,
+            size: 0,
+            wasted_size: 0,
+            changes: Vec::new(),
+        };
+
+        for entry in entries.into_iter().filter(|entry| !entry.is_dir) {
+            let (dir, name) = match entry.path.rsplit_once('/') {
+                Some((dir, name)) => (Some(dir), name),
+                None => (None, entry.path.as_str()),
+            };
+
+            let removed = if name == OPAQUE_WHITEOUT {
+                dir.map(|dir| format!("{dir}/")).unwrap_or_default()
+            } else if let Some(name) = name.strip_prefix(WHITEOUT_PREFIX) {
+                match dir {
+                    Some(dir) => format!("{dir}/{name}"),
+                    None => name.to_owned(),
+                }
+            } else {
+                layer.size += entry.size;
+
+                let kind = if visible.contains_key(&entry.path) {
+                    hide(&mut result, &mut visible, &entry.path);
+                    engine::dto::ImageLayerChangeKind::Modified
+                } else {
+                    engine::dto::ImageLayerChangeKind::Added
+                };
+
+                visible.insert(entry.path.clone(), (index, layer.changes.len(), entry.size));
+                layer.changes.push(engine::dto::ImageLayerChange {
+                    path: entry.path,
+                    kind,
+                    size: entry.size,
+                    overwritten: false,
+                });
+
+                continue;
+            };
+
+            // A whiteout removes a file or a whole directory, an opaque whiteout the content of
+            // its directory. Both only apply to lower layers, as the marker may come after files
+            // the layer itself adds to the directory.
+            let mut paths = visible
+                .iter()
+                .filter(|(_, (layer, _, _))| *layer < index)
+                .map(|(path, _)| path)
+                .filter(|path| {
+                    if name == OPAQUE_WHITEOUT {
+                        path.starts_with(&removed)
+                    } else {
+                        **path == removed
+                            || path
+                                .strip_prefix(&removed)
+                                .is_some_and(|rest| rest.starts_with('/'))
+                    }
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            paths.sort();
+
+            for path in paths {
+                hide(&mut result, &mut visible, &path);
+                layer.changes.push(engine::dto::ImageLayerChange {
+                    path,
+                    kind: engine::dto::ImageLayerChangeKind::Removed,
+                    size: 0,
+                    overwritten: false,
+                });
+            }
+        }
+
+        layer.changes.sort_by(|lhs, rhs| lhs.path.cmp(&rhs.path));
+
+        // The change indexes of this layer's visible files are only valid after sorting.
+        layer
+            .changes
+            .iter()
+            .enumerate()
+            .for_each(|(change_index, change)| {
+                if let Some((layer_index, visible_change_index, _)) = visible.get_mut(&change.path)
+                    && *layer_index == index
+                {
+                    *visible_change_index = change_index;
+                }
+            });
+
+        result.push(layer);
+    }
+
+    let total_size = result.iter().map(|layer| layer.size).sum();
+    let wasted_size = result.iter().map(|layer| layer.wasted_size).sum();
+
+    let mut wasted_files = wasted_files.into_values().collect::<Vec<_>>();
+    wasted_files.sort_by(|lhs, rhs| rhs.size.cmp(&lhs.size).then(lhs.path.cmp(&rhs.path)));
+
+    engine::dto::ImageLayerAnalysis {
+        layers: result,
+        wasted_files,
+        total_size,
+        wasted_size,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn file(path: &str, size: u64) -> LayerEntry {
+        LayerEntry {
+            path: path.to_owned(),
+            size,
+            is_dir: false,
+        }
+    }
+
+    fn dir(path: &str) -> LayerEntry {
+        LayerEntry {
+            path: path.to_owned(),
+            size: 0,
+            is_dir: true,
+        }
+    }
+
+    fn layer(digest: &str, entries: Vec<LayerEntry>) -> (String, Option<String>, Vec<LayerEntry>) {
+        (digest.to_owned(), None, entries)
+    }
+
+    fn changes(
+        layer: &engine::dto::ImageLayer,
+    ) -> Vec<(&str, engine::dto::ImageLayerChangeKind, u64, bool)> {
+        layer
+            .changes
+            .iter()
+            .map(|change| {
+                (
+                    change.path.as_str(),
+                    change.kind,
+                    change.size,
+                    change.overwritten,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_analyze() {
+        use engine::dto::ImageLayerChangeKind::*;
+
+        let analysis = analyze(vec![
+            layer(
+                "base",
+                vec![
+                    dir("etc"),
+                    file("etc/os-release", 10),
+                    file("etc/hosts", 5),
+                    dir("var/cache"),
+                    file("var/cache/a", 100),
+                    file("var/cache/b", 200),
+                    file("tmp/build", 50),
+                ],
+            ),
+            layer(
+                "modify",
+                vec![file("etc/os-release", 12), file("usr/bin/app", 1000)],
+            ),
+            layer(
+                "whiteout",
+                vec![file("etc/.wh.hosts", 0), file("tmp/.wh.build", 0)],
+            ),
+            layer(
+                "opaque",
+                vec![file("var/cache/c", 30), file("var/cache/.wh..wh..opq", 0)],
+            ),
+        ]);
+
+        assert_eq!(analysis.layers.len(), 4);
+
+        let base = &analysis.layers[0];
+        assert_eq!(base.digest, "base");
+        assert_eq!(base.size, 365);
+        assert_eq!(base.wasted_size, 365);
+        assert_eq!(
+            changes(base),
+            vec![
+                ("etc/hosts", Added, 5, true),
+                ("etc/os-release", Added, 10, true),
+                ("tmp/build", Added, 50, true),
+                ("var/cache/a", Added, 100, true),
+                ("var/cache/b", Added, 200, true),
+            ]
+        );
+
+        let modify = &analysis.layers[1];
+        assert_eq!(modify.size, 1012);
+        assert_eq!(modify.wasted_size, 0);
+        assert_eq!(
+            changes(modify),
+            vec![
+                ("etc/os-release", Modified, 12, false),
+                ("usr/bin/app", Added, 1000, false),
+            ]
+        );
+
+        let whiteout = &analysis.layers[2];
+        assert_eq!(whiteout.size, 0);
+        assert_eq!(
+            changes(whiteout),
+            vec![
+                ("etc/hosts", Removed, 0, false),
+                ("tmp/build", Removed, 0, false)
+            ]
+        );
+
+        // The opaque whiteout hides the lower files only, not the one added alongside it.
+        let opaque = &analysis.layers[3];
+        assert_eq!(opaque.size, 30);
+        assert_eq!(
+            changes(opaque),
+            vec![
+                ("var/cache/a", Removed, 0, false),
+                ("var/cache/b", Removed, 0, false),
+                ("var/cache/c", Added, 30, false),
+            ]
+        );
+
+        assert_eq!(analysis.total_size, 1407);
+        assert_eq!(analysis.wasted_size, 365);
+        assert_eq!(
+            analysis
+                .wasted_files
+                .iter()
+                .map(|file| (file.path.as_str(), file.occurrences, file.size))
+                .collect::<Vec<_>>(),
+            vec![
+                ("var/cache/b", 1, 200),
+                ("var/cache/a", 1, 100),
+                ("tmp/build", 1, 50),
+                ("etc/os-release", 1, 10),
+                ("etc/hosts", 1, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_readd() {
+        use engine::dto::ImageLayerChangeKind::*;
+
+        // A file removed by a whiteout and added again later counts as added.
+        let analysis = analyze(vec![
+            layer("a", vec![file("app.conf", 4)]),
+            layer("b", vec![file(".wh.app.conf", 0)]),
+            layer("c", vec![file("app.conf", 6)]),
+        ]);
+
+        assert_eq!(
+            changes(&analysis.layers[0]),
+            vec![("app.conf", Added, 4, true)]
+        );
+        assert_eq!(
+            changes(&analysis.layers[1]),
+            vec![("app.conf", Removed, 0, false)]
+        );
+        assert_eq!(
+            changes(&analysis.layers[2]),
+            vec![("app.conf", Added, 6, false)]
+        );
+        assert_eq!(analysis.wasted_size, 4);
+        assert!((analysis.efficiency() - 0.6).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_layer_digest() {
+        assert_eq!(layer_digest("blobs/sha256/abc"), "abc");
+        assert_eq!(layer_digest("abc/layer.tar"), "abc");
+        assert_eq!(layer_digest("abc.tar"), "abc");
+    }
+}
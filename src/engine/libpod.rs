@@ -0,0 +1,180 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use bytes::Bytes;
+use containers_api::conn;
+use containers_api::conn::hyper;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use futures::stream::BoxStream;
+use serde::de::DeserializeOwned;
+
+/// Client for the few libpod endpoints podman-api can't drive properly: it serializes every body
+/// as JSON or buffers it completely, whereas secrets need their raw bytes and archives should be
+/// streamed from disk.
+#[derive(Clone, Debug)]
+pub(crate) struct Libpod {
+    client: conn::RequestClient<conn::Error>,
+    transport: conn::Transport,
+}
+
+impl Libpod {
+    pub(crate) fn new(uri: &str) -> anyhow::Result<Self> {
+        let transport = match uri.split_once("://") {
+            Some(("unix", path)) => conn::Transport::Unix {
+                client: hyper::Client::builder()
+                    .pool_max_idle_per_host(0)
+                    .build(conn::get_unix_connector()),
+                path: path.into(),
+            },
+            Some(("tcp" | "http", host)) => conn::Transport::Tcp {
+                client: hyper::Client::builder().build(conn::get_http_connector()),
+                host: containers_api::url::url::Url::parse(&format!("tcp://{host}"))?,
+            },
+            _ => anyhow::bail!("unsupported connection URI: {uri}"),
+        };
+
+        Ok(Self {
+            client: conn::RequestClient::new(transport.clone(), Box::new(validate_response)),
+            transport,
+        })
+    }
+
+    /// Posts the raw `body` to the versioned libpod `endpoint` and deserializes the response.
+    pub(crate) async fn post_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: Option<String>,
+        body: Body,
+    ) -> anyhow::Result<T> {
+        let ep = containers_api::url::construct_ep(
+            podman_api::LATEST_API_VERSION.make_endpoint(endpoint),
+            query,
+        );
+
+        self.client
+            .post_json(ep, body.into(), conn::Headers::none())
+            .await
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Posts the raw `body` to the versioned libpod `endpoint` and discards the response.
+    pub(crate) async fn post(
+        &self,
+        endpoint: &str,
+        query: Option<String>,
+        body: Body,
+    ) -> anyhow::Result<()> {
+        let ep = containers_api::url::construct_ep(
+            podman_api::LATEST_API_VERSION.make_endpoint(endpoint),
+            query,
+        );
+
+        self.client
+            .post(ep, body.into(), conn::Headers::none())
+            .await
+            .map(drop)
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Posts the raw `body` to the versioned libpod `endpoint` and deserializes the JSON values the
+    /// engine streams back.
+    pub(crate) fn post_into_stream<T: DeserializeOwned + Send + 'static>(
+        &self,
+        endpoint: &str,
+        query: Option<String>,
+        body: Body,
+    ) -> BoxStream<'_, anyhow::Result<T>> {
+        let ep = containers_api::url::construct_ep(
+            podman_api::LATEST_API_VERSION.make_endpoint(endpoint),
+            query,
+        );
+
+        self.client
+            .post_into_stream(ep, body.into(), conn::Headers::none())
+            .map_err(anyhow::Error::from)
+            .boxed()
+    }
+
+    /// Sends a GET request with headers, which the request client of containers-api can't do.
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: Option<String>,
+        headers: &[(&str, String)],
+    ) -> anyhow::Result<T> {
+        let ep = containers_api::url::construct_ep(
+            podman_api::LATEST_API_VERSION.make_endpoint(endpoint),
+            query,
+        );
+
+        let mut request = hyper::Request::get(self.transport.make_uri(&ep)?);
+        for (key, value) in headers {
+            request = request.header(*key, value);
+        }
+
+        self.send_json(request.body(hyper::Body::empty())?).await
+    }
+
+    /// Sends a DELETE request with a body, which the request client of containers-api can't do.
+    pub(crate) async fn delete_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: Vec<u8>,
+    ) -> anyhow::Result<T> {
+        let uri = self
+            .transport
+            .make_uri(&podman_api::LATEST_API_VERSION.make_endpoint(endpoint))?;
+
+        self.send_json(hyper::Request::delete(uri).body(hyper::Body::from(body))?)
+            .await
+    }
+
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        request: hyper::Request<hyper::Body>,
+    ) -> anyhow::Result<T> {
+        let response = validate_response(self.transport.request(request).await?).await?;
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+
+        serde_json::from_slice(&bytes).map_err(anyhow::Error::from)
+    }
+}
+
+pub(crate) enum Body {
+    /// Bytes that are passed on unchanged
+    Raw(Vec<u8>),
+    /// A tar archive streamed in chunks
+    Tar(BoxStream<'static, std::io::Result<Bytes>>),
+}
+
+impl From<Body> for conn::Payload<hyper::Body> {
+    fn from(value: Body) -> Self {
+        match value {
+            Body::Raw(buf) => Self::Text(hyper::Body::from(buf)),
+            Body::Tar(stream) => Self::XTar(hyper::Body::wrap_stream(stream)),
+        }
+    }
+}
+
+fn validate_response(
+    response: hyper::Response<hyper::Body>,
+) -> Pin<Box<dyn Future<Output = conn::Result<hyper::Response<hyper::Body>>> + Send + Sync>> {
+    Box::pin(async move {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+        let message = serde_json::from_slice::<podman_api::models::ErrorModel>(&bytes)
+            .ok()
+            .and_then(|error| error.message)
+            .unwrap_or_else(|| String::from_utf8_lossy(&bytes).into_owned());
+
+        Err(conn::Error::Fault {
+            code: status,
+            message,
+        })
+    })
+}
@@ -1,8 +1,15 @@
 pub(crate) mod api;
 pub(crate) mod auth;
+pub(crate) mod build_context;
+pub(crate) mod compose;
 pub(crate) mod conn;
+pub(crate) mod container_files;
 pub(crate) mod dto;
+pub(crate) mod layers;
+pub(crate) mod libpod;
 pub(crate) mod opts;
+pub(crate) mod quadlet;
+pub(crate) mod volume_files;
 
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -19,6 +26,8 @@ pub(crate) enum Response<D, P> {
 
 #[derive(Clone, Debug)]
 pub(crate) struct Capabilities {
+    /// checkpoint/restore through CRIU
+    pub(crate) checkpoint: bool,
     pub(crate) kube_generation: bool,
     pub(crate) manual_health_check: bool,
     /// list of image formats if there exist more than a standard format
@@ -26,15 +35,20 @@ pub(crate) struct Capabilities {
     pub(crate) pods: bool,
     pub(crate) privileged_containers: bool,
     pub(crate) prune_external_images: bool,
+    pub(crate) pull_image_tls_verify: bool,
     pub(crate) push_image_tls_verify: bool,
     pub(crate) prune_all_volumes: bool,
     pub(crate) prune_volumes_until: bool,
+    /// Docker only supports secrets in swarm mode
+    pub(crate) secrets: bool,
+    /// Quadlet and legacy systemd unit generation
+    pub(crate) systemd_generation: bool,
 }
 
 #[derive(Clone, Debug)]
 pub(crate) enum Engine {
     Docker(bollard::Docker),
-    Podman(podman_api::Podman),
+    Podman(podman_api::Podman, engine::libpod::Libpod),
 }
 
 impl Engine {
@@ -52,7 +66,10 @@ impl Engine {
             .iter()
             .any(|component| &component.name == "Podman Engine")
         {
-            Ok(Self::Podman(podman_api::Podman::new(uri)?))
+            Ok(Self::Podman(
+                podman_api::Podman::new(uri.as_ref())?,
+                engine::libpod::Libpod::new(uri.as_ref())?,
+            ))
         } else {
             Err(anyhow::anyhow!("no suitable engine detected"))
         }
@@ -61,26 +78,34 @@ impl Engine {
     pub(crate) fn capabilities(&self) -> Capabilities {
         match self {
             Self::Docker(_) => Capabilities {
+                checkpoint: false,
                 kube_generation: false,
                 manual_health_check: false,
                 image_formats: None,
                 pods: false,
                 privileged_containers: false,
                 prune_external_images: false,
+                pull_image_tls_verify: false,
                 push_image_tls_verify: false,
                 prune_all_volumes: true,
                 prune_volumes_until: false,
+                secrets: false,
+                systemd_generation: false,
             },
-            Self::Podman(_) => Capabilities {
+            Self::Podman(..) => Capabilities {
+                checkpoint: true,
                 kube_generation: true,
                 manual_health_check: true,
                 image_formats: Some(vec!["oci", "docker"]),
                 pods: true,
                 privileged_containers: true,
                 prune_external_images: true,
+                pull_image_tls_verify: true,
                 push_image_tls_verify: true,
                 prune_all_volumes: false,
                 prune_volumes_until: true,
+                secrets: true,
+                systemd_generation: true,
             },
         }
     }
@@ -90,28 +115,50 @@ impl Engine {
     pub(crate) fn containers(&self) -> engine::api::Containers {
         match self {
             Self::Docker(docker) => engine::api::Containers::Docker(docker.to_owned()),
-            Self::Podman(podman) => engine::api::Containers::Podman(podman.containers()),
+            Self::Podman(podman, libpod) => {
+                engine::api::Containers::Podman(podman.containers(), libpod.to_owned())
+            }
         }
     }
 
     pub(crate) fn images(&self) -> engine::api::Images {
         match self {
             Self::Docker(docker) => engine::api::Images::Docker(docker.to_owned()),
-            Self::Podman(podman) => engine::api::Images::Podman(podman.images()),
+            Self::Podman(podman, libpod) => {
+                engine::api::Images::Podman(podman.images(), libpod.to_owned())
+            }
+        }
+    }
+
+    pub(crate) fn networks(&self) -> engine::api::Networks {
+        match self {
+            Self::Docker(docker) => engine::api::Networks::Docker(docker.to_owned()),
+            Self::Podman(podman, _) => engine::api::Networks::Podman(podman.networks()),
         }
     }
 
     pub(crate) fn pods(&self) -> engine::api::Pods {
         match self {
             Self::Docker(_) => engine::api::Pods::Docker,
-            Self::Podman(podman) => engine::api::Pods::Podman(podman.pods()),
+            Self::Podman(podman, libpod) => {
+                engine::api::Pods::Podman(podman.to_owned(), libpod.to_owned())
+            }
+        }
+    }
+
+    pub(crate) fn secrets(&self) -> engine::api::Secrets {
+        match self {
+            Self::Docker(_) => engine::api::Secrets::Docker,
+            Self::Podman(podman, libpod) => {
+                engine::api::Secrets::Podman(podman.secrets(), libpod.to_owned())
+            }
         }
     }
 
     pub(crate) fn volumes(&self) -> engine::api::Volumes {
         match self {
             Self::Docker(docker) => engine::api::Volumes::Docker(docker.to_owned()),
-            Self::Podman(podman) => engine::api::Volumes::Podman(podman.volumes()),
+            Self::Podman(podman, _) => engine::api::Volumes::Podman(podman.volumes()),
         }
     }
 }
@@ -124,7 +171,7 @@ impl Engine {
                 .await
                 .map_err(anyhow::Error::from)
                 .map(Into::into),
-            Self::Podman(podman) => podman
+            Self::Podman(podman, _) => podman
                 .info()
                 .await
                 .map_err(anyhow::Error::from)
@@ -143,7 +190,7 @@ impl Engine {
                         serde_json::to_string_pretty(&response).map_err(anyhow::Error::from)
                     })
             }
-            Self::Podman(podman) => {
+            Self::Podman(podman, _) => {
                 podman
                     .info()
                     .await
@@ -158,7 +205,7 @@ impl Engine {
     pub(crate) async fn ping(&self) -> anyhow::Result<()> {
         match self {
             Self::Docker(docker) => docker.ping().await.map_err(anyhow::Error::from).map(|_| ()),
-            Self::Podman(podman) => podman.ping().await.map_err(anyhow::Error::from).map(|_| ()),
+            Self::Podman(podman, _) => podman.ping().await.map_err(anyhow::Error::from).map(|_| ()),
         }
     }
 
@@ -169,7 +216,7 @@ impl Engine {
                 .map_err(anyhow::Error::from)
                 .map_ok(engine::dto::Event::Docker)
                 .boxed(),
-            Self::Podman(podman) => podman
+            Self::Podman(podman, _) => podman
                 .events(&Default::default())
                 .map_err(anyhow::Error::from)
                 .map_ok(engine::dto::Event::Podman)
@@ -0,0 +1,15 @@
+use smart_default::SmartDefault;
+
+#[derive(Clone, SmartDefault)]
+pub(crate) struct ComposeUpOpts {
+    /// path to the compose file on the host
+    pub(crate) file: String,
+    /// overrides the project name of the compose file or its directory
+    pub(crate) project_name: Option<String>,
+    /// Podman only: group all services of the project in a pod
+    #[default(true)]
+    pub(crate) pod: bool,
+    pub(crate) pull_latest: bool,
+    #[default(true)]
+    pub(crate) start: bool,
+}
@@ -0,0 +1,16 @@
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ContainerCheckpointOpts {
+    pub(crate) keep: bool,
+    pub(crate) leave_running: bool,
+    pub(crate) tcp_established: bool,
+}
+
+impl From<ContainerCheckpointOpts> for podman_api::opts::ContainerCheckpointOpts {
+    fn from(value: ContainerCheckpointOpts) -> Self {
+        Self::builder()
+            .keep(value.keep)
+            .leave_running(value.leave_running)
+            .tcp_established(value.tcp_established)
+            .build()
+    }
+}
@@ -5,17 +5,37 @@ use smart_default::SmartDefault;
 
 use crate::engine;
 
+/// default CFS period in microseconds used to translate a CPU count into a quota
+const CPU_PERIOD: u64 = 100_000;
+
 #[derive(Clone, SmartDefault)]
 pub(crate) struct ContainerCreateOpts {
+    pub(crate) cap_add: Vec<String>,
+    pub(crate) cap_drop: Vec<String>,
     pub(crate) cmd: Option<Vec<String>>,
+    /// relative CPU weight
+    pub(crate) cpu_shares: Option<u64>,
+    /// number of CPUs the container may use, e.g. `1.5`
+    pub(crate) cpus: Option<f64>,
+    pub(crate) cpuset_cpus: Option<String>,
+    pub(crate) dns: Vec<String>,
+    pub(crate) entrypoint: Option<Vec<String>>,
     pub(crate) env: HashMap<String, String>,
+    pub(crate) extra_hosts: Vec<engine::opts::PodHost>,
     pub(crate) health_config: Option<engine::dto::HealthConfig>,
+    pub(crate) hostname: Option<String>,
     pub(crate) image: String,
+    pub(crate) init: bool,
     pub(crate) labels: HashMap<String, String>,
     pub(crate) memory_limit: Option<u64>,
     pub(crate) mounts: Vec<ContainerCreateMountOpts>,
     #[default(names::Generator::default().next().unwrap_or_default())]
     pub(crate) name: String,
+    pub(crate) network_mode: ContainerCreateNetworkMode,
+    pub(crate) networks: Vec<ContainerCreateNetworkOpts>,
+    pub(crate) pids_limit: Option<i64>,
+    /// platform of the image to use if the image exists for several platforms
+    pub(crate) platform: Option<engine::opts::Platform>,
     // Podman only
     pub(crate) pod: Option<String>,
     pub(crate) port_mappings: Vec<engine::dto::PortMapping>,
@@ -23,10 +43,20 @@ pub(crate) struct ContainerCreateOpts {
     pub(crate) pull_latest: bool,
     // Podman only
     pub(crate) privileged: bool,
+    pub(crate) read_only_rootfs: bool,
     pub(crate) restart_policy: engine::dto::RestartPolicy,
+    // Podman only
+    pub(crate) secrets: Vec<ContainerCreateSecretOpts>,
+    /// options in the form of `--security-opt`, e.g. `no-new-privileges` or `label=disable`
+    pub(crate) security_opts: Vec<String>,
+    /// size of `/dev/shm` in bytes
+    pub(crate) shm_size: Option<u64>,
     #[default(true)]
     pub(crate) terminal: bool,
+    pub(crate) ulimits: Vec<ContainerCreateUlimit>,
+    pub(crate) user: Option<String>,
     pub(crate) volumes: Vec<ContainerCreateVolumeOpts>,
+    pub(crate) workdir: Option<String>,
 }
 
 impl From<ContainerCreateOpts>
@@ -37,6 +67,12 @@ impl From<ContainerCreateOpts>
 {
     fn from(value: ContainerCreateOpts) -> Self {
         let host_config = bollard::plugin::HostConfig {
+            cap_add: Some(value.cap_add),
+            cap_drop: Some(value.cap_drop),
+            cpu_shares: value.cpu_shares.map(|cpu_shares| cpu_shares as i64),
+            cpuset_cpus: value.cpuset_cpus,
+            dns: Some(value.dns),
+            extra_hosts: Some(value.extra_hosts.into_iter().map(String::from).collect()),
             mounts: Some(
                 value
                     .mounts
@@ -45,7 +81,20 @@ impl From<ContainerCreateOpts>
                     .chain(value.volumes.into_iter().map(Into::into))
                     .collect(),
             ),
+            init: Some(value.init),
             memory: value.memory_limit.map(|memory_limit| memory_limit as i64),
+            nano_cpus: value.cpus.map(|cpus| (cpus * 1_000_000_000.0) as i64),
+            network_mode: match value.network_mode {
+                ContainerCreateNetworkMode::Default => value
+                    .networks
+                    .first()
+                    .map(|network_opts| network_opts.network.clone()),
+                ContainerCreateNetworkMode::Host => Some("host".to_owned()),
+                ContainerCreateNetworkMode::None => Some("none".to_owned()),
+                ContainerCreateNetworkMode::Container(ref container) => {
+                    Some(format!("container:{container}"))
+                }
+            },
             port_bindings: Some({
                 value
                     .port_mappings
@@ -74,17 +123,27 @@ impl From<ContainerCreateOpts>
                         map
                     })
             }),
+            pids_limit: value.pids_limit,
+            readonly_rootfs: Some(value.read_only_rootfs),
             restart_policy: value.restart_policy.into(),
+            security_opt: Some(value.security_opts),
+            shm_size: value.shm_size.map(|shm_size| shm_size as i64),
+            ulimits: Some(value.ulimits.into_iter().map(Into::into).collect()),
             ..Default::default()
         };
 
         let opts = bollard::query_parameters::CreateContainerOptions {
             name: Some(value.name),
-            ..Default::default()
+            platform: value
+                .platform
+                .as_ref()
+                .map(engine::opts::Platform::to_string)
+                .unwrap_or_default(),
         };
 
         let config = bollard::plugin::ContainerCreateBody {
             cmd: value.cmd,
+            entrypoint: value.entrypoint,
             env: Some(
                 value
                     .env
@@ -94,9 +153,23 @@ impl From<ContainerCreateOpts>
             ),
             healthcheck: value.health_config.map(Into::into),
             host_config: Some(host_config),
+            hostname: value.hostname,
             image: Some(value.image),
             labels: Some(value.labels),
+            networking_config: (value.network_mode == ContainerCreateNetworkMode::Default
+                && !value.networks.is_empty())
+            .then(|| bollard::plugin::NetworkingConfig {
+                endpoints_config: Some(
+                    value
+                        .networks
+                        .into_iter()
+                        .map(|network_opts| (network_opts.network.clone(), network_opts.into()))
+                        .collect(),
+                ),
+            }),
             tty: Some(value.terminal),
+            user: value.user,
+            working_dir: value.workdir,
             ..Default::default()
         };
 
@@ -107,39 +180,115 @@ impl From<ContainerCreateOpts>
 impl From<ContainerCreateOpts> for podman_api::opts::ContainerCreateOpts {
     fn from(value: ContainerCreateOpts) -> Self {
         let mut builder = Self::builder()
+            .add_capabilities(value.cap_add)
+            .drop_capabilities(value.cap_drop)
             .command(value.cmd)
+            .dns_server(value.dns)
             .env(value.env)
+            .hosts_add(value.extra_hosts.into_iter().map(String::from))
             .image(value.image)
+            .init(value.init)
             .labels(value.labels)
             .name(value.name)
             .mounts(value.mounts.into_iter().map(Into::into))
             .privileged(value.privileged)
+            .read_only_fs(value.read_only_rootfs)
             .restart_policy(value.restart_policy.into())
+            .r_limits(value.ulimits.into_iter().map(Into::into))
             .terminal(value.terminal)
             .volumes(value.volumes.into_iter().map(Into::into));
 
+        let (secret_files, secret_envs): (Vec<_>, Vec<_>) = value
+            .secrets
+            .into_iter()
+            .partition(|secret_opts| matches!(secret_opts.target, SecretTarget::File(_)));
+        if !secret_files.is_empty() {
+            builder = builder.secrets(secret_files.into_iter().map(Into::into));
+        }
+        if !secret_envs.is_empty() {
+            builder = builder.secret_env(secret_envs.into_iter().filter_map(|secret_opts| {
+                match secret_opts.target {
+                    SecretTarget::Env(var) => Some((var, secret_opts.secret)),
+                    SecretTarget::File(_) => None,
+                }
+            }));
+        }
+
         if let Some(health_config) = value.health_config {
             builder = builder.health_config(health_config.into());
         }
 
-        if let Some(memory_limit) = value.memory_limit {
+        if let Some(entrypoint) = value.entrypoint {
+            builder = builder.entrypoint(entrypoint);
+        }
+        if let Some(platform) = value.platform {
+            builder = builder.image_os(platform.os).image_arch(platform.arch);
+            if let Some(variant) = platform.variant {
+                builder = builder.image_variant(variant);
+            }
+        }
+        if let Some(hostname) = value.hostname {
+            builder = builder.hostname(hostname);
+        }
+        if let Some(shm_size) = value.shm_size {
+            builder = builder.shm_size(shm_size as i64);
+        }
+        if let Some(user) = value.user {
+            builder = builder.user(user);
+        }
+        if let Some(workdir) = value.workdir {
+            builder = builder.work_dir(workdir);
+        }
+
+        for security_opt in value.security_opts {
+            builder = match security_opt.split_once(['=', ':']) {
+                Some(("label", label)) => builder.selinux_opts([label.to_owned()]),
+                Some(("apparmor", profile)) => builder.apparmor_profile(profile),
+                Some(("seccomp", profile)) => builder.seccomp_profile_path(profile),
+                _ if security_opt == "no-new-privileges" => builder.no_new_privilages(true),
+                _ => {
+                    log::warn!("unsupported security option: {security_opt}");
+                    builder
+                }
+            };
+        }
+
+        let cpu =
+            (value.cpus.is_some() || value.cpu_shares.is_some() || value.cpuset_cpus.is_some())
+                .then(|| podman_api::models::LinuxCpu {
+                    cpus: value.cpuset_cpus,
+                    mems: None,
+                    period: value.cpus.map(|_| CPU_PERIOD),
+                    quota: value.cpus.map(|cpus| (cpus * CPU_PERIOD as f64) as i64),
+                    realtime_period: None,
+                    realtime_runtime: None,
+                    shares: value.cpu_shares,
+                });
+        let memory = value
+            .memory_limit
+            .map(|memory_limit| podman_api::models::LinuxMemory {
+                disable_oom_killer: None,
+                kernel: None,
+                kernel_tcp: None,
+                limit: Some(memory_limit as i64),
+                reservation: None,
+                swap: None,
+                swappiness: None,
+                use_hierarchy: None,
+            });
+        let pids = value
+            .pids_limit
+            .map(|limit| podman_api::models::LinuxPids { limit: Some(limit) });
+
+        if cpu.is_some() || memory.is_some() || pids.is_some() {
             builder = builder.resource_limits(podman_api::models::LinuxResources {
                 block_io: None,
-                cpu: None,
+                cpu,
                 devices: None,
                 hugepage_limits: None,
-                memory: Some(podman_api::models::LinuxMemory {
-                    disable_oom_killer: None,
-                    kernel: None,
-                    kernel_tcp: None,
-                    limit: Some(memory_limit as i64),
-                    reservation: None,
-                    swap: None,
-                    swappiness: None,
-                    use_hierarchy: None,
-                }),
+                memory,
                 network: None,
-                pids: None,
+                pids,
                 rdma: None,
                 unified: None,
             });
@@ -147,12 +296,151 @@ impl From<ContainerCreateOpts> for podman_api::opts::ContainerCreateOpts {
 
         match value.pod {
             Some(pod) => builder.pod(pod),
-            None => builder.portmappings(value.port_mappings.into_iter().map(Into::into)),
+            None => {
+                builder = match value.network_mode {
+                    ContainerCreateNetworkMode::Default if !value.networks.is_empty() => builder
+                        .networks(value.networks.into_iter().map(|network_opts| {
+                            (network_opts.network.clone(), network_opts.into())
+                        })),
+                    ContainerCreateNetworkMode::Default => builder,
+                    network_mode => builder.net_namespace(network_mode.into()),
+                };
+                builder.portmappings(value.port_mappings.into_iter().map(Into::into))
+            }
         }
         .build()
     }
 }
 
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) enum ContainerCreateNetworkMode {
+    #[default]
+    Default,
+    Host,
+    None,
+    Container(String),
+}
+
+impl From<ContainerCreateNetworkMode> for podman_api::models::Namespace {
+    fn from(value: ContainerCreateNetworkMode) -> Self {
+        let (nsmode, value) = match value {
+            ContainerCreateNetworkMode::Default => ("default", None),
+            ContainerCreateNetworkMode::Host => ("host", None),
+            ContainerCreateNetworkMode::None => ("none", None),
+            ContainerCreateNetworkMode::Container(container) => ("container", Some(container)),
+        };
+
+        Self {
+            nsmode: Some(nsmode.to_owned()),
+            value,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct ContainerCreateNetworkOpts {
+    pub(crate) aliases: Vec<String>,
+    pub(crate) ipv4_address: Option<String>,
+    pub(crate) ipv6_address: Option<String>,
+    pub(crate) network: String,
+}
+
+impl From<ContainerCreateNetworkOpts> for bollard::plugin::EndpointSettings {
+    fn from(value: ContainerCreateNetworkOpts) -> Self {
+        Self {
+            aliases: Some(value.aliases),
+            ipam_config: (value.ipv4_address.is_some() || value.ipv6_address.is_some()).then(
+                || bollard::plugin::EndpointIpamConfig {
+                    ipv4_address: value.ipv4_address,
+                    ipv6_address: value.ipv6_address,
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ContainerCreateNetworkOpts> for podman_api::models::PerNetworkOptions {
+    fn from(value: ContainerCreateNetworkOpts) -> Self {
+        Self {
+            aliases: Some(value.aliases),
+            interface_name: None,
+            static_ips: Some(
+                value
+                    .ipv4_address
+                    .into_iter()
+                    .chain(value.ipv6_address)
+                    .collect(),
+            ),
+            static_mac: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ContainerCreateUlimit {
+    pub(crate) name: String,
+    pub(crate) soft: i64,
+    pub(crate) hard: i64,
+}
+
+impl From<ContainerCreateUlimit> for bollard::plugin::ResourcesUlimits {
+    fn from(value: ContainerCreateUlimit) -> Self {
+        Self {
+            name: Some(value.name),
+            soft: Some(value.soft),
+            hard: Some(value.hard),
+        }
+    }
+}
+
+impl From<ContainerCreateUlimit> for podman_api::models::PosixRlimit {
+    fn from(value: ContainerCreateUlimit) -> Self {
+        Self {
+            hard: Some(value.hard as u64),
+            soft: Some(value.soft as u64),
+            type_: Some(value.name),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct ContainerCreateSecretOpts {
+    pub(crate) secret: String,
+    pub(crate) target: SecretTarget,
+}
+
+impl From<ContainerCreateSecretOpts> for podman_api::models::Secret {
+    fn from(value: ContainerCreateSecretOpts) -> Self {
+        Self {
+            gid: None,
+            mode: None,
+            source: Some(value.secret),
+            target: match value.target {
+                SecretTarget::File(path) if !path.is_empty() => Some(path),
+                _ => None,
+            },
+            uid: None,
+        }
+    }
+}
+
+/// How a secret is exposed to the container.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SecretTarget {
+    /// Mounted as a file, by default at `/run/secrets/<secret>`
+    File(String),
+    /// Exposed as the given environment variable
+    Env(String),
+}
+
+impl Default for SecretTarget {
+    fn default() -> Self {
+        Self::File(String::new())
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct ContainerCreateMountOpts {
     pub(crate) container_path: String,
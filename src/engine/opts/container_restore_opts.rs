@@ -0,0 +1,40 @@
+#[derive(Clone, Debug)]
+pub(crate) enum ContainerRestoreSource {
+    /// restore a checkpointed container in place
+    Container(String),
+    /// path to an exported checkpoint archive
+    Archive(String),
+}
+
+impl Default for ContainerRestoreSource {
+    fn default() -> Self {
+        Self::Archive(String::new())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ContainerRestoreOpts {
+    pub(crate) source: ContainerRestoreSource,
+    pub(crate) name: Option<String>,
+    pub(crate) keep: bool,
+    pub(crate) tcp_established: bool,
+    pub(crate) ignore_static_ip: bool,
+    pub(crate) ignore_static_mac: bool,
+}
+
+impl From<&ContainerRestoreOpts> for podman_api::opts::ContainerRestoreOpts {
+    fn from(value: &ContainerRestoreOpts) -> Self {
+        let mut builder = Self::builder()
+            .import(matches!(value.source, ContainerRestoreSource::Archive(_)))
+            .keep(value.keep)
+            .tcp_established(value.tcp_established)
+            .ignore_static_ip(value.ignore_static_ip)
+            .ignore_static_mac(value.ignore_static_mac);
+
+        if let Some(ref name) = value.name {
+            builder = builder.name(name);
+        }
+
+        builder.build()
+    }
+}
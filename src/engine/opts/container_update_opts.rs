@@ -0,0 +1,74 @@
+use crate::engine;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ContainerUpdateOpts {
+    pub(crate) resource_limits: engine::dto::ResourceLimits,
+    pub(crate) restart_policy: engine::dto::RestartPolicy,
+}
+
+impl From<ContainerUpdateOpts> for bollard::plugin::ContainerUpdateBody {
+    fn from(value: ContainerUpdateOpts) -> Self {
+        let limits = value.resource_limits;
+
+        Self {
+            blkio_weight: Some(limits.blkio_weight),
+            cpu_period: Some(limits.cpu_period),
+            cpu_quota: Some(limits.cpu_quota),
+            cpu_shares: Some(limits.cpu_shares),
+            cpuset_cpus: Some(limits.cpuset_cpus),
+            memory: Some(limits.memory),
+            memory_swap: Some(limits.memory_swap),
+            pids_limit: Some(limits.pids_limit),
+            restart_policy: value.restart_policy.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The body of the libpod update endpoint. Unlike Docker, Podman takes `-1` for removing a limit,
+/// while unset values keep the current setting.
+impl From<ContainerUpdateOpts> for podman_api::models::LinuxResources {
+    fn from(value: ContainerUpdateOpts) -> Self {
+        let limits = value.resource_limits;
+        let limit = |value: i64| Some(if value > 0 { value } else { -1 });
+
+        Self {
+            block_io: Some(podman_api::models::LinuxBlockIo {
+                leaf_weight: None,
+                throttle_read_bps_device: None,
+                throttle_read_iops_device: None,
+                throttle_write_bps_device: None,
+                throttle_write_iops_device: None,
+                weight: Some(limits.blkio_weight).filter(|weight| *weight > 0),
+                weight_device: None,
+            }),
+            cpu: Some(podman_api::models::LinuxCpu {
+                cpus: Some(limits.cpuset_cpus).filter(|cpus| !cpus.is_empty()),
+                mems: None,
+                period: Some(limits.cpu_period as u64).filter(|period| *period > 0),
+                quota: limit(limits.cpu_quota),
+                realtime_period: None,
+                realtime_runtime: None,
+                shares: Some(limits.cpu_shares as u64).filter(|shares| *shares > 0),
+            }),
+            devices: None,
+            hugepage_limits: None,
+            memory: Some(podman_api::models::LinuxMemory {
+                disable_oom_killer: None,
+                kernel: None,
+                kernel_tcp: None,
+                limit: limit(limits.memory),
+                reservation: None,
+                swap: limit(limits.memory_swap),
+                swappiness: None,
+                use_hierarchy: None,
+            }),
+            network: None,
+            pids: Some(podman_api::models::LinuxPids {
+                limit: limit(limits.pids_limit),
+            }),
+            rdma: None,
+            unified: None,
+        }
+    }
+}
@@ -1,30 +1,191 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::engine;
 
 #[derive(Clone, Default)]
 pub(crate) struct ImageBuildOpts {
+    /// values for the `ARG` instructions of the Containerfile
+    pub(crate) build_args: HashMap<String, String>,
     pub(crate) dockerfile: String,
     pub(crate) labels: HashMap<String, String>,
+    /// do not use cached layers from previous builds
+    pub(crate) no_cache: bool,
     pub(crate) path: String,
-    pub(crate) tag: String,
+    pub(crate) platform: Option<engine::opts::Platform>,
+    pub(crate) pull_policy: ImageBuildPullPolicy,
+    /// files available to `RUN --mount=type=secret` instructions without ending up in the image
+    pub(crate) secrets: Vec<ImageBuildSecret>,
+    /// SSH agent sockets or keys available to `RUN --mount=type=ssh` instructions
+    pub(crate) ssh: Vec<ImageBuildSsh>,
+    /// the first tag is applied by the build itself, the others afterwards
+    pub(crate) tags: Vec<String>,
+    /// the stage of a multi-stage Containerfile to build
+    pub(crate) target: Option<String>,
 }
 
-impl From<ImageBuildOpts> for bollard::query_parameters::BuildImageOptions {
-    fn from(value: ImageBuildOpts) -> Self {
-        Self {
+/// When base images are pulled during a build.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum ImageBuildPullPolicy {
+    /// Pull base images that don't exist locally
+    #[default]
+    Missing,
+    /// Always pull base images
+    Always,
+    /// Pull base images if the registry has a newer version
+    Newer,
+    /// Never pull base images
+    Never,
+}
+
+impl ImageBuildPullPolicy {
+    fn as_libpod_str(self) -> &'static str {
+        match self {
+            Self::Missing => "missing",
+            Self::Always => "always",
+            Self::Newer => "ifnewer",
+            Self::Never => "never",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ImageBuildSecret {
+    /// the ID the Containerfile refers to the secret by
+    pub(crate) id: String,
+    pub(crate) path: PathBuf,
+}
+
+impl ImageBuildSecret {
+    /// Name of the `index`th secret file within the build context archive sent to Podman.
+    pub(crate) fn context_name(index: usize) -> String {
+        format!(".pods-build-secret-{index}")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ImageBuildSsh {
+    /// the ID the Containerfile refers to the agent by, `default` if omitted there
+    pub(crate) id: String,
+    /// agent socket or private keys, the engine's `SSH_AUTH_SOCK` if empty
+    pub(crate) paths: Vec<PathBuf>,
+}
+
+impl ImageBuildOpts {
+    /// Serializes the options as query of the libpod build endpoint. podman-api has no options for
+    /// the pull policy, secrets and SSH, so these are appended here. Podman expects the secret
+    /// files within the build context archive at [`ImageBuildSecret::context_name`].
+    pub(crate) fn into_libpod_query(self) -> anyhow::Result<String> {
+        let pull_policy = self.pull_policy;
+
+        let secret_names = (0..self.secrets.len())
+            .map(ImageBuildSecret::context_name)
+            .collect::<Vec<_>>();
+        let secrets = self
+            .secrets
+            .iter()
+            .zip(&secret_names)
+            .map(|(secret, name)| format!("id={},src={name}", secret.id))
+            .collect::<Vec<_>>();
+
+        let ssh = self
+            .ssh
+            .iter()
+            .map(|ssh| {
+                if ssh.paths.is_empty() {
+                    ssh.id.clone()
+                } else {
+                    format!(
+                        "{}={}",
+                        ssh.id,
+                        ssh.paths
+                            .iter()
+                            .map(|path| path.to_string_lossy())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut query = podman_api::opts::ImageBuildOpts::from(self)
+            .serialize()
+            .unwrap_or_default();
+
+        let mut extra = vec![("pullpolicy", pull_policy.as_libpod_str().to_owned())];
+        if !secrets.is_empty() {
+            extra.push(("secrets", serde_json::to_string(&secrets)?));
+            // The secret files must not be copied into the image by `COPY . …` instructions.
+            extra.push(("excludes", serde_json::to_string(&secret_names)?));
+        }
+        if !ssh.is_empty() {
+            extra.push(("ssh", serde_json::to_string(&ssh)?));
+        }
+
+        if !query.is_empty() {
+            query.push('&');
+        }
+        query.push_str(&containers_api::url::encoded_pairs(extra));
+
+        Ok(query)
+    }
+}
+
+impl TryFrom<ImageBuildOpts> for bollard::query_parameters::BuildImageOptions {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ImageBuildOpts) -> anyhow::Result<Self> {
+        // The classic build API of Docker has no means to pass secrets or SSH agents.
+        if !value.secrets.is_empty() {
+            anyhow::bail!("build secrets are not supported by the Docker API");
+        }
+        if !value.ssh.is_empty() {
+            anyhow::bail!("SSH forwarding is not supported by the Docker API");
+        }
+
+        Ok(Self {
+            buildargs: Some(value.build_args),
             dockerfile: value.dockerfile,
-            t: Some(value.tag),
             labels: Some(value.labels),
+            nocache: value.no_cache,
+            platform: value
+                .platform
+                .as_ref()
+                .map(engine::opts::Platform::to_string)
+                .unwrap_or_default(),
+            // Docker pulls missing base images anyway and only downloads changed layers.
+            pull: match value.pull_policy {
+                ImageBuildPullPolicy::Missing => None,
+                ImageBuildPullPolicy::Always | ImageBuildPullPolicy::Newer => Some("1".to_owned()),
+                ImageBuildPullPolicy::Never => {
+                    anyhow::bail!("the pull policy 'never' is not supported by the Docker API")
+                }
+            },
+            t: value.tags.into_iter().next(),
+            target: value.target.unwrap_or_default(),
             ..Default::default()
-        }
+        })
     }
 }
 
 impl From<ImageBuildOpts> for podman_api::opts::ImageBuildOpts {
     fn from(value: ImageBuildOpts) -> Self {
-        Self::builder(value.path)
+        let mut builder = Self::builder(value.path)
+            .build_args(value.build_args)
             .dockerfile(value.dockerfile)
             .labels(value.labels)
-            .tag(value.tag)
-            .build()
+            .nocache(value.no_cache);
+
+        if let Some(tag) = value.tags.into_iter().next() {
+            builder = builder.tag(tag);
+        }
+        if let Some(platform) = value.platform {
+            builder = builder.platform(platform.to_string());
+        }
+        if let Some(target) = value.target {
+            builder = builder.target(target);
+        }
+
+        builder.build()
     }
 }
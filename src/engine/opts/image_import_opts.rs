@@ -0,0 +1,44 @@
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ImageImportOpts {
+    pub(crate) changes: Vec<String>,
+    pub(crate) message: Option<String>,
+    pub(crate) repo: Option<String>,
+    pub(crate) tag: Option<String>,
+}
+
+impl ImageImportOpts {
+    fn reference(&self) -> Option<String> {
+        self.repo.as_ref().map(|repo| match self.tag {
+            Some(ref tag) => format!("{repo}:{tag}"),
+            None => repo.to_owned(),
+        })
+    }
+}
+
+impl From<ImageImportOpts> for bollard::query_parameters::CreateImageOptions {
+    fn from(value: ImageImportOpts) -> Self {
+        Self {
+            from_src: Some(String::from("-")),
+            repo: value.repo,
+            tag: value.tag,
+            message: value.message,
+            changes: value.changes,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ImageImportOpts> for podman_api::opts::ImageImportOpts {
+    fn from(value: ImageImportOpts) -> Self {
+        let mut builder = Self::builder();
+
+        if let Some(reference) = value.reference() {
+            builder = builder.reference(reference);
+        }
+        if let Some(message) = value.message {
+            builder = builder.message(message);
+        }
+
+        builder.changes(value.changes).build()
+    }
+}
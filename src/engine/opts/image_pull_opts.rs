@@ -1,22 +1,92 @@
-#[derive(Clone, Default)]
+use std::fmt;
+use std::str::FromStr;
+
+use smart_default::SmartDefault;
+
+use crate::engine;
+
+#[derive(Clone, SmartDefault)]
 pub(crate) struct ImagePullOpts {
+    pub(crate) credentials: Option<engine::auth::Credentials>,
+    /// pull the image for another platform than the one of the engine's host
+    pub(crate) platform: Option<Platform>,
     pub(crate) reference: String,
+    #[default(true)]
+    pub(crate) tls_verify: bool,
 }
 
 impl From<ImagePullOpts> for bollard::query_parameters::CreateImageOptions {
     fn from(value: ImagePullOpts) -> Self {
         Self {
             from_image: Some(value.reference),
+            platform: value
+                .platform
+                .as_ref()
+                .map(Platform::to_string)
+                .unwrap_or_default(),
             ..Default::default()
         }
     }
 }
 
 impl From<ImagePullOpts> for podman_api::opts::PullOpts {
-    fn from(value: ImagePullOpts) -> Self {
-        Self::builder()
+    fn from(mut value: ImagePullOpts) -> Self {
+        let mut builder = Self::builder()
             .reference(value.reference)
             .quiet(false)
-            .build()
+            .tls_verify(value.tls_verify);
+
+        if let Some(credentials) = value.credentials.take() {
+            builder.auth(credentials.into());
+        }
+        if let Some(platform) = value.platform {
+            builder = builder.os(platform.os).arch(platform.arch);
+            if let Some(variant) = platform.variant {
+                builder = builder.variant(variant);
+            }
+        }
+
+        builder.build()
+    }
+}
+
+/// An image platform in the form of `os/arch[/variant]`, e.g. `linux/arm64/v8`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct Platform {
+    pub(crate) os: String,
+    pub(crate) arch: String,
+    pub(crate) variant: Option<String>,
+}
+
+impl FromStr for Platform {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('/');
+
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(os), Some(arch), variant, None)
+                if !os.is_empty()
+                    && !arch.is_empty()
+                    && variant.is_none_or(|variant| !variant.is_empty()) =>
+            {
+                Ok(Self {
+                    os: os.to_owned(),
+                    arch: arch.to_owned(),
+                    variant: variant.map(str::to_owned),
+                })
+            }
+            _ => anyhow::bail!("'{s}' is not in the form of os/arch[/variant]"),
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.os, self.arch)?;
+        if let Some(ref variant) = self.variant {
+            write!(f, "/{variant}")?;
+        }
+        Ok(())
     }
 }
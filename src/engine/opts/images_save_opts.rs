@@ -0,0 +1,16 @@
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ImagesSaveOpts {
+    pub(crate) references: Vec<String>,
+    /// one of `engine::Capabilities::image_formats`
+    pub(crate) format: Option<String>,
+}
+
+impl From<ImagesSaveOpts> for podman_api::opts::ImagesExportOpts {
+    fn from(value: ImagesSaveOpts) -> Self {
+        let mut builder = Self::builder().references(value.references);
+        if let Some(format) = value.format {
+            builder = builder.format(format!("{format}-archive"));
+        }
+        builder.build()
+    }
+}
@@ -1,32 +1,64 @@
+mod compose_up_opts;
+mod container_checkpoint_opts;
 mod container_commit_opts;
 mod container_create_opts;
+mod container_restore_opts;
+mod container_update_opts;
 mod containers_prune_opts;
 mod exec_create_opts;
 mod image_build_opts;
+mod image_import_opts;
 mod image_pull_opts;
 mod image_push_opts;
 mod images_prune_opts;
+mod images_save_opts;
 mod log_opts;
+mod network_connect_opts;
+mod network_create_opts;
+mod networks_prune_opts;
 mod pod_create_opts;
+mod pods_play_kube_opts;
+mod secret_create_opts;
 mod volume_create_opts;
 mod volumes_prune_opts;
 
+pub(crate) use compose_up_opts::ComposeUpOpts;
+pub(crate) use container_checkpoint_opts::ContainerCheckpointOpts;
 pub(crate) use container_commit_opts::ContainerCommitOpts;
 pub(crate) use container_create_opts::ContainerCreateMountOpts;
+pub(crate) use container_create_opts::ContainerCreateNetworkMode;
+pub(crate) use container_create_opts::ContainerCreateNetworkOpts;
 pub(crate) use container_create_opts::ContainerCreateOpts;
+pub(crate) use container_create_opts::ContainerCreateSecretOpts;
+pub(crate) use container_create_opts::ContainerCreateUlimit;
 pub(crate) use container_create_opts::ContainerCreateVolumeOpts;
 pub(crate) use container_create_opts::SELinux;
+pub(crate) use container_create_opts::SecretTarget;
+pub(crate) use container_restore_opts::ContainerRestoreOpts;
+pub(crate) use container_restore_opts::ContainerRestoreSource;
+pub(crate) use container_update_opts::ContainerUpdateOpts;
 pub(crate) use containers_prune_opts::ContainersPruneOpts;
 pub(crate) use exec_create_opts::ExecCreateOpts;
 pub(crate) use image_build_opts::ImageBuildOpts;
+pub(crate) use image_build_opts::ImageBuildPullPolicy;
+pub(crate) use image_build_opts::ImageBuildSecret;
+pub(crate) use image_build_opts::ImageBuildSsh;
+pub(crate) use image_import_opts::ImageImportOpts;
 pub(crate) use image_pull_opts::ImagePullOpts;
+pub(crate) use image_pull_opts::Platform;
 pub(crate) use image_push_opts::ImagePushOpts;
 pub(crate) use images_prune_opts::ImagesPruneOpts;
+pub(crate) use images_save_opts::ImagesSaveOpts;
 pub(crate) use log_opts::LogsOpts;
+pub(crate) use network_connect_opts::NetworkConnectOpts;
+pub(crate) use network_create_opts::NetworkCreateOpts;
+pub(crate) use networks_prune_opts::NetworksPruneOpts;
 pub(crate) use pod_create_opts::PodCreateOpts;
 pub(crate) use pod_create_opts::PodDevice;
 pub(crate) use pod_create_opts::PodHost;
 pub(crate) use pod_create_opts::PodHostManagement;
 pub(crate) use pod_create_opts::PodInfra;
+pub(crate) use pods_play_kube_opts::PodsPlayKubeOpts;
+pub(crate) use secret_create_opts::SecretCreateOpts;
 pub(crate) use volume_create_opts::VolumeCreateOpts;
 pub(crate) use volumes_prune_opts::VolumesPruneOpts;
@@ -0,0 +1,26 @@
+#[derive(Clone, Default)]
+pub(crate) struct NetworkConnectOpts {
+    pub(crate) container: String,
+    pub(crate) aliases: Vec<String>,
+}
+
+impl From<NetworkConnectOpts> for bollard::plugin::NetworkConnectRequest {
+    fn from(value: NetworkConnectOpts) -> Self {
+        Self {
+            container: value.container,
+            endpoint_config: Some(bollard::plugin::EndpointSettings {
+                aliases: Some(value.aliases),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+impl From<NetworkConnectOpts> for podman_api::opts::NetworkConnectOpts {
+    fn from(value: NetworkConnectOpts) -> Self {
+        Self::builder()
+            .container(value.container)
+            .aliases(value.aliases)
+            .build()
+    }
+}
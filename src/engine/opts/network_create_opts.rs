@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+#[derive(Clone, Default)]
+pub(crate) struct NetworkCreateOpts {
+    pub(crate) name: String,
+    pub(crate) driver: Option<String>,
+    pub(crate) internal: bool,
+    pub(crate) ipv6_enabled: bool,
+    pub(crate) subnet: Option<String>,
+    pub(crate) gateway: Option<String>,
+    pub(crate) labels: HashMap<String, String>,
+}
+
+impl From<NetworkCreateOpts> for bollard::plugin::NetworkCreateRequest {
+    fn from(value: NetworkCreateOpts) -> Self {
+        Self {
+            name: value.name,
+            driver: value.driver,
+            internal: Some(value.internal),
+            enable_ipv6: Some(value.ipv6_enabled),
+            ipam: value.subnet.map(|subnet| bollard::plugin::Ipam {
+                config: Some(vec![bollard::plugin::IpamConfig {
+                    subnet: Some(subnet),
+                    gateway: value.gateway,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            labels: Some(value.labels),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<NetworkCreateOpts> for podman_api::opts::NetworkCreateOpts {
+    fn from(value: NetworkCreateOpts) -> Self {
+        let builder = Self::builder()
+            .name(value.name)
+            .internal(value.internal)
+            .ipv6_enabled(value.ipv6_enabled)
+            .labels(value.labels);
+
+        let builder = match value.driver {
+            Some(driver) => builder.driver(driver),
+            None => builder,
+        };
+
+        match value.subnet {
+            Some(subnet) => builder.subnets([podman_api::models::Subnet {
+                gateway: value.gateway,
+                lease_range: None,
+                subnet: Some(subnet),
+            }]),
+            None => builder,
+        }
+        .build()
+    }
+}
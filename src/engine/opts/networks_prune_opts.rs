@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+#[derive(Clone, Default)]
+pub(crate) struct NetworksPruneOpts {
+    pub(crate) until: Option<i64>,
+}
+
+impl From<NetworksPruneOpts> for bollard::query_parameters::PruneNetworksOptions {
+    fn from(value: NetworksPruneOpts) -> Self {
+        Self {
+            filters: value
+                .until
+                .map(|until| HashMap::from([("until".to_owned(), vec![until.to_string()])])),
+        }
+    }
+}
+
+impl From<NetworksPruneOpts> for podman_api::opts::NetworkPruneOpts {
+    fn from(value: NetworksPruneOpts) -> Self {
+        Self::builder()
+            .filter(
+                value
+                    .until
+                    .map(|until| until.to_string())
+                    .map(podman_api::opts::NetworkPruneFilter::Until),
+            )
+            .build()
+    }
+}
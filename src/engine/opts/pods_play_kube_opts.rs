@@ -0,0 +1,27 @@
+use smart_default::SmartDefault;
+
+#[derive(Clone, SmartDefault)]
+pub(crate) struct PodsPlayKubeOpts {
+    /// path to the Kubernetes YAML on the host
+    pub(crate) file: String,
+    /// tear down the pods described by the YAML instead of creating them
+    pub(crate) down: bool,
+    /// networks to connect the pods to instead of the default network
+    pub(crate) networks: Vec<String>,
+    /// remove existing pods with the same names before creating them
+    pub(crate) replace: bool,
+    #[default(true)]
+    pub(crate) start: bool,
+}
+
+impl From<PodsPlayKubeOpts> for podman_api::opts::PlayKubernetesYamlOpts {
+    fn from(value: PodsPlayKubeOpts) -> Self {
+        let mut builder = Self::builder().start(value.start);
+
+        if !value.networks.is_empty() {
+            builder = builder.network(value.networks);
+        }
+
+        builder.build()
+    }
+}
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use smart_default::SmartDefault;
+
+#[derive(Clone, SmartDefault)]
+pub(crate) struct SecretCreateOpts {
+    #[default(names::Generator::default().next().unwrap_or_default())]
+    pub(crate) name: String,
+    // The secret content, either typed in or read from a file
+    pub(crate) data: Vec<u8>,
+    pub(crate) driver: Option<String>,
+    pub(crate) labels: HashMap<String, String>,
+}
+
+impl From<SecretCreateOpts> for podman_api::opts::SecretCreateOpts {
+    fn from(value: SecretCreateOpts) -> Self {
+        let mut builder = Self::builder(value.name).labels(value.labels);
+
+        if let Some(driver) = value.driver {
+            builder = builder.driver(driver);
+        }
+
+        builder.build()
+    }
+}
@@ -1,12 +1,20 @@
+use std::collections::HashMap;
+
 #[derive(Clone, Default)]
 pub(crate) struct VolumeCreateOpts {
     pub(crate) name: Option<String>,
+    pub(crate) driver: Option<String>,
+    pub(crate) options: HashMap<String, String>,
+    pub(crate) labels: HashMap<String, String>,
 }
 
 impl From<VolumeCreateOpts> for bollard::plugin::VolumeCreateRequest {
     fn from(value: VolumeCreateOpts) -> Self {
         bollard::config::VolumeCreateRequest {
             name: value.name,
+            driver: value.driver,
+            driver_opts: Some(value.options),
+            labels: Some(value.labels),
             ..Default::default()
         }
     }
@@ -14,8 +22,15 @@ impl From<VolumeCreateOpts> for bollard::plugin::VolumeCreateRequest {
 
 impl From<VolumeCreateOpts> for podman_api::opts::VolumeCreateOpts {
     fn from(value: VolumeCreateOpts) -> Self {
-        podman_api::opts::VolumeCreateOpts::builder()
+        let builder = podman_api::opts::VolumeCreateOpts::builder()
             .name(value.name)
-            .build()
+            .options(value.options)
+            .labels(value.labels);
+
+        match value.driver {
+            Some(driver) => builder.driver(driver),
+            None => builder,
+        }
+        .build()
     }
 }
@@ -0,0 +1,471 @@
+//! Generation of Quadlet unit files, see podman-systemd.unit(5).
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use indexmap::IndexMap;
+
+use crate::engine;
+
+/// Containers and pods are installed into this directory below the user's home directory to be
+/// picked up by the Quadlet systemd generator.
+pub(crate) const QUADLET_DIR: &str = ".config/containers/systemd";
+/// Directory for units generated by the legacy `podman generate systemd`.
+pub(crate) const SYSTEMD_USER_DIR: &str = ".config/systemd/user";
+
+/// Environment variables set by Podman itself that must not end up in a unit.
+const IGNORED_ENV: &[&str] = &["container", "HOSTNAME", "HOME"];
+
+impl engine::Engine {
+    /// Generates the `.container` file for a container together with `.volume` and `.network`
+    /// files for the named volumes and networks it uses.
+    pub(crate) async fn generate_container_quadlet(
+        &self,
+        id: &str,
+    ) -> anyhow::Result<Vec<engine::dto::UnitFile>> {
+        match self {
+            Self::Docker(_) => {
+                anyhow::bail!("Quadlet generation is not supported by the Docker API")
+            }
+            Self::Podman(podman, _) => {
+                let inspection = podman.containers().get(id).inspect().await?;
+                let image_config = image_config(podman, &inspection).await;
+
+                let mut units = Units::default();
+                units.add_container(inspection, image_config, None);
+
+                Ok(units.into_unit_files())
+            }
+        }
+    }
+
+    /// Generates the `.pod` file for a pod and `.container` files for all of its containers.
+    pub(crate) async fn generate_pod_quadlet(
+        &self,
+        id: &str,
+    ) -> anyhow::Result<Vec<engine::dto::UnitFile>> {
+        match self {
+            Self::Docker(_) => anyhow::bail!("pods are not supported by the Docker API"),
+            Self::Podman(podman, _) => {
+                let inspection = podman.pods().get(id).inspect().await?;
+                let pod_name = inspection.name.clone().unwrap_or_default();
+
+                let mut units = Units::default();
+                units.add_pod(&inspection);
+
+                for container in inspection.containers.unwrap_or_default() {
+                    let Some(id) = container.id else {
+                        continue;
+                    };
+                    if inspection.infra_container_id.as_ref() == Some(&id) {
+                        continue;
+                    }
+
+                    let inspection = podman.containers().get(id).inspect().await?;
+                    let image_config = image_config(podman, &inspection).await;
+                    units.add_container(inspection, image_config, Some(&pod_name));
+                }
+
+                Ok(units.into_unit_files())
+            }
+        }
+    }
+}
+
+/// The configuration of the container's image, so that the unit only contains what the container
+/// changes. Without it, the unit pins the image defaults of the time it was generated.
+async fn image_config(
+    podman: &podman_api::Podman,
+    inspection: &podman_api::models::ContainerInspectResponseLibpod,
+) -> Option<podman_api::models::ImageConfig> {
+    let image = inspection
+        .image
+        .as_deref()
+        .filter(|image| !image.is_empty())?;
+
+    podman
+        .images()
+        .get(image)
+        .inspect()
+        .await
+        .inspect_err(|e| log::warn!("Error on inspecting image {image}: {e}"))
+        .ok()
+        .and_then(|inspection| inspection.config)
+}
+
+#[derive(Default)]
+struct Units {
+    containers: IndexMap<String, String>,
+    pods: IndexMap<String, String>,
+    volumes: IndexMap<String, String>,
+    networks: IndexMap<String, String>,
+}
+
+impl Units {
+    fn into_unit_files(self) -> Vec<engine::dto::UnitFile> {
+        [
+            (self.pods, "pod"),
+            (self.containers, "container"),
+            (self.volumes, "volume"),
+            (self.networks, "network"),
+        ]
+        .into_iter()
+        .flat_map(|(units, extension)| {
+            units
+                .into_iter()
+                .map(move |(name, content)| engine::dto::UnitFile {
+                    name: format!("{name}.{extension}"),
+                    content,
+                })
+        })
+        .collect()
+    }
+
+    fn add_pod(&mut self, inspection: &podman_api::models::InspectPodData) {
+        let name = inspection.name.clone().unwrap_or_default();
+
+        let mut unit = String::new();
+        unit_section(&mut unit, &format!("{name} pod"));
+
+        writeln!(unit, "[Pod]").unwrap();
+        writeln!(unit, "PodName={name}").unwrap();
+
+        if let Some(infra_config) = &inspection.infra_config {
+            infra_config
+                .port_bindings
+                .clone()
+                .map(engine::dto::PortMappings::from)
+                .map(engine::dto::PortMappings::into_inner)
+                .unwrap_or_default()
+                .iter()
+                .for_each(|port_mapping| publish_port(&mut unit, port_mapping));
+
+            infra_config.networks.iter().flatten().for_each(|network| {
+                let network = self.add_network(network);
+                writeln!(unit, "Network={network}").unwrap();
+            });
+        }
+
+        install_section(&mut unit);
+
+        self.pods.insert(name, unit);
+    }
+
+    fn add_container(
+        &mut self,
+        inspection: podman_api::models::ContainerInspectResponseLibpod,
+        image_config: Option<podman_api::models::ImageConfig>,
+        pod: Option<&str>,
+    ) {
+        let (image_cmd, image_entrypoint, image_env, image_labels) = image_config
+            .map(|config| (config.cmd, config.entrypoint, config.env, config.labels))
+            .unwrap_or_default();
+        let name = inspection.name.unwrap_or_default();
+
+        let mut unit = String::new();
+        unit_section(&mut unit, &format!("{name} container"));
+
+        writeln!(unit, "[Container]").unwrap();
+        writeln!(unit, "ContainerName={name}").unwrap();
+        if let Some(image) = inspection.image_name.filter(|image| !image.is_empty()) {
+            writeln!(unit, "Image={image}").unwrap();
+        }
+
+        match pod {
+            Some(pod) => writeln!(unit, "Pod={pod}.pod").unwrap(),
+            None => {
+                // Ports and networks of containers in a pod belong to the pod.
+                if let Some(settings) = inspection.network_settings {
+                    settings
+                        .ports
+                        .map(engine::dto::PortMappings::from)
+                        .map(engine::dto::PortMappings::into_inner)
+                        .unwrap_or_default()
+                        .iter()
+                        .for_each(|port_mapping| publish_port(&mut unit, port_mapping));
+
+                    let mut networks = settings
+                        .networks
+                        .unwrap_or_default()
+                        .into_keys()
+                        .collect::<Vec<_>>();
+                    networks.sort();
+                    networks.into_iter().for_each(|network| {
+                        let network = self.add_network(&network);
+                        writeln!(unit, "Network={network}").unwrap();
+                    });
+                }
+            }
+        }
+
+        for mount in inspection.mounts.unwrap_or_default() {
+            let Some(destination) = mount.destination else {
+                continue;
+            };
+            let read_only = if mount.rw.unwrap_or(true) { "" } else { ":ro" };
+
+            match mount.type_.as_deref() {
+                Some("volume") => {
+                    let Some(volume) = mount.name else {
+                        continue;
+                    };
+                    let volume = self.add_volume(&volume);
+                    writeln!(unit, "Volume={volume}:{destination}{read_only}").unwrap();
+                }
+                Some("bind") => {
+                    let Some(source) = mount.source else {
+                        continue;
+                    };
+                    writeln!(unit, "Volume={source}:{destination}{read_only}").unwrap();
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(config) = inspection.config {
+            let image_env = image_env.unwrap_or_default();
+            let mut env = config.env.unwrap_or_default();
+            env.sort();
+            env.iter()
+                .filter(|env| {
+                    !IGNORED_ENV.contains(&env.split_once('=').map(|(key, _)| key).unwrap_or(env))
+                        && !image_env.contains(env)
+                })
+                .for_each(|env| writeln!(unit, "Environment={}", quote(env)).unwrap());
+
+            let image_labels = image_labels.unwrap_or_default();
+            let mut labels = config
+                .labels
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|(key, value)| image_labels.get(key) != Some(value))
+                .collect::<Vec<_>>();
+            labels.sort();
+            labels.into_iter().for_each(|(key, value)| {
+                writeln!(unit, "Label={}", quote(&format!("{key}={value}"))).unwrap()
+            });
+
+            if let Some(user) = config.user.filter(|user| !user.is_empty()) {
+                writeln!(unit, "User={user}").unwrap();
+            }
+            if let Some(working_dir) = config
+                .working_dir
+                .filter(|dir| !dir.is_empty() && dir != "/")
+            {
+                writeln!(unit, "WorkingDir={working_dir}").unwrap();
+            }
+
+            // Podman reports the entrypoint joined by spaces.
+            let entrypoint = config
+                .entrypoint
+                .filter(|entrypoint| !entrypoint.is_empty())
+                .filter(|entrypoint| *entrypoint != image_entrypoint.unwrap_or_default().join(" "));
+            if let Some(entrypoint) = &entrypoint {
+                let args = entrypoint.split_whitespace().collect::<Vec<_>>();
+                let entrypoint = match args.as_slice() {
+                    [arg] => arg.to_string(),
+                    args => serde_json::to_string(args).unwrap(),
+                };
+                writeln!(unit, "Entrypoint={}", entrypoint.replace('%', "%%")).unwrap();
+            }
+
+            // Overriding the entrypoint drops the command of the image.
+            if let Some(cmd) = config
+                .cmd
+                .filter(|cmd| !cmd.is_empty())
+                .filter(|cmd| entrypoint.is_some() || image_cmd.as_ref() != Some(cmd))
+            {
+                writeln!(
+                    unit,
+                    "Exec={}",
+                    cmd.iter()
+                        .map(|arg| quote(arg))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                )
+                .unwrap();
+            }
+        }
+
+        let restart_policy = inspection
+            .host_config
+            .and_then(|host_config| host_config.restart_policy)
+            .and_then(|restart_policy| restart_policy.name)
+            .and_then(|policy| match policy.as_str() {
+                "always" | "unless-stopped" => Some("always"),
+                "on-failure" => Some("on-failure"),
+                _ => None,
+            });
+
+        if let Some(restart_policy) = restart_policy {
+            writeln!(unit).unwrap();
+            writeln!(unit, "[Service]").unwrap();
+            writeln!(unit, "Restart={restart_policy}").unwrap();
+        }
+
+        if pod.is_none() {
+            install_section(&mut unit);
+        }
+
+        self.containers.insert(name, unit);
+    }
+
+    /// Adds a `.volume` file and returns the reference to use in other units.
+    fn add_volume(&mut self, name: &str) -> String {
+        self.volumes
+            .entry(name.to_owned())
+            .or_insert_with(|| format!("[Volume]\nVolumeName={name}\n"));
+
+        format!("{name}.volume")
+    }
+
+    /// Adds a `.network` file and returns the reference to use in other units. The default
+    /// networks are referenced by their names.
+    fn add_network(&mut self, name: &str) -> String {
+        if matches!(name, "podman" | "bridge" | "host" | "none") {
+            return name.to_owned();
+        }
+
+        self.networks
+            .entry(name.to_owned())
+            .or_insert_with(|| format!("[Network]\nNetworkName={name}\n"));
+
+        format!("{name}.network")
+    }
+}
+
+fn unit_section(unit: &mut String, description: &str) {
+    writeln!(unit, "[Unit]").unwrap();
+    writeln!(unit, "Description={description}").unwrap();
+    writeln!(unit).unwrap();
+}
+
+fn install_section(unit: &mut String) {
+    writeln!(unit).unwrap();
+    writeln!(unit, "[Install]").unwrap();
+    writeln!(unit, "WantedBy=default.target").unwrap();
+}
+
+fn publish_port(unit: &mut String, port_mapping: &engine::dto::PortMapping) {
+    let Some(host_port) = port_mapping.host_port else {
+        return;
+    };
+
+    write!(unit, "PublishPort=").unwrap();
+    if !port_mapping.host_ip.is_empty() {
+        write!(unit, "{}:", port_mapping.host_ip).unwrap();
+    }
+    writeln!(
+        unit,
+        "{host_port}:{}/{}",
+        port_mapping.container_port, port_mapping.protocol
+    )
+    .unwrap();
+}
+
+/// Quotes a value the way systemd splits arguments if it contains whitespace or quotes. `%` is
+/// always doubled, as systemd would expand it as a specifier.
+fn quote(value: &str) -> String {
+    let value = value.replace('%', "%%");
+    if value.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'' || c == '\\') {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value
+    }
+}
+
+/// Converts the response of the legacy `podman generate systemd` into unit files.
+pub(crate) fn systemd_unit_files(units: HashMap<String, String>) -> Vec<engine::dto::UnitFile> {
+    let mut units = units
+        .into_iter()
+        .map(|(name, content)| engine::dto::UnitFile {
+            name: format!("{name}.service"),
+            content,
+        })
+        .collect::<Vec<_>>();
+    units.sort_by(|a, b| a.name.cmp(&b.name));
+    units
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("plain"), "plain");
+        assert_eq!(quote("hello world"), "\"hello world\"");
+        assert_eq!(quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote("C:\\dir"), "\"C:\\\\dir\"");
+        assert_eq!(quote("100%"), "100%%");
+        assert_eq!(quote("echo 100%"), "\"echo 100%%\"");
+    }
+
+    #[test]
+    fn test_container_unit() {
+        let inspection = serde_json::from_value(serde_json::json!({
+            "Name": "app",
+            "ImageName": "docker.io/library/nginx:latest",
+            "Mounts": [
+                { "Type": "volume", "Name": "data", "Destination": "/data", "RW": true },
+                { "Type": "bind", "Source": "/srv/www", "Destination": "/www", "RW": false },
+            ],
+            "Config": {
+                "Env": ["PATH=/usr/bin", "HOSTNAME=abc", "GREETING=hello world"],
+                "Labels": { "app": "web", "maintainer": "nginx" },
+                "Entrypoint": "/bin/sh -c",
+                "Cmd": ["echo 100%"],
+                "User": "",
+                "WorkingDir": "/",
+            },
+            "HostConfig": { "RestartPolicy": { "Name": "unless-stopped" } },
+        }))
+        .unwrap();
+        let image_config = serde_json::from_value(serde_json::json!({
+            "Env": ["PATH=/usr/bin"],
+            "Labels": { "maintainer": "nginx" },
+            "Entrypoint": ["/docker-entrypoint.sh"],
+            "Cmd": ["nginx", "-g", "daemon off;"],
+        }))
+        .unwrap();
+
+        let mut units = Units::default();
+        units.add_container(inspection, Some(image_config), Some("web"));
+
+        let units = units
+            .into_unit_files()
+            .into_iter()
+            .map(|unit| (unit.name, unit.content))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            units,
+            [
+                (
+                    "app.container".to_owned(),
+                    "[Unit]
+Description=app container
+
+[Container]
+ContainerName=app
+Image=docker.io/library/nginx:latest
+Pod=web.pod
+Volume=data.volume:/data
+Volume=/srv/www:/www:ro
+Environment=\"GREETING=hello world\"
+Label=app=web
+Entrypoint=[\"/bin/sh\",\"-c\"]
+Exec=\"echo 100%%\"
+
+[Service]
+Restart=always
+"
+                    .to_owned()
+                ),
+                (
+                    "data.volume".to_owned(),
+                    "[Volume]\nVolumeName=data\n".to_owned()
+                ),
+            ]
+        );
+    }
+}
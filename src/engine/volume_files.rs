@@ -0,0 +1,145 @@
+//! Access to the content of volumes. Neither engine exposes volume content through its API, so a
+//! helper container that mounts the volume is created. The container is never started, because
+//! the archive endpoint also works for containers that have only been created.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use gtk::glib;
+
+use crate::engine;
+use crate::keyring;
+
+/// A small image for helper containers. It is pulled if it is not present.
+const HELPER_IMAGE: &str = "docker.io/library/busybox:latest";
+/// Label attached to helper containers with the name of the mounted volume as value.
+pub(crate) const HELPER_LABEL: &str = "com.github.marhkb.Pods.volume-helper";
+/// The path at which helper containers mount the volume.
+pub(crate) const VOLUME_MOUNT_PATH: &str = "/volume";
+
+/// A created container that mounts a volume. The container is removed when this is dropped.
+pub(crate) struct VolumeHelper {
+    container: Option<engine::api::Container>,
+    runtime: tokio::runtime::Handle,
+}
+
+impl VolumeHelper {
+    pub(crate) async fn new(engine: &engine::Engine, volume: &str) -> anyhow::Result<Self> {
+        let images = engine.images();
+        if images.get(HELPER_IMAGE).inspect().await.is_err() {
+            log::info!("Pulling '{HELPER_IMAGE}' for accessing volume '{volume}'");
+
+            let mut reports = keyring::pull_image(
+                &images,
+                engine::opts::ImagePullOpts {
+                    reference: HELPER_IMAGE.to_owned(),
+                    ..Default::default()
+                },
+            );
+            while let Some(report) = reports.next().await {
+                match report? {
+                    engine::dto::ImagePullReport::Error { message } => anyhow::bail!(message),
+                    engine::dto::ImagePullReport::Finished { .. } => break,
+                    engine::dto::ImagePullReport::Streaming { .. } => {}
+                }
+            }
+        }
+
+        let containers = engine.containers();
+        let id = containers
+            .create(engine::opts::ContainerCreateOpts {
+                image: HELPER_IMAGE.to_owned(),
+                labels: HashMap::from([(HELPER_LABEL.to_owned(), volume.to_owned())]),
+                name: format!(
+                    "{volume}-helper-{}",
+                    &glib::uuid_string_random().as_str()[..8]
+                ),
+                terminal: false,
+                volumes: vec![engine::opts::ContainerCreateVolumeOpts {
+                    container_path: VOLUME_MOUNT_PATH.to_owned(),
+                    read_only: false,
+                    selinux: engine::opts::SELinux::NoLabel,
+                    volume: volume.to_owned(),
+                }],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(Self {
+            container: Some(containers.get(id)),
+            runtime: tokio::runtime::Handle::current(),
+        })
+    }
+
+    pub(crate) fn container(&self) -> &engine::api::Container {
+        self.container.as_ref().unwrap()
+    }
+}
+
+impl Drop for VolumeHelper {
+    fn drop(&mut self) {
+        if let Some(container) = self.container.take() {
+            self.runtime.spawn(async move {
+                if let Err(e) = container.remove(true).await {
+                    log::warn!("Error on removing volume helper container: {e}");
+                }
+            });
+        }
+    }
+}
+
+impl engine::api::Volume {
+    /// Streams the content of the volume as a tar archive with paths relative to the volume.
+    pub(crate) fn export(
+        &self,
+        engine: engine::Engine,
+    ) -> BoxStream<'static, anyhow::Result<Bytes>> {
+        let volume = self.name().to_owned();
+
+        async_stream::try_stream! {
+            let helper = VolumeHelper::new(&engine, &volume).await?;
+
+            let mut stream = helper
+                .container()
+                .copy_from(format!("{VOLUME_MOUNT_PATH}/."));
+            while let Some(chunk) = stream.next().await {
+                yield chunk?;
+            }
+        }
+        .boxed()
+    }
+
+    /// Extracts a tar archive into the volume, overwriting existing files. The archive is streamed
+    /// from disk.
+    pub(crate) fn import(
+        &self,
+        engine: engine::Engine,
+        path: PathBuf,
+    ) -> BoxStream<'_, anyhow::Result<engine::dto::UploadReport<()>>> {
+        engine::conn::upload(path, move |body| async move {
+            let helper = VolumeHelper::new(&engine, self.name()).await?;
+            helper
+                .container()
+                .copy_to_stream(VOLUME_MOUNT_PATH.to_owned(), body)
+                .await
+        })
+    }
+}
+
+/// Removes helper containers that have been left behind, e.g. because the application was
+/// terminated while accessing a volume.
+pub(crate) async fn remove_stale_helpers(engine: &engine::Engine) -> anyhow::Result<()> {
+    let containers = engine.containers();
+
+    for dto in containers.list().await? {
+        if dto.is_volume_helper() {
+            log::info!("Removing stale volume helper container '{}'", dto.id());
+            containers.get(dto.id()).remove(true).await?;
+        }
+    }
+
+    Ok(())
+}
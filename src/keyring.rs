@@ -0,0 +1,159 @@
+//! Registry credentials stored in the Secret Service.
+
+use std::collections::HashMap;
+
+use futures::StreamExt;
+use futures::stream;
+use futures::stream::BoxStream;
+
+use crate::engine;
+
+/// Attribute holding the registry host of a secret.
+pub(crate) const REGISTRY_ATTRIBUTE: &str = "registry";
+/// Attribute marking the secrets of registry accounts.
+const SCHEMA_ATTRIBUTE: &str = "xdg:schema";
+const REGISTRY_SCHEMA: &str = "com.github.marhkb.Pods.Registry";
+
+/// Looks up the credentials stored for a registry host. Credentials of registry accounts take
+/// precedence over credentials saved when pushing an image.
+pub(crate) async fn registry_credentials(
+    host: &str,
+) -> anyhow::Result<Option<engine::auth::Credentials>> {
+    let Some(keyring) = crate::KEYRING.get() else {
+        return Ok(None);
+    };
+
+    let mut items = keyring
+        .search_items(&HashMap::from([
+            (SCHEMA_ATTRIBUTE, REGISTRY_SCHEMA),
+            (REGISTRY_ATTRIBUTE, host),
+        ]))
+        .await?;
+    if items.is_empty() {
+        items = keyring
+            .search_items(&HashMap::from([(REGISTRY_ATTRIBUTE, host)]))
+            .await?;
+    }
+
+    match items.first() {
+        Some(item) => serde_json::from_slice(item.secret().await?.as_bytes())
+            .map(Some)
+            .map_err(anyhow::Error::from),
+        None => Ok(None),
+    }
+}
+
+/// Returns all registry accounts sorted by their hosts.
+pub(crate) async fn registry_accounts() -> anyhow::Result<Vec<(String, engine::auth::Credentials)>>
+{
+    let Some(keyring) = crate::KEYRING.get() else {
+        return Ok(Vec::new());
+    };
+
+    let mut accounts = Vec::new();
+    for item in keyring
+        .search_items(&HashMap::from([(SCHEMA_ATTRIBUTE, REGISTRY_SCHEMA)]))
+        .await?
+    {
+        let Some(host) = item.attributes().await?.remove(REGISTRY_ATTRIBUTE) else {
+            continue;
+        };
+        let credentials = serde_json::from_slice(item.secret().await?.as_bytes())?;
+
+        accounts.push((host, credentials));
+    }
+    accounts.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(accounts)
+}
+
+/// Stores the credentials for a registry host and replaces previously stored ones.
+pub(crate) async fn store_registry_credentials(
+    host: &str,
+    credentials: &engine::auth::Credentials,
+) -> anyhow::Result<()> {
+    let keyring = crate::KEYRING
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("Secret Service is not available"))?;
+
+    keyring
+        .create_item(
+            host,
+            &HashMap::from([
+                (SCHEMA_ATTRIBUTE, REGISTRY_SCHEMA),
+                (REGISTRY_ATTRIBUTE, host),
+            ]),
+            serde_json::to_vec(credentials)?,
+            true,
+        )
+        .await
+        .map_err(anyhow::Error::from)
+}
+
+/// Removes the registry account of a host.
+pub(crate) async fn delete_registry_credentials(host: &str) -> anyhow::Result<()> {
+    let keyring = crate::KEYRING
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("Secret Service is not available"))?;
+
+    keyring
+        .delete(&HashMap::from([
+            (SCHEMA_ATTRIBUTE, REGISTRY_SCHEMA),
+            (REGISTRY_ATTRIBUTE, host),
+        ]))
+        .await
+        .map_err(anyhow::Error::from)
+}
+
+async fn complete_credentials(
+    credentials: &mut Option<engine::auth::Credentials>,
+    reference: &str,
+) {
+    if credentials.is_some() {
+        return;
+    }
+
+    match registry_credentials(engine::auth::registry_host(reference)).await {
+        Ok(stored) => *credentials = stored,
+        Err(e) => log::warn!("Error on looking up registry credentials: {e}"),
+    }
+}
+
+/// Searches images with the credentials stored for the registry the term points to.
+pub(crate) async fn search_images(
+    images: &engine::api::Images,
+    term: String,
+) -> anyhow::Result<Vec<engine::dto::ImageSearchResponseItem>> {
+    let mut credentials = None;
+    complete_credentials(&mut credentials, &term).await;
+
+    images.search(term, credentials).await
+}
+
+/// Pulls an image with the credentials stored for its registry unless the options already carry
+/// credentials.
+pub(crate) fn pull_image(
+    images: &engine::api::Images,
+    mut opts: engine::opts::ImagePullOpts,
+) -> BoxStream<'_, anyhow::Result<engine::dto::ImagePullReport>> {
+    stream::once(async move {
+        complete_credentials(&mut opts.credentials, &opts.reference).await;
+        opts
+    })
+    .flat_map(move |opts| images.pull(opts))
+    .boxed()
+}
+
+/// Pushes an image with the credentials stored for its registry unless the options already carry
+/// credentials.
+pub(crate) fn push_image(
+    image: &engine::api::Image,
+    mut opts: engine::opts::ImagePushOpts,
+) -> BoxStream<'_, anyhow::Result<engine::dto::ImagePushReport>> {
+    stream::once(async move {
+        complete_credentials(&mut opts.credentials, &opts.repo).await;
+        opts
+    })
+    .flat_map(move |opts| image.push(opts))
+    .boxed()
+}
@@ -5,7 +5,9 @@ mod application;
 #[rustfmt::skip]
 mod config;
 mod engine;
+mod keyring;
 mod model;
+mod registry;
 mod rt;
 mod utils;
 mod view;
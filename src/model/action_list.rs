@@ -182,6 +182,10 @@ impl ActionList {
         self.insert_action(model::ContainerCommitAction::new(self, container, opts))
     }
 
+    pub(crate) fn compose_up(&self, opts: engine::opts::ComposeUpOpts) -> model::ComposeUpAction {
+        self.insert_action(model::ComposeUpAction::new(self, opts))
+    }
+
     pub(crate) fn copy_from_container(
         &self,
         container: &model::Container,
@@ -220,6 +224,13 @@ impl ActionList {
         self.insert_action(model::ContainerCreateAction::new(self, opts, run, |_| {}))
     }
 
+    pub(crate) fn create_network(
+        &self,
+        opts: engine::opts::NetworkCreateOpts,
+    ) -> model::NetworkCreateAction {
+        self.insert_action(model::NetworkCreateAction::new(self, opts))
+    }
+
     pub(crate) fn create_pod(&self, opts: engine::opts::PodCreateOpts) -> model::PodCreateAction {
         self.insert_action(model::PodCreateAction::new(self, opts))
     }
@@ -231,6 +242,69 @@ impl ActionList {
         self.insert_action(model::VolumeCreateAction::new(self, opts))
     }
 
+    pub(crate) fn checkpoint_container(
+        &self,
+        container: &model::Container,
+        opts: engine::opts::ContainerCheckpointOpts,
+        host_path: &str,
+    ) -> model::ContainerCheckpointAction {
+        self.insert_action(model::ContainerCheckpointAction::new(
+            self, container, opts, host_path,
+        ))
+    }
+
+    pub(crate) fn restore_container(
+        &self,
+        opts: engine::opts::ContainerRestoreOpts,
+    ) -> model::ContainerRestoreAction {
+        self.insert_action(model::ContainerRestoreAction::new(self, opts))
+    }
+
+    pub(crate) fn export_container(
+        &self,
+        container: &model::Container,
+        host_path: &str,
+    ) -> model::ContainerExportAction {
+        self.insert_action(model::ContainerExportAction::new(
+            self, container, host_path,
+        ))
+    }
+
+    pub(crate) fn export_volume(
+        &self,
+        volume: &model::Volume,
+        host_path: &str,
+    ) -> model::VolumeExportAction {
+        self.insert_action(model::VolumeExportAction::new(self, volume, host_path))
+    }
+
+    pub(crate) fn import_image(
+        &self,
+        opts: engine::opts::ImageImportOpts,
+        host_path: &str,
+    ) -> model::ImageImportAction {
+        self.insert_action(model::ImageImportAction::new(self, opts, host_path))
+    }
+
+    pub(crate) fn import_volume(
+        &self,
+        volume: &model::Volume,
+        host_path: &str,
+    ) -> model::VolumeImportAction {
+        self.insert_action(model::VolumeImportAction::new(self, volume, host_path))
+    }
+
+    pub(crate) fn load_images(&self, host_path: &str) -> model::ImagesLoadAction {
+        self.insert_action(model::ImagesLoadAction::new(self, host_path))
+    }
+
+    pub(crate) fn play_kube(
+        &self,
+        opts: engine::opts::PodsPlayKubeOpts,
+    ) -> model::PodsPlayKubeAction {
+        self.insert_action(model::PodsPlayKubeAction::new(self, opts))
+    }
+
     pub(crate) fn pull_image(&self, opts: engine::opts::ImagePullOpts) -> model::ImagePullAction {
         self.insert_action(model::ImagePullAction::new(self, opts))
     }
@@ -257,6 +331,13 @@ impl ActionList {
         self.insert_action(model::ImagesPruneAction::new(self, opts))
     }
 
+    pub(crate) fn prune_networks(
+        &self,
+        opts: engine::opts::NetworksPruneOpts,
+    ) -> model::NetworksPruneAction {
+        self.insert_action(model::NetworksPruneAction::new(self, opts))
+    }
+
     pub(crate) fn prune_pods(&self) -> model::PodsPruneAction {
         self.insert_action(model::PodsPruneAction::from(self))
     }
@@ -268,6 +349,14 @@ impl ActionList {
         self.insert_action(model::VolumesPruneAction::new(self, opts))
     }
 
+    pub(crate) fn save_images(
+        &self,
+        opts: engine::opts::ImagesSaveOpts,
+        host_path: &str,
+    ) -> model::ImagesSaveAction {
+        self.insert_action(model::ImagesSaveAction::new(self, opts, host_path))
+    }
+
     fn insert_action<A: IsA<model::Action>>(&self, action: A) -> A {
         let imp = self.imp();
 
@@ -36,6 +36,10 @@ mod imp {
         pub(super) pod_list: OnceCell<Option<model::PodList>>,
         #[property(get = Self::volume_list)]
         pub(super) volume_list: OnceCell<model::VolumeList>,
+        #[property(get = Self::network_list)]
+        pub(super) network_list: OnceCell<model::NetworkList>,
+        #[property(get = Self::secret_list, nullable)]
+        pub(super) secret_list: OnceCell<Option<model::SecretList>>,
         #[property(get = Self::info, set, nullable)]
         pub(super) info: OnceCell<Option<model::Info>>,
         #[property(get = Self::action_list)]
@@ -116,6 +120,13 @@ mod imp {
                                 mount,
                             ));
                         });
+
+                    obj.sync_networks(container);
+                    container.connect_networks_notify(clone!(
+                        #[weak]
+                        obj,
+                        move |container| obj.sync_networks(container)
+                    ));
                 }
             ));
 
@@ -144,6 +155,15 @@ mod imp {
                                 .container_list()
                                 .remove_container(container.id().as_str())
                         });
+
+                    obj.network_list()
+                        .iter::<model::Network>()
+                        .map(Result::unwrap)
+                        .for_each(|network| {
+                            network
+                                .container_list()
+                                .remove_container(container.id().as_str())
+                        });
                 }
             ));
 
@@ -204,6 +224,18 @@ mod imp {
                     });
                 }
             ));
+
+            obj.network_list().connect_network_added(clone!(
+                #[weak]
+                obj,
+                move |_, network| {
+                    obj.container_list()
+                        .iter::<model::Container>()
+                        .map(Result::unwrap)
+                        .filter(|container| container.networks().contains(&network.name()))
+                        .for_each(|container| network.container_list().add_container(&container));
+                }
+            ));
         }
     }
 
@@ -239,6 +271,25 @@ mod imp {
                 .to_owned()
         }
 
+        fn network_list(&self) -> model::NetworkList {
+            self.network_list
+                .get_or_init(|| model::NetworkList::from(&*self.obj()))
+                .to_owned()
+        }
+
+        fn secret_list(&self) -> Option<model::SecretList> {
+            self.secret_list
+                .get_or_init(|| {
+                    let obj = &*self.obj();
+                    if obj.engine().capabilities().secrets() {
+                        Some(model::SecretList::from(obj))
+                    } else {
+                        None
+                    }
+                })
+                .to_owned()
+        }
+
         fn info(&self) -> Option<model::Info> {
             self.info.get().cloned().flatten()
         }
@@ -262,6 +313,16 @@ impl Client {
             .property("engine", model::Engine::from(engine.clone()))
             .build();
 
+        rt::Promise::new({
+            let engine = engine.clone();
+            async move {
+                if let Err(e) = engine::volume_files::remove_stale_helpers(&engine).await {
+                    log::warn!("Error on removing stale volume helper containers: {e}");
+                }
+            }
+        })
+        .spawn();
+
         rt::Promise::new(async move { engine.info().await }).defer(clone!(
             #[weak]
             obj,
@@ -296,6 +357,10 @@ impl Client {
                         pod_list.refresh(err_op.clone());
                     }
                     obj.volume_list().refresh(err_op.clone());
+                    obj.network_list().refresh(err_op.clone());
+                    if let Some(secret_list) = obj.secret_list() {
+                        secret_list.refresh(err_op.clone());
+                    }
 
                     op();
                     obj.start_event_listener(err_op, finish_op);
@@ -338,6 +403,14 @@ impl Client {
                         engine::dto::EventType::Volume => {
                             obj.volume_list().handle_event(event, err_op.clone())
                         }
+                        engine::dto::EventType::Network => {
+                            obj.network_list().handle_event(event, err_op.clone())
+                        }
+                        engine::dto::EventType::Secret => {
+                            if let Some(secret_list) = obj.secret_list() {
+                                secret_list.handle_event(event, err_op.clone());
+                            }
+                        }
                         engine::dto::EventType::Other => {}
                     }
                     glib::ControlFlow::Continue
@@ -351,6 +424,25 @@ impl Client {
         ));
     }
 
+    /// Updates the container lists of all networks to match the networks of the container.
+    fn sync_networks(&self, container: &model::Container) {
+        let networks = container.networks();
+
+        self.network_list()
+            .iter::<model::Network>()
+            .map(Result::unwrap)
+            .for_each(|network| {
+                let container_list = network.container_list();
+                if networks.contains(&network.name()) {
+                    if !container_list.contains(container.id().as_str()) {
+                        container_list.add_container(container);
+                    }
+                } else {
+                    container_list.remove_container(container.id().as_str());
+                }
+            });
+    }
+
     /// This is needed to keep track of images and containers that are managed by Buildah.
     /// See https://github.com/marhkb/pods/issues/306
     fn start_refresh_interval(&self) {
@@ -362,7 +454,7 @@ impl Client {
                 #[upgrade_or]
                 glib::ControlFlow::Break,
                 move || {
-                    log::debug!("Syncing images, containers, pods and volumes");
+                    log::debug!("Syncing images, containers, pods, volumes, networks and secrets");
 
                     obj.image_list().refresh(|_| {});
                     obj.container_list().refresh(|_| {});
@@ -370,6 +462,10 @@ impl Client {
                         pod_list.refresh(|_| {});
                     }
                     obj.volume_list().refresh(|_| {});
+                    obj.network_list().refresh(|_| {});
+                    if let Some(secret_list) = obj.secret_list() {
+                        secret_list.refresh(|_| {});
+                    }
 
                     log::debug!("Sleeping for {SYNC_INTERVAL} seconds until next sync");
 
@@ -0,0 +1,176 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use futures::StreamExt;
+use futures::future;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::ComposeUpAction)]
+    pub(crate) struct ComposeUpAction {
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedComposeUpOpts>,
+
+        #[property(get, set, nullable)]
+        pub(super) project: RefCell<Option<String>>,
+        #[property(get, set)]
+        pub(super) steps: Cell<u32>,
+        #[property(get, set)]
+        pub(super) completed_steps: Cell<u32>,
+        #[property(get, set)]
+        pub(super) step_description: RefCell<String>,
+        #[property(get)]
+        pub(super) output: gtk::TextBuffer,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ComposeUpAction {
+        const NAME: &'static str = "ComposeUpAction";
+        type Type = super::ComposeUpAction;
+        type ParentType = model::ArtifactAction;
+    }
+
+    impl ObjectImpl for ComposeUpAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ComposeUpAction(ObjectSubclass<imp::ComposeUpAction>)
+        @extends model::Action, model::ArtifactAction;
+}
+
+impl ComposeUpAction {
+    pub(crate) fn new(action_list: &model::ActionList, opts: engine::opts::ComposeUpOpts) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("opts", model::BoxedComposeUpOpts::from(opts))
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(client) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+        else {
+            return self;
+        };
+
+        let opts = (*self.opts()).clone();
+        let abort_registration = self.setup_abort_handle();
+
+        rt::Pipe::new((*client.engine()).clone(), move |engine| {
+            future::Abortable::new(engine.compose_up(opts), abort_registration).boxed()
+        })
+        .on_next(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[upgrade_or]
+            glib::ControlFlow::Break,
+            move |report| match report {
+                Ok(report) => {
+                    match report {
+                        engine::dto::ComposeReport::Planned { project, steps } => {
+                            obj.set_project(Some(project));
+                            obj.set_steps(steps);
+                        }
+                        engine::dto::ComposeReport::Step { description } => {
+                            if !obj.step_description().is_empty() {
+                                obj.set_completed_steps(obj.completed_steps() + 1);
+                            }
+                            obj.insert_line(&description);
+                            obj.set_step_description(description);
+                        }
+                        engine::dto::ComposeReport::Streaming { line } => obj.insert(&line),
+                        engine::dto::ComposeReport::Finished { pod_id } => {
+                            obj.set_completed_steps(obj.steps());
+                            obj.insert_line(&gettext("Compose Project Created"));
+
+                            match pod_id {
+                                Some(pod_id) => obj.finish(pod_id),
+                                None => obj.set_state(model::ActionState::Finished),
+                            }
+
+                            return glib::ControlFlow::Break;
+                        }
+                    }
+                    glib::ControlFlow::Continue
+                }
+                Err(e) => {
+                    log::error!("error on creating compose project: {e}");
+                    obj.set_failed(&e.to_string());
+                    glib::ControlFlow::Break
+                }
+            }
+        ));
+
+        self
+    }
+
+    fn finish(&self, pod_id: String) {
+        let Some(pod_list) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .and_then(|client| client.pod_list())
+        else {
+            self.set_state(model::ActionState::Finished);
+            return;
+        };
+
+        let op = clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |pod: &model::Pod| {
+                obj.set_artifact(Some(pod.upcast_ref()));
+                obj.set_state(model::ActionState::Finished);
+            }
+        );
+
+        match pod_list.get_pod(&pod_id) {
+            Some(pod) => op(&pod),
+            None => {
+                pod_list.connect_pod_added(move |_, pod| {
+                    if pod.id() == pod_id {
+                        op(pod);
+                    }
+                });
+            }
+        }
+    }
+
+    fn insert(&self, text: &str) {
+        let output = self.output();
+        let mut iter = output.end_iter();
+
+        output.insert(&mut iter, text);
+    }
+
+    fn insert_line(&self, text: &str) {
+        self.insert(text);
+        self.insert("\n");
+    }
+}
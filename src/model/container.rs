@@ -17,6 +17,7 @@ use crate::monad_boxed_type;
 use crate::rt;
 
 monad_boxed_type!(pub(crate) BoxedMounts(Vec<engine::dto::Mount>) impls Debug, PartialEq is nullable);
+monad_boxed_type!(pub(crate) BoxedNetworks(Vec<String>) impls Debug, Default, PartialEq is nullable);
 monad_boxed_type!(pub(crate) BoxedContainerStats(engine::dto::ContainerStats) impls Debug, PartialEq is nullable);
 
 mod imp {
@@ -35,6 +36,10 @@ mod imp {
         #[property(get = Self::volume_list)]
         pub(super) volume_list: OnceCell<model::ContainerVolumeList>,
 
+        #[property(get, set, construct_only, nullable)]
+        pub(super) compose_project: RefCell<Option<String>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) compose_service: RefCell<Option<String>>,
         #[property(get, set, construct_only)]
         pub(super) created: OnceCell<i64>,
         #[property(get, set, construct_only)]
@@ -51,6 +56,8 @@ mod imp {
         pub(super) mounts: OnceCell<BoxedMounts>,
         #[property(get, set, construct)]
         pub(super) name: RefCell<String>,
+        #[property(get, set, construct)]
+        pub(super) networks: RefCell<BoxedNetworks>,
         #[property(get = Self::pod_id, set, construct_only, nullable)]
         pub(super) pod_id: OnceCell<Option<String>>,
         #[property(get, set, construct_only)]
@@ -177,6 +184,8 @@ impl Container {
     {
         op(glib::Object::builder()
             .property("container-list", container_list)
+            .property("compose-project", dto.compose_project)
+            .property("compose-service", dto.compose_service)
             .property("created", dto.created)
             .property(
                 "health-status",
@@ -188,6 +197,7 @@ impl Container {
             .property("is-infra", dto.is_infra)
             .property("mounts", BoxedMounts::from(dto.mounts))
             .property("name", dto.name)
+            .property("networks", BoxedNetworks::from(dto.networks))
             .property("pod-id", dto.pod_id)
             .property("ports", model::PortMappingList::from(dto.ports))
             .property("status", model::ContainerStatus::from(dto.status)))
@@ -204,6 +214,9 @@ impl Container {
     pub(crate) fn update_from_summary(&self, dto: engine::dto::ContainerSummary) {
         self.set_health_status(model::ContainerHealthStatus::from(dto.health_status));
         self.set_name(dto.name);
+        if *self.networks() != dto.networks {
+            self.set_networks(BoxedNetworks::from(dto.networks));
+        }
         self.set_status(model::ContainerStatus::from(dto.status));
     }
 
@@ -372,6 +385,26 @@ impl Container {
         );
     }
 
+    pub(crate) fn update_resources<F>(&self, opts: engine::opts::ContainerUpdateOpts, op: F)
+    where
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        self.action(
+            "updating",
+            |container| async move { container.update(opts).await },
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| {
+                    if result.is_ok() {
+                        obj.inspect_and_update(|e| log::error!("inspect container: {e}"));
+                    }
+                    op(result)
+                }
+            ),
+        );
+    }
+
     pub(crate) fn remove<F>(&self, op: F)
     where
         F: FnOnce(anyhow::Result<()>) + 'static,
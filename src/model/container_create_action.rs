@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::cell::OnceCell;
 
 use adw::prelude::*;
@@ -10,6 +11,7 @@ use glib::subclass::prelude::*;
 use gtk::glib;
 
 use crate::engine;
+use crate::keyring;
 use crate::model;
 use crate::model::prelude::*;
 use crate::rt;
@@ -24,6 +26,9 @@ mod imp {
         pub(super) opts: OnceCell<model::BoxedContainerCreateOpts>,
         #[property(get)]
         pub(super) output: gtk::TextBuffer,
+        /// set when the registry denied pulling the image because of missing or wrong credentials
+        #[property(get, set)]
+        pub(super) auth_required: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -82,18 +87,23 @@ impl ContainerCreateAction {
 
         let engine = client.engine();
 
-        if !self.opts().pull_latest && client.image_list().find_image(&self.opts().image).is_some()
+        // A local image may belong to another platform than the requested one.
+        if !self.opts().pull_latest
+            && self.opts().platform.is_none()
+            && client.image_list().find_image(&self.opts().image).is_some()
         {
             return self.exec_create_container(&engine, run, err_op);
         }
 
         let opts = engine::opts::ImagePullOpts {
+            platform: self.opts().platform.clone(),
             reference: self.opts().image.clone(),
+            ..Default::default()
         };
         let abort_registration = self.setup_abort_handle();
 
         rt::Pipe::new(engine.images(), move |images| {
-            future::Abortable::new(images.pull(opts), abort_registration).boxed()
+            future::Abortable::new(keyring::pull_image(images, opts), abort_registration).boxed()
         })
         .on_next(clone!(
             #[weak(rename_to = obj)]
@@ -106,6 +116,7 @@ impl ContainerCreateAction {
                 Ok(report) => match report {
                     engine::dto::ImagePullReport::Error { message } => {
                         log::warn!("error pulling container image: {message}");
+                        obj.set_auth_required(engine::auth::is_unauthorized(&message));
                         obj.set_failed(&message);
                         glib::ControlFlow::Break
                     }
@@ -120,7 +131,9 @@ impl ContainerCreateAction {
                 },
                 Err(e) => {
                     log::warn!("error pulling container image: {e}");
-                    obj.set_failed(&e.to_string());
+                    let message = e.to_string();
+                    obj.set_auth_required(engine::auth::is_unauthorized(&message));
+                    obj.set_failed(&message);
                     glib::ControlFlow::Break
                 }
             }
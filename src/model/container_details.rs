@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
+use std::cell::RefCell;
 
 use glib::Properties;
 use glib::prelude::*;
@@ -11,6 +12,7 @@ use crate::model;
 use crate::monad_boxed_type;
 
 monad_boxed_type!(pub(crate) BoxedHealthConfig(engine::dto::HealthConfig) impls Debug is nullable);
+monad_boxed_type!(pub(crate) BoxedResourceLimits(engine::dto::ResourceLimits) impls Debug, Default);
 
 mod imp {
     use super::*;
@@ -24,7 +26,9 @@ mod imp {
         pub(super) health_config: OnceCell<Option<BoxedHealthConfig>>,
         #[property(get, set)]
         pub(super) health_failing_streak: Cell<u32>,
-        #[property(get, set, construct_only, default)]
+        #[property(get, set, construct)]
+        pub(super) resource_limits: RefCell<BoxedResourceLimits>,
+        #[property(get, set, construct, default)]
         pub(super) restart_policy: Cell<model::ContainerRestartPolicy>,
         #[property(get, set, construct)]
         pub(super) size: Cell<i64>,
@@ -66,6 +70,10 @@ impl From<engine::dto::ContainerDetails> for ContainerDetails {
             )
             .property("health-config", value.health_config.map(BoxedHealthConfig))
             .property("health-failing-streak", value.health_failing_streak)
+            .property(
+                "resource-limits",
+                BoxedResourceLimits::from(value.resource_limits),
+            )
             .property(
                 "restart-policy",
                 model::ContainerRestartPolicy::from(value.restart_policy),
@@ -80,6 +88,8 @@ impl ContainerDetails {
     pub(crate) fn update(&self, dto: engine::dto::ContainerDetails) {
         self.health_check_logs().sync(dto.health_check_logs);
         self.set_health_failing_streak(dto.health_failing_streak);
+        self.set_resource_limits(BoxedResourceLimits::from(dto.resource_limits));
+        self.set_restart_policy(model::ContainerRestartPolicy::from(dto.restart_policy));
         self.set_size(dto.size);
         self.set_up_since(dto.up_since);
     }
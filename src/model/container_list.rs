@@ -249,6 +249,11 @@ impl ContainerList {
     }
 
     fn upsert_container(&self, dto: engine::dto::Container) {
+        // Containers that only exist while accessing the content of a volume are not shown.
+        if dto.is_volume_helper() {
+            return;
+        }
+
         if let Some(container) = self.get_container(dto.id()) {
             container.update(dto);
         } else {
@@ -0,0 +1,131 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use futures::StreamExt;
+use futures::stream;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::ContainerRestoreAction)]
+    pub(crate) struct ContainerRestoreAction {
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedContainerRestoreOpts>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerRestoreAction {
+        const NAME: &'static str = "ContainerRestoreAction";
+        type Type = super::ContainerRestoreAction;
+        type ParentType = model::ArtifactAction;
+    }
+
+    impl ObjectImpl for ContainerRestoreAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerRestoreAction(ObjectSubclass<imp::ContainerRestoreAction>)
+        @extends model::Action, model::ArtifactAction;
+}
+
+impl ContainerRestoreAction {
+    pub(crate) fn new(
+        action_list: &model::ActionList,
+        opts: engine::opts::ContainerRestoreOpts,
+    ) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("opts", model::BoxedContainerRestoreOpts::from(opts))
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(api) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.engine().containers())
+        else {
+            return self;
+        };
+
+        let abort_registration = self.setup_abort_handle();
+
+        rt::Pipe::new(api, {
+            let opts = (*self.opts()).clone();
+            move |containers| {
+                stream::Abortable::new(containers.restore(opts), abort_registration).boxed()
+            }
+        })
+        .on_next(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[upgrade_or]
+            glib::ControlFlow::Break,
+            move |report| match report {
+                Ok(engine::dto::UploadReport::Sending { .. }) => glib::ControlFlow::Continue,
+                Ok(engine::dto::UploadReport::Finished(container_id)) => {
+                    obj.finish(container_id);
+                    glib::ControlFlow::Break
+                }
+                Err(e) => {
+                    log::warn!("error restoring container: {e}");
+                    obj.set_failed(&e.to_string());
+                    glib::ControlFlow::Break
+                }
+            }
+        ));
+
+        self
+    }
+
+    fn finish(&self, container_id: String) {
+        let Some(container_list) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.container_list())
+        else {
+            return;
+        };
+
+        match container_list.get_container(&container_id) {
+            Some(container) => {
+                self.set_artifact(Some(container.upcast_ref()));
+                self.set_state(model::ActionState::Finished);
+            }
+            None => {
+                container_list.connect_container_added(clone!(
+                    #[weak(rename_to = obj)]
+                    self,
+                    move |_, container| {
+                        if container.id() == container_id {
+                            obj.set_artifact(Some(container.upcast_ref()));
+                            obj.set_state(model::ActionState::Finished);
+                        }
+                    }
+                ));
+            }
+        }
+    }
+}
@@ -20,6 +20,8 @@ mod imp {
         #[property(get, set, construct_only)]
         pub(super) inner: OnceCell<BoxedCapabilities>,
 
+        #[property(get = Self::checkpoint)]
+        _checkpoint: PhantomData<bool>,
         #[property(get = Self::kube_generation)]
         _kube_generation: PhantomData<bool>,
         #[property(get = Self::manual_health_check)]
@@ -32,12 +34,18 @@ mod imp {
         _privileged_containers: PhantomData<bool>,
         #[property(get = Self::prune_external_images)]
         _prune_external_images: PhantomData<bool>,
+        #[property(get = Self::pull_image_tls_verify)]
+        _pull_image_tls_verify: PhantomData<bool>,
         #[property(get = Self::push_image_tls_verify)]
         _push_image_tls_verify: PhantomData<bool>,
         #[property(get = Self::prune_all_volumes)]
         _prune_all_volumes: PhantomData<bool>,
         #[property(get = Self::prune_volumes_until)]
         _prune_volumes_until: PhantomData<bool>,
+        #[property(get = Self::secrets)]
+        _secrets: PhantomData<bool>,
+        #[property(get = Self::systemd_generation)]
+        _systemd_generation: PhantomData<bool>,
     }
 
     #[glib::object_subclass]
@@ -61,6 +69,10 @@ mod imp {
     }
 
     impl EngineCapabilities {
+        pub(super) fn checkpoint(&self) -> bool {
+            self.obj().inner().checkpoint
+        }
+
         pub(super) fn kube_generation(&self) -> bool {
             self.obj().inner().kube_generation
         }
@@ -93,6 +105,10 @@ mod imp {
             self.obj().inner().prune_external_images
         }
 
+        pub(super) fn pull_image_tls_verify(&self) -> bool {
+            self.obj().inner().pull_image_tls_verify
+        }
+
         pub(super) fn push_image_tls_verify(&self) -> bool {
             self.obj().inner().push_image_tls_verify
         }
@@ -104,6 +120,14 @@ mod imp {
         pub(super) fn prune_volumes_until(&self) -> bool {
             self.obj().inner().prune_volumes_until
         }
+
+        pub(super) fn secrets(&self) -> bool {
+            self.obj().inner().secrets
+        }
+
+        pub(super) fn systemd_generation(&self) -> bool {
+            self.obj().inner().systemd_generation
+        }
     }
 }
 
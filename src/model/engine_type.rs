@@ -17,7 +17,7 @@ impl From<&engine::Engine> for EngineType {
     fn from(value: &engine::Engine) -> Self {
         match *value {
             engine::Engine::Docker(_) => Self::Docker,
-            engine::Engine::Podman(_) => Self::Podman,
+            engine::Engine::Podman(..) => Self::Podman,
         }
     }
 }
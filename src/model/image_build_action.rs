@@ -1,4 +1,6 @@
+use std::cell::Cell;
 use std::cell::OnceCell;
+use std::cell::RefCell;
 
 use adw::prelude::*;
 use futures::StreamExt;
@@ -6,6 +8,7 @@ use futures::stream;
 use glib::Properties;
 use glib::clone;
 use glib::subclass::prelude::*;
+use gtk::gio;
 use gtk::glib;
 
 use crate::engine;
@@ -23,6 +26,15 @@ mod imp {
         pub(super) opts: OnceCell<model::BoxedImageBuildOpts>,
         #[property(get)]
         pub(super) output: gtk::TextBuffer,
+        /// number of files in the build context or zero if unknown
+        #[property(get, set)]
+        pub(super) context_files: Cell<u32>,
+        #[property(get, set)]
+        pub(super) context_size: Cell<u64>,
+        #[property(get = Self::steps)]
+        pub(super) steps: OnceCell<gio::ListStore>,
+        /// incomplete last line of the output that has not been parsed yet
+        pub(super) pending_line: RefCell<String>,
     }
 
     #[glib::object_subclass]
@@ -45,6 +57,14 @@ mod imp {
             self.derived_property(id, pspec)
         }
     }
+
+    impl ImageBuildAction {
+        pub(super) fn steps(&self) -> gio::ListStore {
+            self.steps
+                .get_or_init(gio::ListStore::new::<model::ImageBuildStep>)
+                .to_owned()
+        }
+    }
 }
 
 glib::wrapper! {
@@ -69,6 +89,16 @@ impl ImageBuildAction {
             return self;
         };
 
+        // Also marks the current step as failed or cancelled.
+        self.connect_notify_local(Some("state"), |obj, _| {
+            let state = obj.state();
+            if state != model::ActionState::Ongoing
+                && let Some(step) = obj.current_step()
+            {
+                step.finish(state);
+            }
+        });
+
         let opts = (*self.opts()).clone();
         let abort_registration = self.setup_abort_handle();
 
@@ -86,6 +116,11 @@ impl ImageBuildAction {
             glib::ControlFlow::Break,
             move |report| match report {
                 Ok(report) => match report {
+                    engine::dto::ImageBuildReport::Context { files, size } => {
+                        obj.set_context_files(files as u32);
+                        obj.set_context_size(size);
+                        glib::ControlFlow::Continue
+                    }
                     engine::dto::ImageBuildReport::Error { message } => {
                         log::warn!("error building image: {message}");
                         obj.set_failed(&message);
@@ -146,5 +181,63 @@ impl ImageBuildAction {
         let mut iter = output.end_iter();
 
         output.insert(&mut iter, text);
+
+        let lines = {
+            let mut pending_line = self.imp().pending_line.borrow_mut();
+            pending_line.push_str(text);
+
+            match pending_line.rfind('\n') {
+                Some(index) => {
+                    let rest = pending_line.split_off(index + 1);
+                    std::mem::replace(&mut *pending_line, rest)
+                }
+                None => return,
+            }
+        };
+
+        lines
+            .lines()
+            .filter_map(engine::dto::ImageBuildProgress::parse)
+            .for_each(|progress| self.update_steps(progress));
+    }
+
+    fn update_steps(&self, progress: engine::dto::ImageBuildProgress) {
+        match progress {
+            engine::dto::ImageBuildProgress::Step {
+                stage,
+                number,
+                total,
+                instruction,
+            } => {
+                if let Some(step) = self.current_step() {
+                    step.finish(model::ActionState::Finished);
+                }
+                self.steps().append(&model::ImageBuildStep::new(
+                    stage,
+                    number,
+                    total,
+                    instruction,
+                ));
+            }
+            engine::dto::ImageBuildProgress::UsingCache => {
+                if let Some(step) = self.current_step() {
+                    step.set_cached(true);
+                }
+            }
+            engine::dto::ImageBuildProgress::Layer { id } => {
+                if let Some(step) = self.current_step() {
+                    step.set_layer(Some(id));
+                }
+            }
+        }
+    }
+
+    /// Returns the last step if it is still running.
+    fn current_step(&self) -> Option<model::ImageBuildStep> {
+        let steps = self.steps();
+        steps
+            .item(steps.n_items().checked_sub(1)?)
+            .and_downcast::<model::ImageBuildStep>()
+            .filter(|step| step.state() == model::ActionState::Ongoing)
     }
 }
@@ -0,0 +1,92 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use glib::Properties;
+use glib::prelude::*;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::model;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::ImageBuildStep)]
+    pub(crate) struct ImageBuildStep {
+        /// stage of a multi-stage build in the form of `n/m`
+        #[property(get, set, construct_only, nullable)]
+        pub(super) stage: OnceCell<Option<String>>,
+        #[property(get, set, construct_only)]
+        pub(super) number: OnceCell<u32>,
+        #[property(get, set, construct_only)]
+        pub(super) total: OnceCell<u32>,
+        #[property(get, set, construct_only)]
+        pub(super) instruction: OnceCell<String>,
+        /// monotonic time in microseconds the step has started at
+        #[property(get, set, construct_only)]
+        pub(super) start: OnceCell<i64>,
+        /// monotonic time in microseconds the step has ended at or zero if it is still running
+        #[property(get, set)]
+        pub(super) end: Cell<i64>,
+        #[property(get, set)]
+        pub(super) cached: Cell<bool>,
+        #[property(get, set, nullable)]
+        pub(super) layer: RefCell<Option<String>>,
+        #[property(get, set, builder(model::ActionState::Ongoing))]
+        pub(super) state: Cell<model::ActionState>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageBuildStep {
+        const NAME: &'static str = "ImageBuildStep";
+        type Type = super::ImageBuildStep;
+    }
+
+    impl ObjectImpl for ImageBuildStep {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageBuildStep(ObjectSubclass<imp::ImageBuildStep>);
+}
+
+impl ImageBuildStep {
+    pub(crate) fn new(stage: Option<String>, number: u32, total: u32, instruction: String) -> Self {
+        glib::Object::builder()
+            .property("stage", stage)
+            .property("number", number)
+            .property("total", total)
+            .property("instruction", instruction)
+            .property("start", glib::monotonic_time())
+            .build()
+    }
+
+    pub(crate) fn finish(&self, state: model::ActionState) {
+        if self.state() != model::ActionState::Ongoing {
+            return;
+        }
+        self.set_end(glib::monotonic_time());
+        self.set_state(state);
+    }
+
+    /// Elapsed time of the step in microseconds.
+    pub(crate) fn duration(&self) -> i64 {
+        match self.end() {
+            0 => glib::monotonic_time() - self.start(),
+            end => end - self.start(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
+use std::marker::PhantomData;
 
 use glib::Properties;
 use glib::prelude::*;
@@ -26,8 +27,14 @@ mod imp {
         pub(super) entrypoint: OnceCell<Option<String>>,
         #[property(get, set, construct_only)]
         pub(super) exposed_ports: OnceCell<gtk::StringList>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) os: OnceCell<Option<String>>,
+        #[property(get = Self::platform, nullable)]
+        pub(super) platform: PhantomData<Option<String>>,
         #[property(get, set, construct)]
         pub(super) shared_size: Cell<u64>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) variant: OnceCell<Option<String>>,
         #[property(get, set, construct)]
         pub(super) virtual_size: Cell<u64>,
     }
@@ -51,6 +58,24 @@ mod imp {
             self.derived_property(id, pspec)
         }
     }
+
+    impl ImageDetails {
+        fn platform(&self) -> Option<String> {
+            let obj = &*self.obj();
+
+            let os = obj.os().filter(|os| !os.is_empty())?;
+            let arch = obj.architecture().filter(|arch| !arch.is_empty())?;
+
+            Some(
+                engine::opts::Platform {
+                    os,
+                    arch,
+                    variant: obj.variant().filter(|variant| !variant.is_empty()),
+                }
+                .to_string(),
+            )
+        }
+    }
 }
 
 glib::wrapper! {
@@ -69,7 +94,9 @@ impl From<engine::dto::ImageDetails> for ImageDetails {
                 "exposed-ports",
                 gtk::StringList::from_iter(value.exposed_ports),
             )
+            .property("os", value.os)
             .property("shared-size", value.shared_size.unwrap_or(0))
+            .property("variant", value.variant)
             .property("virtual-size", value.virtual_size.unwrap_or(0))
             .build()
     }
@@ -0,0 +1,137 @@
+use std::cell::OnceCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use futures::StreamExt;
+use futures::stream;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::ImageImportAction)]
+    pub(crate) struct ImageImportAction {
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedImageImportOpts>,
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageImportAction {
+        const NAME: &'static str = "ImageImportAction";
+        type Type = super::ImageImportAction;
+        type ParentType = model::ArtifactAction;
+    }
+
+    impl ObjectImpl for ImageImportAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageImportAction(ObjectSubclass<imp::ImageImportAction>)
+        @extends model::Action, model::ArtifactAction;
+}
+
+impl ImageImportAction {
+    pub(crate) fn new(
+        action_list: &model::ActionList,
+        opts: engine::opts::ImageImportOpts,
+        host_path: &str,
+    ) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("opts", model::BoxedImageImportOpts::from(opts))
+            .property("host-path", host_path)
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(api) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.engine().images())
+        else {
+            return self;
+        };
+
+        let abort_registration = self.setup_abort_handle();
+
+        rt::Pipe::new(api, {
+            let host_path = PathBuf::from(self.host_path());
+            let opts = (*self.opts()).clone();
+            move |images| {
+                stream::Abortable::new(images.import(opts, host_path), abort_registration).boxed()
+            }
+        })
+        .on_next(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[upgrade_or]
+            glib::ControlFlow::Break,
+            move |report| match report {
+                Ok(engine::dto::UploadReport::Sending { .. }) => glib::ControlFlow::Continue,
+                Ok(engine::dto::UploadReport::Finished(image_id)) => {
+                    obj.finish(image_id);
+                    glib::ControlFlow::Break
+                }
+                Err(e) => {
+                    log::warn!("error importing image: {e}");
+                    obj.set_failed(&e.to_string());
+                    glib::ControlFlow::Break
+                }
+            }
+        ));
+
+        self
+    }
+
+    fn finish(&self, image_id: String) {
+        let Some(image_list) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.image_list())
+        else {
+            return;
+        };
+
+        match image_list.get_image(&image_id) {
+            Some(image) => {
+                self.set_artifact(Some(image.upcast_ref()));
+                self.set_state(model::ActionState::Finished);
+            }
+            None => {
+                image_list.connect_image_added(clone!(
+                    #[weak(rename_to = obj)]
+                    self,
+                    move |_, image| {
+                        if image.id() == image_id {
+                            obj.set_artifact(Some(image.upcast_ref()));
+                            obj.set_state(model::ActionState::Finished);
+                        }
+                    }
+                ));
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::cell::OnceCell;
 
 use adw::prelude::*;
@@ -9,6 +10,7 @@ use glib::subclass::prelude::*;
 use gtk::glib;
 
 use crate::engine;
+use crate::keyring;
 use crate::model;
 use crate::model::prelude::*;
 use crate::rt;
@@ -23,6 +25,9 @@ mod imp {
         pub(super) opts: OnceCell<model::BoxedImagePullOpts>,
         #[property(get)]
         pub(super) output: gtk::TextBuffer,
+        /// set when the registry denied the pull because of missing or wrong credentials
+        #[property(get, set)]
+        pub(super) auth_required: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -73,7 +78,7 @@ impl ImagePullAction {
         let abort_registration = self.setup_abort_handle();
 
         rt::Pipe::new(engine.images(), move |images| {
-            future::Abortable::new(images.pull(opts), abort_registration).boxed()
+            future::Abortable::new(keyring::pull_image(images, opts), abort_registration).boxed()
         })
         .on_next(clone!(
             #[weak(rename_to = obj)]
@@ -84,7 +89,7 @@ impl ImagePullAction {
                 Ok(report) => match report {
                     engine::dto::ImagePullReport::Error { message } => {
                         log::warn!("error pulling image: {message}");
-                        obj.set_failed(&message);
+                        obj.fail(&message);
                         glib::ControlFlow::Break
                     }
                     engine::dto::ImagePullReport::Streaming { line } => {
@@ -99,7 +104,7 @@ impl ImagePullAction {
                 },
                 Err(e) => {
                     log::warn!("error pulling image: {e}");
-                    obj.set_failed(&e.to_string());
+                    obj.fail(&e.to_string());
                     glib::ControlFlow::Break
                 }
             }
@@ -108,6 +113,11 @@ impl ImagePullAction {
         self
     }
 
+    fn fail(&self, message: &str) {
+        self.set_auth_required(engine::auth::is_unauthorized(message));
+        self.set_failed(message);
+    }
+
     fn finish(&self, image_id: String) {
         let Some(image_list) = self
             .action_list()
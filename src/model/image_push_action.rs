@@ -9,6 +9,7 @@ use glib::subclass::prelude::*;
 use gtk::glib;
 
 use crate::engine;
+use crate::keyring;
 use crate::model;
 use crate::model::prelude::*;
 use crate::rt;
@@ -81,7 +82,7 @@ impl ImagePushAction {
         let abort_registration = self.setup_abort_handle();
 
         rt::Pipe::new(api, move |api| {
-            future::Abortable::new(api.push(opts), abort_registration).boxed()
+            future::Abortable::new(keyring::push_image(api, opts), abort_registration).boxed()
         })
         .on_next(clone!(
             #[weak(rename_to = obj)]
@@ -0,0 +1,129 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use futures::StreamExt;
+use futures::stream;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::ImagesLoadAction)]
+    pub(crate) struct ImagesLoadAction {
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+
+        #[property(get, set)]
+        pub(super) archive_size: Cell<u64>,
+        #[property(get, set)]
+        pub(super) sent_bytes: Cell<u64>,
+        #[property(get, set, nullable)]
+        pub(super) loaded_images: RefCell<Option<gtk::StringList>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImagesLoadAction {
+        const NAME: &'static str = "ImagesLoadAction";
+        type Type = super::ImagesLoadAction;
+        type ParentType = model::Action;
+    }
+
+    impl ObjectImpl for ImagesLoadAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImagesLoadAction(ObjectSubclass<imp::ImagesLoadAction>)
+        @extends model::Action;
+}
+
+impl ImagesLoadAction {
+    pub(crate) fn new(action_list: &model::ActionList, host_path: &str) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("host-path", host_path)
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(api) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.engine().images())
+        else {
+            return self;
+        };
+
+        rt::Promise::new({
+            let host_path = self.host_path();
+            async move { tokio::fs::metadata(host_path).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok(metadata) => {
+                    obj.set_archive_size(metadata.len());
+
+                    let abort_registration = obj.setup_abort_handle();
+
+                    rt::Pipe::new(api, {
+                        let host_path = PathBuf::from(obj.host_path());
+                        move |images| {
+                            stream::Abortable::new(images.load(host_path), abort_registration)
+                                .boxed()
+                        }
+                    })
+                    .on_next(clone!(
+                        #[weak]
+                        obj,
+                        #[upgrade_or]
+                        glib::ControlFlow::Break,
+                        move |report| match report {
+                            Ok(engine::dto::UploadReport::Sending { sent }) => {
+                                obj.set_sent_bytes(sent);
+                                glib::ControlFlow::Continue
+                            }
+                            Ok(engine::dto::UploadReport::Finished(names)) => {
+                                obj.set_loaded_images(Some(gtk::StringList::from_iter(names)));
+                                obj.set_state(model::ActionState::Finished);
+                                glib::ControlFlow::Break
+                            }
+                            Err(e) => {
+                                log::warn!("error loading images: {e}");
+                                obj.set_failed(&e.to_string());
+                                glib::ControlFlow::Break
+                            }
+                        }
+                    ));
+                }
+                Err(e) => obj.set_failed(&e.to_string()),
+            }
+        ));
+
+        self
+    }
+}
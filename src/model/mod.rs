@@ -4,17 +4,21 @@ mod action_list;
 mod action_state;
 mod artifact_action;
 mod client;
+mod compose_up_action;
 mod connection;
 mod connection_manager;
 mod container;
+mod container_checkpoint_action;
 mod container_commit_action;
 mod container_copy_from_action;
 mod container_copy_to_action;
 mod container_create_action;
 mod container_details;
+mod container_export_action;
 mod container_health_status;
 mod container_list;
 mod container_restart_policy;
+mod container_restore_action;
 mod container_status;
 mod container_volume;
 mod container_volume_list;
@@ -27,29 +31,43 @@ mod health_check_log;
 mod health_check_log_list;
 mod image;
 mod image_build_action;
+mod image_build_step;
 mod image_details;
+mod image_import_action;
 mod image_list;
 mod image_pull_action;
 mod image_push_action;
 mod image_search_response;
+mod images_load_action;
 mod images_prune_action;
+mod images_save_action;
 mod info;
 mod key_val;
 mod mount;
+mod network;
+mod network_attachment;
+mod network_create_action;
+mod network_list;
+mod networks_prune_action;
 mod opts;
 mod pod;
 mod pod_create_action;
 mod pod_details;
 mod pod_list;
 mod pod_status;
+mod pods_play_kube_action;
 mod pods_prune_action;
 mod port_mapping;
 mod port_mapping_list;
 mod port_mapping_protocol;
 mod process;
 mod process_list;
+mod registry_account;
 mod repo_tag;
 mod repo_tag_list;
+mod secret;
+mod secret_list;
+mod secret_ref;
 mod selectable;
 mod selectable_list;
 mod simple_container_list;
@@ -57,6 +75,8 @@ mod suggestion_item;
 mod value;
 mod volume;
 mod volume_create_action;
+mod volume_export_action;
+mod volume_import_action;
 mod volume_list;
 mod volumes_prune_action;
 
@@ -87,19 +107,23 @@ export_gobjects![
     pub(crate) use self::action_state::ActionState;
     pub(crate) use self::artifact_action::ArtifactAction;
     pub(crate) use self::client::Client;
+    pub(crate) use self::compose_up_action::ComposeUpAction;
     pub(crate) use self::connection::Connection;
     pub(crate) use self::connection_manager::ConnectionManager;
     pub(crate) use self::container::BoxedContainerStats;
     pub(crate) use self::container::Container;
+    pub(crate) use self::container_checkpoint_action::ContainerCheckpointAction;
     pub(crate) use self::container_commit_action::ContainerCommitAction;
     pub(crate) use self::container_copy_from_action::ContainerCopyFromAction;
     pub(crate) use self::container_copy_to_action::ContainerCopyToAction;
     pub(crate) use self::container_copy_to_action::ContainerCopyToActionOngoingState;
     pub(crate) use self::container_create_action::ContainerCreateAction;
     pub(crate) use self::container_details::ContainerDetails;
+    pub(crate) use self::container_export_action::ContainerExportAction;
     pub(crate) use self::container_health_status::ContainerHealthStatus;
     pub(crate) use self::container_list::ContainerList;
     pub(crate) use self::container_restart_policy::ContainerRestartPolicy;
+    pub(crate) use self::container_restore_action::ContainerRestoreAction;
     pub(crate) use self::container_status::ContainerStatus;
     pub(crate) use self::container_volume::ContainerVolume;
     pub(crate) use self::container_volume_list::ContainerVolumeList;
@@ -112,23 +136,36 @@ export_gobjects![
     pub(crate) use self::health_check_log_list::HealthCheckLogList;
     pub(crate) use self::image::Image;
     pub(crate) use self::image_build_action::ImageBuildAction;
+    pub(crate) use self::image_build_step::ImageBuildStep;
     pub(crate) use self::image_details::ImageDetails;
+    pub(crate) use self::image_import_action::ImageImportAction;
     pub(crate) use self::image_list::ImageList;
     pub(crate) use self::image_pull_action::ImagePullAction;
     pub(crate) use self::image_push_action::ImagePushAction;
     pub(crate) use self::image_search_response::ImageSearchResponse;
+    pub(crate) use self::images_load_action::ImagesLoadAction;
     pub(crate) use self::images_prune_action::ImagesPruneAction;
+    pub(crate) use self::images_save_action::ImagesSaveAction;
     pub(crate) use self::info::Info;
     pub(crate) use self::key_val::KeyVal;
     pub(crate) use self::mount::Mount;
+    pub(crate) use self::network::Network;
+    pub(crate) use self::network_attachment::NetworkAttachment;
+    pub(crate) use self::network_create_action::NetworkCreateAction;
+    pub(crate) use self::network_list::NetworkList;
+    pub(crate) use self::networks_prune_action::NetworksPruneAction;
     pub(crate) use self::opts::BoxedContainerCommitOpts;
     pub(crate) use self::opts::BoxedContainerCreateOpts;
     pub(crate) use self::opts::BoxedContainerCreateVolumeOpts;
     pub(crate) use self::opts::BoxedContainersPruneOpts;
     pub(crate) use self::opts::BoxedImageBuildOpts;
+    pub(crate) use self::opts::BoxedImageImportOpts;
     pub(crate) use self::opts::BoxedImagePullOpts;
     pub(crate) use self::opts::BoxedImagePushOpts;
     pub(crate) use self::opts::BoxedImagesPruneOpts;
+    pub(crate) use self::opts::BoxedImagesSaveOpts;
+    pub(crate) use self::opts::BoxedNetworkCreateOpts;
+    pub(crate) use self::opts::BoxedNetworksPruneOpts;
     pub(crate) use self::opts::BoxedPodCreateOpts;
     pub(crate) use self::opts::BoxedVolumeCreateOpts;
     pub(crate) use self::opts::BoxedVolumesPruneOpts;
@@ -137,14 +174,19 @@ export_gobjects![
     pub(crate) use self::pod_details::PodDetails;
     pub(crate) use self::pod_list::PodList;
     pub(crate) use self::pod_status::PodStatus;
+    pub(crate) use self::pods_play_kube_action::PodsPlayKubeAction;
     pub(crate) use self::pods_prune_action::PodsPruneAction;
     pub(crate) use self::port_mapping::PortMapping;
     pub(crate) use self::port_mapping_list::PortMappingList;
     pub(crate) use self::port_mapping_protocol::PortMappingProtocol;
     pub(crate) use self::process::Process;
     pub(crate) use self::process_list::ProcessList;
+    pub(crate) use self::registry_account::RegistryAccount;
     pub(crate) use self::repo_tag::RepoTag;
     pub(crate) use self::repo_tag_list::RepoTagList;
+    pub(crate) use self::secret::Secret;
+    pub(crate) use self::secret_list::SecretList;
+    pub(crate) use self::secret_ref::SecretRef;
     pub(crate) use self::selectable::Selectable;
     pub(crate) use self::selectable_list::SelectableList;
     pub(crate) use self::simple_container_list::SimpleContainerList;
@@ -152,6 +194,8 @@ export_gobjects![
     pub(crate) use self::value::Value;
     pub(crate) use self::volume::Volume;
     pub(crate) use self::volume_create_action::VolumeCreateAction;
+    pub(crate) use self::volume_export_action::VolumeExportAction;
+    pub(crate) use self::volume_import_action::VolumeImportAction;
     pub(crate) use self::volume_list::VolumeList;
     pub(crate) use self::volumes_prune_action::VolumesPruneAction;
 ];
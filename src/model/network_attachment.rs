@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use glib::Properties;
+use glib::prelude::*;
+use glib::subclass::Signal;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::NetworkAttachment)]
+    pub(crate) struct NetworkAttachment {
+        #[property(get, set, construct_only)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set)]
+        pub(super) network: RefCell<String>,
+        // Comma separated list of aliases
+        #[property(get, set)]
+        pub(super) aliases: RefCell<String>,
+        #[property(get, set)]
+        pub(super) ipv4_address: RefCell<String>,
+        #[property(get, set)]
+        pub(super) ipv6_address: RefCell<String>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkAttachment {
+        const NAME: &'static str = "NetworkAttachment";
+        type Type = super::NetworkAttachment;
+    }
+
+    impl ObjectImpl for NetworkAttachment {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("remove-request").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkAttachment(ObjectSubclass<imp::NetworkAttachment>);
+}
+
+impl NetworkAttachment {
+    pub(crate) fn new(
+        client: &model::Client,
+        opts: Option<&engine::opts::ContainerCreateNetworkOpts>,
+    ) -> Self {
+        let opts = opts.cloned().unwrap_or_default();
+
+        glib::Object::builder()
+            .property("client", client)
+            .property("network", opts.network)
+            .property("aliases", opts.aliases.join(", "))
+            .property("ipv4-address", opts.ipv4_address.unwrap_or_default())
+            .property("ipv6-address", opts.ipv6_address.unwrap_or_default())
+            .build()
+    }
+
+    pub(crate) fn remove_request(&self) {
+        self.emit_by_name::<()>("remove-request", &[]);
+    }
+
+    pub(crate) fn connect_remove_request<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("remove-request", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
+
+impl From<&NetworkAttachment> for engine::opts::ContainerCreateNetworkOpts {
+    fn from(value: &NetworkAttachment) -> Self {
+        Self {
+            aliases: value
+                .aliases()
+                .split(',')
+                .map(str::trim)
+                .filter(|alias| !alias.is_empty())
+                .map(ToOwned::to_owned)
+                .collect(),
+            ipv4_address: Some(value.ipv4_address().trim().to_owned())
+                .filter(|address| !address.is_empty()),
+            ipv6_address: Some(value.ipv6_address().trim().to_owned())
+                .filter(|address| !address.is_empty()),
+            network: value.network(),
+        }
+    }
+}
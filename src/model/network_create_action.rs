@@ -0,0 +1,125 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::NetworkCreateAction)]
+    pub(crate) struct NetworkCreateAction {
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedNetworkCreateOpts>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkCreateAction {
+        const NAME: &'static str = "NetworkCreateAction";
+        type Type = super::NetworkCreateAction;
+        type ParentType = model::ArtifactAction;
+    }
+
+    impl ObjectImpl for NetworkCreateAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkCreateAction(ObjectSubclass<imp::NetworkCreateAction>)
+        @extends model::Action, model::ArtifactAction;
+}
+
+impl NetworkCreateAction {
+    pub(crate) fn new(
+        action_list: &model::ActionList,
+        opts: engine::opts::NetworkCreateOpts,
+    ) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("opts", model::BoxedNetworkCreateOpts::from(opts))
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(api) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.engine().networks())
+        else {
+            return self;
+        };
+
+        rt::Promise::new({
+            let opts = (*self.opts()).clone();
+            async move { api.create(opts).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |name| match name {
+                Ok(name) => {
+                    obj.finish(
+                        name,
+                        clone!(
+                            #[weak]
+                            obj,
+                            move |network| {
+                                obj.set_artifact(Some(network.upcast_ref()));
+                                obj.set_state(model::ActionState::Finished);
+                            }
+                        ),
+                    );
+                }
+                Err(e) => {
+                    log::warn!("error creating network: {e}");
+                    obj.set_failed(&e.to_string())
+                }
+            }
+        ));
+
+        self
+    }
+
+    fn finish<F>(&self, name: String, op: F)
+    where
+        F: Fn(&model::Network) + 'static,
+    {
+        let Some(network_list) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.network_list())
+        else {
+            return;
+        };
+
+        match network_list.get_network(&name) {
+            Some(network) => op(&network),
+            None => {
+                network_list.connect_network_added(move |_, network| {
+                    if network.name() == name {
+                        op(network);
+                    }
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,432 @@
+use std::borrow::Borrow;
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use gio::prelude::*;
+use gio::subclass::prelude::*;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::Signal;
+use gtk::gio;
+use gtk::glib;
+use indexmap::IndexMap;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::NetworkList)]
+    pub(crate) struct NetworkList {
+        pub(super) list: RefCell<IndexMap<String, model::Network>>,
+        #[property(get, set)]
+        pub(super) test: Cell<u32>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get)]
+        pub(super) listing: Cell<bool>,
+        #[property(get = Self::is_initialized, type = bool)]
+        pub(super) initialized: OnceCell<()>,
+        #[property(get, set)]
+        pub(super) selection_mode: Cell<bool>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkList {
+        const NAME: &'static str = "NetworkList";
+        type Type = super::NetworkList;
+        type Interfaces = (gio::ListModel, model::SelectableList);
+    }
+
+    impl ObjectImpl for NetworkList {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("network-added")
+                        .param_types([model::Network::static_type()])
+                        .build(),
+                    Signal::builder("network-removed")
+                        .param_types([model::Network::static_type()])
+                        .build(),
+                    Signal::builder("containers-of-network-changed")
+                        .param_types([model::Network::static_type()])
+                        .build(),
+                ]
+            })
+        }
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: OnceLock<Vec<glib::ParamSpec>> = OnceLock::new();
+            PROPERTIES.get_or_init(|| {
+                Self::derived_properties()
+                    .iter()
+                    .cloned()
+                    .chain(vec![
+                        glib::ParamSpecUInt::builder("len").read_only().build(),
+                        glib::ParamSpecUInt::builder("unused").read_only().build(),
+                        glib::ParamSpecUInt::builder("used").read_only().build(),
+                        glib::ParamSpecUInt::builder("num-selected")
+                            .read_only()
+                            .build(),
+                    ])
+                    .collect::<Vec<_>>()
+            })
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "len" => self.obj().len().to_value(),
+                "unused" => self.obj().unused().to_value(),
+                "used" => self.obj().used().to_value(),
+                "num-selected" => self.obj().num_selected().to_value(),
+                _ => self.derived_property(id, pspec),
+            }
+        }
+        fn constructed(&self) {
+            self.parent_constructed();
+            let obj = &*self.obj();
+
+            model::SelectableList::bootstrap(obj.upcast_ref());
+
+            obj.connect_items_changed(|self_, _, _, _| self_.notify("len"));
+
+            obj.connect_network_added(|list, _| list.notify_num_networks());
+            obj.connect_network_removed(|list, _| list.notify_num_networks());
+        }
+    }
+
+    impl ListModelImpl for NetworkList {
+        fn item_type(&self) -> glib::Type {
+            model::Network::static_type()
+        }
+
+        fn n_items(&self) -> u32 {
+            self.list.borrow().len() as u32
+        }
+
+        fn item(&self, position: u32) -> Option<glib::Object> {
+            self.list
+                .borrow()
+                .get_index(position as usize)
+                .map(|(_, obj)| obj.upcast_ref())
+                .cloned()
+        }
+    }
+
+    impl NetworkList {
+        pub(super) fn is_initialized(&self) -> bool {
+            self.initialized.get().is_some()
+        }
+
+        pub(super) fn set_as_initialized(&self) {
+            if self.is_initialized() {
+                return;
+            }
+            self.initialized.set(()).unwrap();
+            self.obj().notify("initialized");
+        }
+
+        pub(super) fn set_listing(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.listing() == value {
+                return;
+            }
+            self.listing.set(value);
+            obj.notify("listing");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkList(ObjectSubclass<imp::NetworkList>)
+        @implements gio::ListModel, model::SelectableList;
+}
+
+impl From<&model::Client> for NetworkList {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl NetworkList {
+    pub(crate) fn notify_num_networks(&self) {
+        self.notify("unused");
+        self.notify("used");
+    }
+
+    pub(crate) fn len(&self) -> u32 {
+        self.n_items()
+    }
+
+    pub(crate) fn unused(&self) -> u32 {
+        self.len() - self.used()
+    }
+
+    pub(crate) fn used(&self) -> u32 {
+        self.imp()
+            .list
+            .borrow()
+            .values()
+            .filter(|network| network.container_list().n_items() > 0)
+            .count() as u32
+    }
+
+    fn add_network(&self, inspection: engine::dto::Network) {
+        let network = model::Network::new(self, inspection);
+
+        let index = self.len();
+
+        self.imp()
+            .list
+            .borrow_mut()
+            .insert(network.name(), network.clone());
+
+        self.items_changed(index, 0, 1);
+        self.network_added(&network);
+    }
+
+    fn inspect_and_add_network<F>(&self, name: String, err_op: F)
+    where
+        F: FnOnce(anyhow::Error) + Clone + 'static,
+    {
+        let Some(api) = self.api() else { return };
+
+        rt::Promise::new(async move { api.get(&name).inspect().await }).defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |inspection| match inspection {
+                Ok(inspection) => obj.add_network(inspection),
+                Err(e) => err_op(e),
+            }
+        ));
+    }
+
+    pub(crate) fn get_network<Q: Borrow<str> + ?Sized>(&self, name: &Q) -> Option<model::Network> {
+        self.imp().list.borrow().get(name.borrow()).cloned()
+    }
+
+    pub(crate) fn remove_network(&self, name: &str) {
+        let mut list = self.imp().list.borrow_mut();
+        if let Some((idx, _, network)) = list.shift_remove_full(name) {
+            drop(list);
+
+            self.items_changed(idx as u32, 1, 0);
+            self.emit_by_name::<()>("network-removed", &[&network]);
+            network.emit_deleted();
+        }
+    }
+
+    pub(crate) fn refresh<F>(&self, err_op: F)
+    where
+        F: FnOnce(anyhow::Error) + Clone + 'static,
+    {
+        let Some(api) = self.api() else { return };
+
+        self.imp().set_listing(true);
+
+        rt::Promise::new(async move { api.list().await }).defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                match result {
+                    Ok(networks) => {
+                        let to_remove = obj
+                            .imp()
+                            .list
+                            .borrow()
+                            .keys()
+                            .filter(|name| !networks.iter().any(|network| &network.name == *name))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        to_remove.iter().for_each(|name| {
+                            obj.remove_network(name);
+                        });
+
+                        networks.into_iter().for_each(|network| {
+                            if obj.get_network(&network.name).is_none() {
+                                obj.add_network(network);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log::error!("Error on retrieving networks: {}", e);
+                        err_op(e);
+                    }
+                }
+                let imp = obj.imp();
+                imp.set_listing(false);
+                imp.set_as_initialized();
+            }
+        ));
+    }
+
+    pub(crate) fn handle_event<F>(&self, event: engine::dto::Event, err_op: F)
+    where
+        F: FnOnce(anyhow::Error) + Clone + 'static,
+    {
+        match event {
+            engine::Response::Docker(event) => {
+                let mut attributes = event
+                    .actor
+                    .and_then(|actor| actor.attributes)
+                    .unwrap_or_default();
+                let Some(action) = event.action else {
+                    return;
+                };
+
+                match action.as_str() {
+                    "destroy" => {
+                        if let Some(name) = attributes.remove("name") {
+                            self.remove_network(&name);
+                        }
+                    }
+                    "create" => {
+                        if let Some(name) = attributes.remove("name") {
+                            self.inspect_and_add_network(name, err_op);
+                        }
+                    }
+                    "connect" => {
+                        if let (Some(name), Some(container)) =
+                            (attributes.remove("name"), attributes.remove("container"))
+                        {
+                            self.connect_container(&name, &container);
+                        }
+                    }
+                    "disconnect" => {
+                        if let (Some(name), Some(container)) =
+                            (attributes.remove("name"), attributes.remove("container"))
+                        {
+                            self.disconnect_container(&name, &container);
+                        }
+                    }
+                    // Pruned networks are also reported with single "destroy" events.
+                    "prune" => {}
+                    other => log::debug!("unhandled network action: {other}"),
+                }
+            }
+            engine::Response::Podman(mut event) => {
+                let attributes = &mut event.actor.attributes;
+
+                match event.action.as_str() {
+                    "remove" => {
+                        if let Some(name) = attributes.remove("name") {
+                            self.remove_network(&name);
+                        }
+                    }
+                    "create" => {
+                        if let Some(name) = attributes.remove("name") {
+                            self.inspect_and_add_network(name, err_op);
+                        }
+                    }
+                    "connect" => {
+                        if let Some(name) = attributes.remove("network") {
+                            self.connect_container(&name, &event.actor.id);
+                        }
+                    }
+                    "disconnect" => {
+                        if let Some(name) = attributes.remove("network") {
+                            self.disconnect_container(&name, &event.actor.id);
+                        }
+                    }
+                    "prune" => {}
+                    other => log::debug!("unhandled network action: {other}"),
+                }
+            }
+        }
+    }
+
+    /// The container lists of the networks follow the networks of the container.
+    fn connect_container(&self, name: &str, container_id: &str) {
+        if let Some(container) = self.container(container_id) {
+            let mut networks = container.networks();
+            if !networks.iter().any(|network| network == name) {
+                networks.push(name.to_owned());
+                container.set_networks(networks);
+            }
+        }
+    }
+
+    fn disconnect_container(&self, name: &str, container_id: &str) {
+        if let Some(container) = self.container(container_id) {
+            let mut networks = container.networks();
+            if networks.iter().any(|network| network == name) {
+                networks.retain(|network| network != name);
+                container.set_networks(networks);
+            }
+        }
+    }
+
+    fn container(&self, id: &str) -> Option<model::Container> {
+        self.client()
+            .and_then(|client| client.container_list().get_container(id))
+    }
+
+    fn network_added(&self, network: &model::Network) {
+        self.emit_by_name::<()>("network-added", &[network]);
+        network.container_list().connect_notify_local(
+            Some("len"),
+            clone!(
+                #[weak(rename_to=obj)]
+                self,
+                #[weak]
+                network,
+                move |_, _| {
+                    obj.emit_by_name::<()>("containers-of-network-changed", &[&network]);
+                }
+            ),
+        );
+    }
+
+    pub(crate) fn connect_network_added<F: Fn(&Self, &model::Network) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_signal("network-added", f)
+    }
+
+    pub(crate) fn connect_network_removed<F: Fn(&Self, &model::Network) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_signal("network-removed", f)
+    }
+
+    pub(crate) fn connect_containers_of_network_changed<F: Fn(&Self, &model::Network) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_signal("containers-of-network-changed", f)
+    }
+
+    fn connect_signal<F: Fn(&Self, &model::Network) + 'static>(
+        &self,
+        signal: &str,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local(signal, true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            let network = values[1].get::<model::Network>().unwrap();
+            f(&obj, &network);
+
+            None
+        })
+    }
+
+    pub(crate) fn api(&self) -> Option<engine::api::Networks> {
+        self.client()
+            .as_ref()
+            .map(model::Client::engine)
+            .as_deref()
+            .map(engine::Engine::networks)
+    }
+}
@@ -0,0 +1,97 @@
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::NetworksPruneAction)]
+    pub(crate) struct NetworksPruneAction {
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedNetworksPruneOpts>,
+        #[property(get, set, nullable)]
+        pub(super) deleted_networks: RefCell<Option<gtk::StringList>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworksPruneAction {
+        const NAME: &'static str = "NetworksPruneAction";
+        type Type = super::NetworksPruneAction;
+        type ParentType = model::Action;
+    }
+
+    impl ObjectImpl for NetworksPruneAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworksPruneAction(ObjectSubclass<imp::NetworksPruneAction>)
+        @extends model::Action;
+}
+
+impl NetworksPruneAction {
+    pub(crate) fn new(
+        action_list: &model::ActionList,
+        opts: engine::opts::NetworksPruneOpts,
+    ) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("opts", model::BoxedNetworksPruneOpts::from(opts))
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(api) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.engine().networks())
+        else {
+            return self;
+        };
+
+        rt::Promise::new({
+            let opts = (*self.opts()).clone();
+            async move { api.prune(opts).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |prune_report| match prune_report {
+                Ok(prune_report) => {
+                    obj.set_deleted_networks(Some(gtk::StringList::from_iter(
+                        prune_report.deleted,
+                    )));
+                    obj.set_state(model::ActionState::Finished);
+                }
+                Err(e) => {
+                    log::warn!("error pruning networks: {e}");
+                    obj.set_failed(&e.to_string())
+                }
+            }
+        ));
+
+        self
+    }
+}
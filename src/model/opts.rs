@@ -3,17 +3,27 @@ use gtk::glib;
 use crate::engine;
 use crate::monad_boxed_type;
 
+monad_boxed_type!(pub(crate) BoxedComposeUpOpts(engine::opts::ComposeUpOpts) impls Default);
+
 monad_boxed_type!(pub(crate) BoxedContainerCreateOpts(engine::opts::ContainerCreateOpts) impls Default);
 monad_boxed_type!(pub(crate) BoxedContainerCreateVolumeOpts(engine::opts::ContainerCreateVolumeOpts) impls Default is nullable);
 monad_boxed_type!(pub(crate) BoxedContainersPruneOpts(engine::opts::ContainersPruneOpts) impls Default);
 monad_boxed_type!(pub(crate) BoxedContainerCommitOpts(engine::opts::ContainerCommitOpts) impls Default);
+monad_boxed_type!(pub(crate) BoxedContainerCheckpointOpts(engine::opts::ContainerCheckpointOpts) impls Default);
+monad_boxed_type!(pub(crate) BoxedContainerRestoreOpts(engine::opts::ContainerRestoreOpts) impls Default);
 
 monad_boxed_type!(pub(crate) BoxedPodCreateOpts(engine::opts::PodCreateOpts) impls Default);
+monad_boxed_type!(pub(crate) BoxedPodsPlayKubeOpts(engine::opts::PodsPlayKubeOpts) impls Default);
 
 monad_boxed_type!(pub(crate) BoxedImagesPruneOpts(engine::opts::ImagesPruneOpts) impls Default);
 monad_boxed_type!(pub(crate) BoxedImageBuildOpts(engine::opts::ImageBuildOpts) impls Default);
+monad_boxed_type!(pub(crate) BoxedImageImportOpts(engine::opts::ImageImportOpts) impls Default);
 monad_boxed_type!(pub(crate) BoxedImagePullOpts(engine::opts::ImagePullOpts) impls Default);
 monad_boxed_type!(pub(crate) BoxedImagePushOpts(engine::opts::ImagePushOpts) impls Default);
+monad_boxed_type!(pub(crate) BoxedImagesSaveOpts(engine::opts::ImagesSaveOpts) impls Default);
+
+monad_boxed_type!(pub(crate) BoxedNetworkCreateOpts(engine::opts::NetworkCreateOpts) impls Default);
+monad_boxed_type!(pub(crate) BoxedNetworksPruneOpts(engine::opts::NetworksPruneOpts) impls Default);
 
 monad_boxed_type!(pub(crate) BoxedVolumesPruneOpts(engine::opts::VolumesPruneOpts) impls Default);
 monad_boxed_type!(pub(crate) BoxedVolumeCreateOpts(engine::opts::VolumeCreateOpts) impls Default);
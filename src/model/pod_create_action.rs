@@ -10,6 +10,7 @@ use glib::subclass::prelude::*;
 use gtk::glib;
 
 use crate::engine;
+use crate::keyring;
 use crate::model;
 use crate::model::prelude::*;
 use crate::rt;
@@ -88,11 +89,12 @@ impl PodCreateAction {
 
         let opts = engine::opts::ImagePullOpts {
             reference: image.to_owned(),
+            ..Default::default()
         };
         let abort_registration = self.setup_abort_handle();
 
         rt::Pipe::new(engine.images(), move |images| {
-            future::Abortable::new(images.pull(opts), abort_registration).boxed()
+            future::Abortable::new(keyring::pull_image(images, opts), abort_registration).boxed()
         })
         .on_next(clone!(
             #[weak(rename_to = obj)]
@@ -0,0 +1,106 @@
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use futures::future;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::PodsPlayKubeAction)]
+    pub(crate) struct PodsPlayKubeAction {
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedPodsPlayKubeOpts>,
+        /// The created pods or, when tearing down, the removed pods.
+        #[property(get, set, nullable)]
+        pub(super) pods: RefCell<Option<gtk::StringList>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for PodsPlayKubeAction {
+        const NAME: &'static str = "PodsPlayKubeAction";
+        type Type = super::PodsPlayKubeAction;
+        type ParentType = model::Action;
+    }
+
+    impl ObjectImpl for PodsPlayKubeAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct PodsPlayKubeAction(ObjectSubclass<imp::PodsPlayKubeAction>)
+        @extends model::Action;
+}
+
+impl PodsPlayKubeAction {
+    pub(crate) fn new(
+        action_list: &model::ActionList,
+        opts: engine::opts::PodsPlayKubeOpts,
+    ) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("opts", model::BoxedPodsPlayKubeOpts::from(opts))
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(api) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| client.engine().pods())
+        else {
+            return self;
+        };
+
+        let opts = (*self.opts()).clone();
+        let down = opts.down;
+        let abort_registration = self.setup_abort_handle();
+
+        rt::Promise::new(async move {
+            future::Abortable::new(api.play_kube(opts), abort_registration).await
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |report| if let Ok(report) = report {
+                match report {
+                    Ok(report) => {
+                        obj.set_pods(Some(gtk::StringList::from_iter(if down {
+                            report.removed_pods
+                        } else {
+                            report.pods
+                        })));
+                        obj.set_state(model::ActionState::Finished);
+                    }
+                    Err(e) => {
+                        log::warn!("error on playing kube YAML: {e}");
+                        obj.set_failed(&e.to_string());
+                    }
+                }
+            }
+        ));
+
+        self
+    }
+}
@@ -0,0 +1,93 @@
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+use glib::Properties;
+use glib::prelude::*;
+use glib::subclass::Signal;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::monad_boxed_type;
+
+monad_boxed_type!(pub(crate) BoxedCredentials(engine::auth::Credentials));
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::RegistryAccount)]
+    pub(crate) struct RegistryAccount {
+        #[property(get, set, construct_only)]
+        pub(super) host: OnceCell<String>,
+        #[property(get, set, construct_only)]
+        pub(super) credentials: OnceCell<BoxedCredentials>,
+        /// `None` for accounts using an identity token
+        #[property(get = Self::username, nullable)]
+        _username: PhantomData<Option<String>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for RegistryAccount {
+        const NAME: &'static str = "RegistryAccount";
+        type Type = super::RegistryAccount;
+    }
+
+    impl ObjectImpl for RegistryAccount {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("remove-request").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+
+    impl RegistryAccount {
+        fn username(&self) -> Option<String> {
+            match &**self.obj().credentials() {
+                engine::auth::Credentials::BasicAuth { username, .. } => Some(username.to_owned()),
+                engine::auth::Credentials::IdentityToken(_) => None,
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct RegistryAccount(ObjectSubclass<imp::RegistryAccount>);
+}
+
+impl RegistryAccount {
+    pub(crate) fn new(host: &str, credentials: engine::auth::Credentials) -> Self {
+        glib::Object::builder()
+            .property("host", host)
+            .property("credentials", BoxedCredentials::from(credentials))
+            .build()
+    }
+
+    pub(crate) fn remove_request(&self) {
+        self.emit_by_name::<()>("remove-request", &[]);
+    }
+
+    pub(crate) fn connect_remove_request<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("remove-request", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::sync::OnceLock;
+
+use glib::Properties;
+use glib::prelude::*;
+use glib::subclass::Signal;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::Secret)]
+    pub(crate) struct Secret {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) secret_list: glib::WeakRef<model::SecretList>,
+
+        #[property(get, set, construct_only)]
+        pub(super) id: OnceCell<String>,
+        #[property(get, set, construct_only)]
+        pub(super) name: OnceCell<String>,
+        #[property(get, set, construct_only)]
+        pub(super) created_at: OnceCell<i64>,
+        #[property(get, set, construct_only)]
+        pub(super) updated_at: OnceCell<i64>,
+        #[property(get, set, construct_only)]
+        pub(super) driver: OnceCell<String>,
+
+        #[property(get)]
+        pub(super) to_be_deleted: Cell<bool>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Secret {
+        const NAME: &'static str = "Secret";
+        type Type = super::Secret;
+    }
+
+    impl ObjectImpl for Secret {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("deleted").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+
+    impl Secret {
+        pub(super) fn set_to_be_deleted(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.to_be_deleted() == value {
+                return;
+            }
+            self.to_be_deleted.set(value);
+            obj.notify("to-be-deleted");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct Secret(ObjectSubclass<imp::Secret>);
+}
+
+impl Secret {
+    pub(crate) fn new(secret_list: &model::SecretList, dto: engine::dto::Secret) -> Self {
+        glib::Object::builder()
+            .property("secret-list", secret_list)
+            .property("id", dto.id)
+            .property("name", dto.name)
+            .property("created-at", dto.created_at)
+            .property("updated-at", dto.updated_at)
+            .property("driver", dto.driver)
+            .build()
+    }
+
+    pub(crate) fn api(&self) -> Option<engine::api::Secret> {
+        self.secret_list()
+            .and_then(|secret_list| secret_list.api())
+            .map(|api| api.get(self.id()))
+    }
+
+    pub(crate) async fn delete(&self) -> anyhow::Result<()> {
+        let Some(api) = self.api() else { return Ok(()) };
+
+        let imp = self.imp();
+
+        imp.set_to_be_deleted(true);
+
+        rt::Promise::new(async move { api.remove().await })
+            .exec()
+            .await
+            .inspect_err(|e| {
+                imp.set_to_be_deleted(false);
+                log::error!("Error on removing secret: {}", e);
+            })
+    }
+
+    pub(super) fn emit_deleted(&self) {
+        self.emit_by_name::<()>("deleted", &[]);
+    }
+
+    pub(crate) fn connect_deleted<F: Fn(&Self) + 'static>(&self, f: F) -> glib::SignalHandlerId {
+        self.connect_local("deleted", true, move |values| {
+            f(&values[0].get::<Self>().unwrap());
+
+            None
+        })
+    }
+}
@@ -0,0 +1,285 @@
+use std::borrow::Borrow;
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use gio::prelude::*;
+use gio::subclass::prelude::*;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::Signal;
+use gtk::gio;
+use gtk::glib;
+use indexmap::IndexMap;
+
+use crate::engine;
+use crate::model;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::SecretList)]
+    pub(crate) struct SecretList {
+        pub(super) list: RefCell<IndexMap<String, model::Secret>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get)]
+        pub(super) listing: Cell<bool>,
+        #[property(get = Self::is_initialized, type = bool)]
+        pub(super) initialized: OnceCell<()>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretList {
+        const NAME: &'static str = "SecretList";
+        type Type = super::SecretList;
+        type Interfaces = (gio::ListModel,);
+    }
+
+    impl ObjectImpl for SecretList {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("secret-added")
+                        .param_types([model::Secret::static_type()])
+                        .build(),
+                    Signal::builder("secret-removed")
+                        .param_types([model::Secret::static_type()])
+                        .build(),
+                ]
+            })
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: OnceLock<Vec<glib::ParamSpec>> = OnceLock::new();
+            PROPERTIES.get_or_init(|| {
+                Self::derived_properties()
+                    .iter()
+                    .cloned()
+                    .chain(Some(
+                        glib::ParamSpecUInt::builder("len").read_only().build(),
+                    ))
+                    .collect::<Vec<_>>()
+            })
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "len" => self.obj().len().to_value(),
+                _ => self.derived_property(id, pspec),
+            }
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj()
+                .connect_items_changed(|self_, _, _, _| self_.notify("len"));
+        }
+    }
+
+    impl ListModelImpl for SecretList {
+        fn item_type(&self) -> glib::Type {
+            model::Secret::static_type()
+        }
+
+        fn n_items(&self) -> u32 {
+            self.list.borrow().len() as u32
+        }
+
+        fn item(&self, position: u32) -> Option<glib::Object> {
+            self.list
+                .borrow()
+                .get_index(position as usize)
+                .map(|(_, obj)| obj.upcast_ref())
+                .cloned()
+        }
+    }
+
+    impl SecretList {
+        pub(super) fn is_initialized(&self) -> bool {
+            self.initialized.get().is_some()
+        }
+
+        pub(super) fn set_as_initialized(&self) {
+            if self.is_initialized() {
+                return;
+            }
+            self.initialized.set(()).unwrap();
+            self.obj().notify("initialized");
+        }
+
+        pub(super) fn set_listing(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.listing() == value {
+                return;
+            }
+            self.listing.set(value);
+            obj.notify("listing");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretList(ObjectSubclass<imp::SecretList>)
+        @implements gio::ListModel;
+}
+
+impl From<&model::Client> for SecretList {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl SecretList {
+    pub(crate) fn len(&self) -> u32 {
+        self.n_items()
+    }
+
+    fn add_secret(&self, dto: engine::dto::Secret) {
+        let secret = model::Secret::new(self, dto);
+
+        let index = self.len();
+
+        self.imp()
+            .list
+            .borrow_mut()
+            .insert(secret.id(), secret.clone());
+
+        self.items_changed(index, 0, 1);
+        self.emit_by_name::<()>("secret-added", &[&secret]);
+    }
+
+    fn inspect_and_add_secret<F>(&self, id: String, err_op: F)
+    where
+        F: FnOnce(anyhow::Error) + Clone + 'static,
+    {
+        let Some(api) = self.api() else { return };
+
+        rt::Promise::new(async move { api.get(id).inspect().await }).defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |dto| match dto {
+                Ok(dto) => {
+                    if obj.get_secret(&dto.id).is_none() {
+                        obj.add_secret(dto);
+                    }
+                }
+                Err(e) => err_op(e),
+            }
+        ));
+    }
+
+    pub(crate) fn get_secret<Q: Borrow<str> + ?Sized>(&self, id: &Q) -> Option<model::Secret> {
+        self.imp().list.borrow().get(id.borrow()).cloned()
+    }
+
+    pub(crate) fn remove_secret(&self, id: &str) {
+        let mut list = self.imp().list.borrow_mut();
+        if let Some((idx, _, secret)) = list.shift_remove_full(id) {
+            drop(list);
+
+            self.items_changed(idx as u32, 1, 0);
+            self.emit_by_name::<()>("secret-removed", &[&secret]);
+            secret.emit_deleted();
+        }
+    }
+
+    pub(crate) async fn create_secret(
+        &self,
+        opts: engine::opts::SecretCreateOpts,
+    ) -> anyhow::Result<()> {
+        let Some(api) = self.api() else { return Ok(()) };
+
+        let id = rt::Promise::new(async move { api.create(opts).await })
+            .exec()
+            .await
+            .inspect_err(|e| log::error!("Error on creating secret: {}", e))?;
+
+        // The event listener may have added the secret already.
+        if self.get_secret(&id).is_none() {
+            self.inspect_and_add_secret(id, |e| log::error!("Error on inspecting secret: {}", e));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn refresh<F>(&self, err_op: F)
+    where
+        F: FnOnce(anyhow::Error) + Clone + 'static,
+    {
+        let Some(api) = self.api() else { return };
+
+        self.imp().set_listing(true);
+
+        rt::Promise::new(async move { api.list().await }).defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                match result {
+                    Ok(secrets) => {
+                        let to_remove = obj
+                            .imp()
+                            .list
+                            .borrow()
+                            .keys()
+                            .filter(|id| !secrets.iter().any(|secret| &secret.id == *id))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        to_remove.iter().for_each(|id| {
+                            obj.remove_secret(id);
+                        });
+
+                        secrets.into_iter().for_each(|secret| {
+                            if obj.get_secret(&secret.id).is_none() {
+                                obj.add_secret(secret);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log::error!("Error on retrieving secrets: {}", e);
+                        err_op(e);
+                    }
+                }
+                let imp = obj.imp();
+                imp.set_listing(false);
+                imp.set_as_initialized();
+            }
+        ));
+    }
+
+    pub(crate) fn handle_event<F>(&self, event: engine::dto::Event, err_op: F)
+    where
+        F: FnOnce(anyhow::Error) + Clone + 'static,
+    {
+        match event {
+            engine::Response::Docker(_) => {
+                log::warn!("secret events are not supported for Docker");
+            }
+            engine::Response::Podman(event) => {
+                let id = event.actor.id;
+
+                match event.action.as_str() {
+                    "remove" => self.remove_secret(&id),
+                    "create" => self.inspect_and_add_secret(id, err_op),
+                    other => log::warn!("unhandled secret action: {other}"),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn api(&self) -> Option<engine::api::Secrets> {
+        self.client()
+            .as_ref()
+            .map(model::Client::engine)
+            .as_deref()
+            .map(engine::Engine::secrets)
+    }
+}
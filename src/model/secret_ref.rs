@@ -0,0 +1,112 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use glib::Properties;
+use glib::prelude::*;
+use glib::subclass::Signal;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::SecretRef)]
+    pub(crate) struct SecretRef {
+        #[property(get, set, construct_only)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set)]
+        pub(super) secret: RefCell<String>,
+        // Whether the secret is exposed as an environment variable instead of a file
+        #[property(get, set)]
+        pub(super) env: Cell<bool>,
+        // Either the file path or the name of the environment variable
+        #[property(get, set)]
+        pub(super) target: RefCell<String>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretRef {
+        const NAME: &'static str = "SecretRef";
+        type Type = super::SecretRef;
+    }
+
+    impl ObjectImpl for SecretRef {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("remove-request").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretRef(ObjectSubclass<imp::SecretRef>);
+}
+
+impl SecretRef {
+    pub(crate) fn new(
+        client: &model::Client,
+        opts: Option<&engine::opts::ContainerCreateSecretOpts>,
+    ) -> Self {
+        let opts = opts.cloned().unwrap_or_default();
+
+        let (env, target) = match opts.target {
+            engine::opts::SecretTarget::File(path) => (false, path),
+            engine::opts::SecretTarget::Env(var) => (true, var),
+        };
+
+        glib::Object::builder()
+            .property("client", client)
+            .property("secret", opts.secret)
+            .property("env", env)
+            .property("target", target)
+            .build()
+    }
+
+    pub(crate) fn remove_request(&self) {
+        self.emit_by_name::<()>("remove-request", &[]);
+    }
+
+    pub(crate) fn connect_remove_request<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("remove-request", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
+
+impl From<&SecretRef> for engine::opts::ContainerCreateSecretOpts {
+    fn from(value: &SecretRef) -> Self {
+        let target = value.target().trim().to_owned();
+
+        Self {
+            secret: value.secret(),
+            target: if value.env() {
+                engine::opts::SecretTarget::Env(target)
+            } else {
+                engine::opts::SecretTarget::File(target)
+            },
+        }
+    }
+}
@@ -128,6 +128,10 @@ impl SimpleContainerList {
             .and_then(glib::WeakRef::upgrade)
     }
 
+    pub(crate) fn contains<Q: Borrow<str> + ?Sized>(&self, id: &Q) -> bool {
+        self.imp().0.borrow().contains_key(id.borrow())
+    }
+
     pub(crate) fn add_container(&self, container: &model::Container) {
         let (index, _) = self.imp().0.borrow_mut().insert_full(container.id(), {
             let weak_ref = glib::WeakRef::new();
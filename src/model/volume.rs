@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
+use std::collections::HashMap;
 use std::sync::OnceLock;
 
 use gio::prelude::*;
@@ -12,8 +13,11 @@ use gtk::glib;
 
 use crate::engine;
 use crate::model;
+use crate::monad_boxed_type;
 use crate::rt;
 
+monad_boxed_type!(pub(crate) BoxedStringMap(HashMap<String, String>) impls Debug, Default);
+
 mod imp {
     use super::*;
 
@@ -31,6 +35,10 @@ mod imp {
         pub(super) driver: OnceCell<String>,
         #[property(get, set, construct_only)]
         pub(super) mountpoint: OnceCell<String>,
+        #[property(get, set, construct_only)]
+        pub(super) options: OnceCell<BoxedStringMap>,
+        #[property(get, set, construct_only)]
+        pub(super) labels: OnceCell<BoxedStringMap>,
 
         #[property(get, set)]
         pub(super) searching_containers: Cell<bool>,
@@ -110,6 +118,8 @@ impl Volume {
             .property("created-at", dto.created_at)
             .property("driver", dto.driver)
             .property("mountpoint", dto.mountpoint)
+            .property("options", BoxedStringMap::from(dto.options))
+            .property("labels", BoxedStringMap::from(dto.labels))
             .build()
     }
 
@@ -0,0 +1,141 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use futures::StreamExt;
+use futures::stream;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::prelude::*;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties)]
+    #[properties(wrapper_type = super::VolumeImportAction)]
+    pub(crate) struct VolumeImportAction {
+        #[property(get, set, construct_only)]
+        pub(super) volume: glib::WeakRef<model::Volume>,
+
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+
+        #[property(get, set)]
+        pub(super) archive_size: Cell<u64>,
+        #[property(get, set)]
+        pub(super) sent_bytes: Cell<u64>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for VolumeImportAction {
+        const NAME: &'static str = "VolumeImportAction";
+        type Type = super::VolumeImportAction;
+        type ParentType = model::Action;
+    }
+
+    impl ObjectImpl for VolumeImportAction {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct VolumeImportAction(ObjectSubclass<imp::VolumeImportAction>)
+        @extends model::Action;
+}
+
+impl VolumeImportAction {
+    pub(crate) fn new(
+        action_list: &model::ActionList,
+        volume: &model::Volume,
+        host_path: &str,
+    ) -> Self {
+        model::Action::builder::<Self>(action_list)
+            .property("volume", volume)
+            .property("host-path", host_path)
+            .build()
+            .exec()
+    }
+
+    fn exec(self) -> Self {
+        let Some(api) = self.volume().and_then(|volume| volume.api()) else {
+            self.set_failed(&gettext("Volume has been removed"));
+            return self;
+        };
+        let Some(engine) = self
+            .action_list()
+            .and_then(|action_list| action_list.client())
+            .map(|client| engine::Engine::clone(&client.engine()))
+        else {
+            return self;
+        };
+
+        rt::Promise::new({
+            let host_path = self.host_path();
+            async move { tokio::fs::metadata(host_path).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok(metadata) => {
+                    obj.set_archive_size(metadata.len());
+
+                    let abort_registration = obj.setup_abort_handle();
+
+                    rt::Pipe::new(api, {
+                        let host_path = PathBuf::from(obj.host_path());
+                        move |volume| {
+                            stream::Abortable::new(
+                                volume.import(engine, host_path),
+                                abort_registration,
+                            )
+                            .boxed()
+                        }
+                    })
+                    .on_next(clone!(
+                        #[weak]
+                        obj,
+                        #[upgrade_or]
+                        glib::ControlFlow::Break,
+                        move |report| match report {
+                            Ok(engine::dto::UploadReport::Sending { sent }) => {
+                                obj.set_sent_bytes(sent);
+                                glib::ControlFlow::Continue
+                            }
+                            Ok(engine::dto::UploadReport::Finished(())) => {
+                                obj.set_state(model::ActionState::Finished);
+                                glib::ControlFlow::Break
+                            }
+                            Err(e) => {
+                                log::warn!("error importing volume: {e}");
+                                obj.set_failed(&e.to_string());
+                                glib::ControlFlow::Break
+                            }
+                        }
+                    ));
+                }
+                Err(e) => obj.set_failed(&e.to_string()),
+            }
+        ));
+
+        self
+    }
+}
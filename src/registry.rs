@@ -0,0 +1,380 @@
+//! Access to container registries that doesn't go through the engine: importing the credentials
+//! of `docker login` and `podman login` and validating credentials against the registry API.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use gtk::glib;
+use http_body_util::BodyExt;
+use http_body_util::Full;
+use hyper::header;
+use serde::Deserialize;
+
+use crate::engine;
+use crate::rt;
+
+/// Docker Hub serves its registry API from a different host than its image references suggest.
+const DOCKER_HUB_API_HOST: &str = "registry-1.docker.io";
+/// Token services are often served through CDNs that redirect a few times.
+const MAX_REDIRECTS: usize = 10;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum AuthFile {
+    /// `~/.docker/config.json`
+    Docker,
+    /// `${XDG_RUNTIME_DIR}/containers/auth.json`
+    Podman,
+}
+
+impl AuthFile {
+    pub(crate) fn path(self) -> Option<PathBuf> {
+        match self {
+            Self::Docker => Some(glib::home_dir().join(".docker").join("config.json")),
+            Self::Podman => std::env::var_os("XDG_RUNTIME_DIR")
+                .map(PathBuf::from)
+                .map(|dir| dir.join("containers").join("auth.json")),
+        }
+    }
+
+    /// Reads the credentials stored in the file. Credentials kept by credential helpers aren't
+    /// contained in the file and are therefore skipped.
+    pub(crate) async fn read(self) -> anyhow::Result<Vec<(String, engine::auth::Credentials)>> {
+        #[derive(Deserialize)]
+        struct Content {
+            #[serde(default)]
+            auths: HashMap<String, Entry>,
+        }
+
+        #[derive(Deserialize)]
+        struct Entry {
+            auth: Option<String>,
+            identitytoken: Option<String>,
+        }
+
+        let path = self
+            .path()
+            .ok_or_else(|| anyhow::anyhow!("XDG_RUNTIME_DIR is not set"))?;
+        let content = serde_json::from_slice::<Content>(&tokio::fs::read(&path).await?)?;
+
+        let mut accounts = content
+            .auths
+            .into_iter()
+            .filter_map(|(key, entry)| {
+                let credentials = match entry.identitytoken.filter(|token| !token.is_empty()) {
+                    Some(token) => engine::auth::Credentials::IdentityToken(token),
+                    None => {
+                        let auth = glib::base64_decode(&entry.auth?);
+                        let auth = String::from_utf8(auth).ok()?;
+                        let (username, password) = auth.split_once(':')?;
+
+                        engine::auth::Credentials::BasicAuth {
+                            username: username.to_owned(),
+                            password: password.to_owned(),
+                        }
+                    }
+                };
+
+                Some((normalize_host(&key), credentials))
+            })
+            .collect::<Vec<_>>();
+        accounts.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok(accounts)
+    }
+}
+
+/// Turns the keys of auth files like `https://index.docker.io/v1/` or `quay.io/namespace` into
+/// registry hosts.
+fn normalize_host(key: &str) -> String {
+    let key = key
+        .strip_prefix("https://")
+        .or_else(|| key.strip_prefix("http://"))
+        .unwrap_or(key);
+    let host = key.split('/').next().unwrap_or(key);
+
+    match host {
+        "index.docker.io" | DOCKER_HUB_API_HOST => engine::auth::DEFAULT_REGISTRY.to_owned(),
+        _ => host.to_owned(),
+    }
+}
+
+/// Checks the credentials against the `/v2/` endpoint of the registry. Registries delegating the
+/// authentication to a token service are asked for a token instead.
+pub(crate) async fn validate_credentials(
+    host: &str,
+    credentials: &engine::auth::Credentials,
+) -> anyhow::Result<()> {
+    let host = host.to_owned();
+    let credentials = credentials.to_owned();
+
+    rt::Promise::new(async move { check_credentials(&host, &credentials).await })
+        .exec()
+        .await
+}
+
+async fn check_credentials(
+    host: &str,
+    credentials: &engine::auth::Credentials,
+) -> anyhow::Result<()> {
+    let api_host = if host == engine::auth::DEFAULT_REGISTRY {
+        DOCKER_HUB_API_HOST
+    } else {
+        host
+    };
+
+    let response = Request::get(&format!("https://{api_host}/v2/"))
+        .authorization(credentials)
+        .send()
+        .await?;
+
+    match response.status.as_u16() {
+        200..=299 => Ok(()),
+        401 => {
+            let challenge = response
+                .header(header::WWW_AUTHENTICATE)
+                .and_then(|challenge| challenge.strip_prefix("Bearer "))
+                .ok_or_else(|| anyhow::anyhow!("invalid credentials"))?;
+            let params = challenge_params(challenge);
+
+            let realm = params
+                .get("realm")
+                .ok_or_else(|| anyhow::anyhow!("token service is missing in challenge"))?;
+            let service = params
+                .get("service")
+                .map(String::as_str)
+                .unwrap_or(api_host);
+
+            let response = match credentials {
+                engine::auth::Credentials::BasicAuth { .. } => {
+                    Request::get(&with_query(realm, &format!("service={}", escape(service))))
+                        .authorization(credentials)
+                }
+                engine::auth::Credentials::IdentityToken(token) => Request::post(
+                    realm,
+                    &format!(
+                        "grant_type=refresh_token&client_id=pods&service={}&refresh_token={}",
+                        escape(service),
+                        escape(token),
+                    ),
+                ),
+            }
+            .send()
+            .await?;
+
+            match response.status.as_u16() {
+                200..=299 => Ok(()),
+                401 | 403 => anyhow::bail!("invalid credentials"),
+                status => anyhow::bail!("token service responded with status {status}"),
+            }
+        }
+        status => anyhow::bail!("registry responded with status {status}"),
+    }
+}
+
+/// Splits the parameters of a challenge like `realm="…",service="…"`.
+fn challenge_params(challenge: &str) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    let mut rest = challenge.trim();
+
+    while let Some((key, value)) = rest.split_once('=') {
+        let key = key.trim().trim_start_matches(',').trim().to_lowercase();
+
+        let (value, remainder) = match value.strip_prefix('"') {
+            Some(value) => value.split_once('"').unwrap_or((value, "")),
+            None => value.split_once(',').unwrap_or((value, "")),
+        };
+
+        params.insert(key, value.to_owned());
+        rest = remainder.trim_start_matches(',');
+    }
+
+    params
+}
+
+/// Appends the query to a URI that may already have one, like the realms of some token services.
+fn with_query(uri: &str, query: &str) -> String {
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    format!("{uri}{separator}{query}")
+}
+
+/// Resolves the `Location` of a redirect, which may be relative to the requested URI.
+fn redirect_target(uri: &hyper::Uri, location: &str) -> anyhow::Result<hyper::Uri> {
+    let location = location.parse::<hyper::Uri>()?;
+    if location.scheme().is_some() {
+        return Ok(location);
+    }
+
+    let mut parts = location.into_parts();
+    parts.scheme = uri.scheme().cloned();
+    parts.authority = uri.authority().cloned();
+
+    Ok(hyper::Uri::from_parts(parts)?)
+}
+
+fn escape(value: &str) -> glib::GString {
+    glib::Uri::escape_string(value, None, false)
+}
+
+struct Request {
+    method: hyper::Method,
+    uri: String,
+    authorization: Option<String>,
+    body: Option<String>,
+}
+
+struct Response {
+    status: hyper::StatusCode,
+    headers: hyper::HeaderMap,
+}
+
+impl Response {
+    fn header(&self, name: header::HeaderName) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+impl Request {
+    fn get(uri: &str) -> Self {
+        Self {
+            method: hyper::Method::GET,
+            uri: uri.to_owned(),
+            authorization: None,
+            body: None,
+        }
+    }
+
+    fn post(uri: &str, form: &str) -> Self {
+        Self {
+            method: hyper::Method::POST,
+            uri: uri.to_owned(),
+            authorization: None,
+            body: Some(form.to_owned()),
+        }
+    }
+
+    fn authorization(mut self, credentials: &engine::auth::Credentials) -> Self {
+        self.authorization = Some(match credentials {
+            engine::auth::Credentials::BasicAuth { username, password } => format!(
+                "Basic {}",
+                glib::base64_encode(format!("{username}:{password}").as_bytes())
+            ),
+            engine::auth::Credentials::IdentityToken(token) => format!("Bearer {token}"),
+        });
+        self
+    }
+
+    /// Sends the request and follows redirects. The body of the response is read completely but
+    /// dropped, as the status and the headers are all that is needed to check credentials.
+    async fn send(self) -> anyhow::Result<Response> {
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_native_roots()?
+            .https_or_http()
+            .enable_http1()
+            .build();
+        let client =
+            hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+                .build::<_, Full<Bytes>>(connector);
+
+        let origin = self.uri.parse::<hyper::Uri>()?;
+        let mut uri = origin.clone();
+        let mut method = self.method;
+        let mut body = self.body;
+
+        for _ in 0..=MAX_REDIRECTS {
+            let mut request = hyper::Request::builder()
+                .method(method.clone())
+                .uri(uri.clone())
+                .header(header::USER_AGENT, "pods");
+            // Credentials are only meant for the host they were given to and must not be sent in
+            // plain text after a redirect from HTTPS to HTTP.
+            if let Some(authorization) = &self.authorization
+                && uri.scheme() == origin.scheme()
+                && uri.authority() == origin.authority()
+            {
+                request = request.header(header::AUTHORIZATION, authorization);
+            }
+            if body.is_some() {
+                request = request.header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+            }
+
+            let response = client
+                .request(request.body(Full::new(Bytes::from(body.clone().unwrap_or_default())))?)
+                .await?;
+            let status = response.status();
+            let headers = response.headers().to_owned();
+            response.into_body().collect().await?;
+
+            let location = headers
+                .get(header::LOCATION)
+                .filter(|_| status.is_redirection())
+                .map(|location| location.to_str())
+                .transpose()?;
+            let Some(location) = location else {
+                return Ok(Response { status, headers });
+            };
+
+            uri = redirect_target(&uri, location)?;
+            if status == hyper::StatusCode::SEE_OTHER {
+                method = hyper::Method::GET;
+                body = None;
+            }
+        }
+
+        anyhow::bail!("too many redirects for '{}'", self.uri)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(normalize_host("https://index.docker.io/v1/"), "docker.io");
+        assert_eq!(normalize_host("registry-1.docker.io"), "docker.io");
+        assert_eq!(normalize_host("quay.io/namespace"), "quay.io");
+        assert_eq!(normalize_host("http://localhost:5000"), "localhost:5000");
+        assert_eq!(normalize_host("ghcr.io"), "ghcr.io");
+    }
+
+    #[test]
+    fn test_challenge_params() {
+        let params = challenge_params(
+            r#"realm="https://auth.docker.io/token",service="registry.docker.io",scope="a,b""#,
+        );
+        assert_eq!(params["realm"], "https://auth.docker.io/token");
+        assert_eq!(params["service"], "registry.docker.io");
+        assert_eq!(params["scope"], "a,b");
+
+        let params = challenge_params("Realm=https://example.com/token, service=example.com");
+        assert_eq!(params["realm"], "https://example.com/token");
+        assert_eq!(params["service"], "example.com");
+    }
+
+    #[test]
+    fn test_with_query() {
+        assert_eq!(
+            with_query("https://example.com/token", "service=a"),
+            "https://example.com/token?service=a"
+        );
+        assert_eq!(
+            with_query("https://example.com/token?tenant=b", "service=a"),
+            "https://example.com/token?tenant=b&service=a"
+        );
+    }
+
+    #[test]
+    fn test_redirect_target() {
+        let uri = "https://example.com/v2/".parse::<hyper::Uri>().unwrap();
+
+        assert_eq!(
+            redirect_target(&uri, "/token?service=a").unwrap(),
+            "https://example.com/token?service=a"
+        );
+        assert_eq!(
+            redirect_target(&uri, "https://cdn.example.com/token").unwrap(),
+            "https://cdn.example.com/token"
+        );
+    }
+}
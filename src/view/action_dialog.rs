@@ -10,6 +10,7 @@ use glib::closure;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
+use crate::engine;
 use crate::model;
 use crate::model::prelude::*;
 use crate::utils;
@@ -189,7 +190,11 @@ glib::wrapper! {
 
 impl From<model::Action> for ActionDialog {
     fn from(value: model::Action) -> Self {
-        if let Some(action) = value.downcast_ref::<model::ContainerCommitAction>() {
+        if let Some(action) = value.downcast_ref::<model::ComposeUpAction>() {
+            Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::ContainerCheckpointAction>() {
+            Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::ContainerCommitAction>() {
             Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::ContainerCopyFromAction>() {
             Self::from(action)
@@ -197,22 +202,42 @@ impl From<model::Action> for ActionDialog {
             Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::ContainerCreateAction>() {
             Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::ContainerExportAction>() {
+            Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::ContainerRestoreAction>() {
+            Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::ContainersPruneAction>() {
             Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::ImageBuildAction>() {
             Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::ImageImportAction>() {
+            Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::ImagePullAction>() {
             Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::ImagePushAction>() {
             Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::ImagesLoadAction>() {
+            Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::ImagesPruneAction>() {
             Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::ImagesSaveAction>() {
+            Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::NetworkCreateAction>() {
+            Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::NetworksPruneAction>() {
+            Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::PodCreateAction>() {
             Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::PodsPlayKubeAction>() {
+            Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::PodsPruneAction>() {
             Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::VolumeCreateAction>() {
             Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::VolumeExportAction>() {
+            Self::from(action)
+        } else if let Some(action) = value.downcast_ref::<model::VolumeImportAction>() {
+            Self::from(action)
         } else if let Some(action) = value.downcast_ref::<model::VolumesPruneAction>() {
             Self::from(action)
         } else {
@@ -333,6 +358,8 @@ impl ActionDialog {
             view::ImageDetailsPage::from(image).upcast()
         } else if let Some(volume) = artifact.downcast_ref::<model::Volume>() {
             view::VolumeDetailsPage::from(volume).upcast()
+        } else if let Some(network) = artifact.downcast_ref::<model::Network>() {
+            view::NetworkDetailsPage::from(network).upcast()
         } else {
             return;
         };
@@ -362,8 +389,23 @@ impl ActionDialog {
         self.close();
 
         let dialog: adw::Dialog = if let Some(action) =
-            action.downcast_ref::<model::ContainerCommitAction>()
+            action.downcast_ref::<model::ContainerCheckpointAction>()
         {
+            match action.container() {
+                Some(container) => view::ContainerCheckpointOptsDialog::new(
+                    &container,
+                    Some(action.opts()),
+                    &action.host_path(),
+                )
+                .upcast(),
+                None => alert_dialog(
+                    &gettext("Container Removed"),
+                    &gettext("Container cannot be checkpointed because it has been removed."),
+                ),
+            }
+        } else if let Some(action) = action.downcast_ref::<model::ComposeUpAction>() {
+            view::ComposeUpOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::ContainerCommitAction>() {
             match action.container() {
                 Some(container) => {
                     view::ContainerCommitOptsDialog::new(&container, Some(action.opts())).upcast()
@@ -407,14 +449,38 @@ impl ActionDialog {
             }
         } else if let Some(action) = action.downcast_ref::<model::ContainerCreateAction>() {
             view::ContainerCreateOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::ContainerExportAction>() {
+            match action.container() {
+                Some(container) => {
+                    view::ContainerExportOptsDialog::new(&container, &action.host_path()).upcast()
+                }
+                None => alert_dialog(
+                    &gettext("Container Removed"),
+                    &gettext("Container cannot be exported because it has been removed."),
+                ),
+            }
+        } else if let Some(action) = action.downcast_ref::<model::ContainerRestoreAction>() {
+            let opts = action.opts();
+            let container = match opts.source {
+                engine::opts::ContainerRestoreSource::Container(ref id) => {
+                    client.container_list().get_container(id)
+                }
+                engine::opts::ContainerRestoreSource::Archive(_) => None,
+            };
+            view::ContainerRestoreOptsDialog::new(&client, container.as_ref(), Some(opts)).upcast()
         } else if let Some(action) = action.downcast_ref::<model::ContainersPruneAction>() {
             view::ContainersPruneOptsDialog::new(&client, Some(action.opts())).upcast()
         } else if let Some(action) = action.downcast_ref::<model::PodCreateAction>() {
             view::PodCreateOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::PodsPlayKubeAction>() {
+            view::PodsPlayKubeOptsDialog::new(&client, Some(action.opts())).upcast()
         } else if action.is::<model::PodsPruneAction>() {
             view::PodsPruneOptsDialog::from(&client).upcast()
         } else if let Some(action) = action.downcast_ref::<model::ImageBuildAction>() {
             view::ImageBuildOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::ImageImportAction>() {
+            view::ImageImportOptsDialog::new(&client, Some(action.opts()), &action.host_path())
+                .upcast()
         } else if let Some(action) = action.downcast_ref::<model::ImagePullAction>() {
             view::ImagePullOptsDialog::new(&client, Some(action.opts())).upcast()
         } else if let Some(action) = action.downcast_ref::<model::ImagePushAction>() {
@@ -427,12 +493,40 @@ impl ActionDialog {
                     &gettext("Cannot push removed tag."),
                 ),
             }
+        } else if let Some(action) = action.downcast_ref::<model::ImagesLoadAction>() {
+            view::ImagesLoadOptsDialog::new(&client, &action.host_path()).upcast()
         } else if let Some(action) = action.downcast_ref::<model::ImagesPruneAction>() {
             view::ImagesPruneOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::ImagesSaveAction>() {
+            view::ImagesSaveOptsDialog::new(&client, action.opts(), &action.host_path()).upcast()
         } else if let Some(action) = action.downcast_ref::<model::VolumeCreateAction>() {
             view::VolumeCreateOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::VolumeExportAction>() {
+            match action.volume() {
+                Some(volume) => {
+                    view::VolumeExportOptsDialog::new(&volume, &action.host_path()).upcast()
+                }
+                None => alert_dialog(
+                    &gettext("Volume Removed"),
+                    &gettext("Volume cannot be exported because it has been removed."),
+                ),
+            }
+        } else if let Some(action) = action.downcast_ref::<model::VolumeImportAction>() {
+            match action.volume() {
+                Some(volume) => {
+                    view::VolumeImportOptsDialog::new(&volume, &action.host_path()).upcast()
+                }
+                None => alert_dialog(
+                    &gettext("Volume Removed"),
+                    &gettext("Archive cannot be imported because the volume has been removed."),
+                ),
+            }
         } else if let Some(action) = action.downcast_ref::<model::VolumesPruneAction>() {
             view::VolumesPruneOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::NetworkCreateAction>() {
+            view::NetworkCreateOptsDialog::new(&client, Some(action.opts())).upcast()
+        } else if let Some(action) = action.downcast_ref::<model::NetworksPruneAction>() {
+            view::NetworksPruneOptsDialog::new(&client, Some(action.opts())).upcast()
         } else {
             unreachable!()
         };
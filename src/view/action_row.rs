@@ -320,7 +320,14 @@ impl ActionRow {
 }
 
 fn action_image(action: &model::Action) -> &str {
-    if action
+    if action.downcast_ref::<model::ComposeUpAction>().is_some() {
+        "stacked-plates-symbolic"
+    } else if action
+        .downcast_ref::<model::ContainerCheckpointAction>()
+        .is_some()
+    {
+        "document-save-symbolic"
+    } else if action
         .downcast_ref::<model::ContainerCommitAction>()
         .is_some()
     {
@@ -338,23 +345,50 @@ fn action_image(action: &model::Action) -> &str {
     } else if action
         .downcast_ref::<model::ContainerCreateAction>()
         .is_some()
+        || action
+            .downcast_ref::<model::ContainerRestoreAction>()
+            .is_some()
     {
         "package-x-generic-symbolic"
+    } else if action
+        .downcast_ref::<model::ContainerExportAction>()
+        .is_some()
+    {
+        "document-save-symbolic"
     } else if action.downcast_ref::<model::ImageBuildAction>().is_some()
         || action.downcast_ref::<model::ImagePullAction>().is_some()
+        || action.downcast_ref::<model::ImageImportAction>().is_some()
         || action.downcast_ref::<model::ImagePushAction>().is_some()
     {
         "image-x-generic-symbolic"
-    } else if action.downcast_ref::<model::PodCreateAction>().is_some() {
+    } else if action.downcast_ref::<model::ImagesLoadAction>().is_some() {
+        "document-open-symbolic"
+    } else if action.downcast_ref::<model::ImagesSaveAction>().is_some() {
+        "document-save-symbolic"
+    } else if action.downcast_ref::<model::PodCreateAction>().is_some()
+        || action.downcast_ref::<model::PodsPlayKubeAction>().is_some()
+    {
         "pods-symbolic"
     } else if action.downcast_ref::<model::VolumeCreateAction>().is_some() {
         "drive-harddisk-symbolic"
+    } else if action.downcast_ref::<model::VolumeExportAction>().is_some() {
+        "document-save-symbolic"
+    } else if action.downcast_ref::<model::VolumeImportAction>().is_some() {
+        "document-open-symbolic"
+    } else if action
+        .downcast_ref::<model::NetworkCreateAction>()
+        .is_some()
+    {
+        "network-wired-symbolic"
     } else if action
         .downcast_ref::<model::ContainersPruneAction>()
         .is_some()
         || action.downcast_ref::<model::PodsPruneAction>().is_some()
         || action.downcast_ref::<model::ImagesPruneAction>().is_some()
         || action.downcast_ref::<model::VolumesPruneAction>().is_some()
+        || action
+            .downcast_ref::<model::NetworksPruneAction>()
+            .is_some()
     {
         "eraser5-symbolic"
     } else {
@@ -363,7 +397,24 @@ fn action_image(action: &model::Action) -> &str {
 }
 
 fn action_description(action: &model::Action) -> String {
-    if let Some(action) = action.downcast_ref::<model::ContainerCommitAction>() {
+    if let Some(action) = action.downcast_ref::<model::ComposeUpAction>() {
+        let opts = action.opts();
+        match opts.project_name.as_deref() {
+            Some(project) => gettext!("Create compose project <b>{}</b>", project),
+            None => gettext!(
+                "Import <b>{}</b>",
+                std::path::Path::new(&opts.file)
+                    .file_name()
+                    .map(|file_name| file_name.to_string_lossy())
+                    .unwrap_or_default()
+            ),
+        }
+    } else if let Some(action) = action.downcast_ref::<model::ContainerCheckpointAction>() {
+        action
+            .container()
+            .map(|container| gettext!("Checkpoint <b>{}</b>", container.name()))
+            .unwrap_or_else(|| gettext("Checkpoint container"))
+    } else if let Some(action) = action.downcast_ref::<model::ContainerCommitAction>() {
         action
             .container()
             .map(|container| gettext!("Commit <b>{}</b>", container.name()))
@@ -380,6 +431,16 @@ fn action_description(action: &model::Action) -> String {
             .unwrap_or_else(|| gettext("Copy to container"))
     } else if let Some(action) = action.downcast_ref::<model::ContainerCreateAction>() {
         gettext!("Create <b>{}</b>", action.opts().name)
+    } else if let Some(action) = action.downcast_ref::<model::ContainerExportAction>() {
+        action
+            .container()
+            .map(|container| gettext!("Export <b>{}</b>", container.name()))
+            .unwrap_or_else(|| gettext("Export container"))
+    } else if let Some(action) = action.downcast_ref::<model::ContainerRestoreAction>() {
+        match action.opts().name.as_deref() {
+            Some(name) => gettext!("Restore <b>{}</b>", name),
+            None => gettext("Restore container"),
+        }
     } else if action
         .downcast_ref::<model::ContainersPruneAction>()
         .is_some()
@@ -387,10 +448,27 @@ fn action_description(action: &model::Action) -> String {
         gettext("Prune Containers")
     } else if let Some(action) = action.downcast_ref::<model::PodCreateAction>() {
         gettext!("Create <b>{}</b>", action.opts().name)
+    } else if let Some(action) = action.downcast_ref::<model::PodsPlayKubeAction>() {
+        let opts = action.opts();
+        let file_name = std::path::Path::new(&opts.file)
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        if opts.down {
+            gettext!("Tear down <b>{}</b>", file_name)
+        } else {
+            gettext!("Play <b>{}</b>", file_name)
+        }
     } else if action.downcast_ref::<model::PodsPruneAction>().is_some() {
         gettext("Prune Pods")
     } else if let Some(action) = action.downcast_ref::<model::ImageBuildAction>() {
         gettext!("Build <b>{}</b>", action.opts().tag)
+    } else if let Some(action) = action.downcast_ref::<model::ImageImportAction>() {
+        match action.opts().repo.as_deref() {
+            Some(repo) => gettext!("Import <b>{}</b>", repo),
+            None => gettext("Import image"),
+        }
     } else if let Some(action) = action.downcast_ref::<model::ImagePullAction>() {
         gettext!("Pull <b>{}</b>", action.opts().reference)
     } else if let Some(action) = action.downcast_ref::<model::ImagePushAction>() {
@@ -398,13 +476,47 @@ fn action_description(action: &model::Action) -> String {
         gettext!("Push <b>{}</b>", format!("{}:{}", opts.repo, opts.tag))
     } else if action.downcast_ref::<model::ImagesPruneAction>().is_some() {
         gettext("Prune Images")
+    } else if action.downcast_ref::<model::ImagesLoadAction>().is_some() {
+        gettext("Load Images")
+    } else if let Some(action) = action.downcast_ref::<model::ImagesSaveAction>() {
+        match action.opts().references.as_slice() {
+            [reference] => gettext!("Save <b>{}</b>", reference),
+            references => gettext!("Save <b>{}</b> images", references.len()),
+        }
     } else if let Some(action) = action.downcast_ref::<model::VolumeCreateAction>() {
         match action.opts().name.as_deref() {
             Some(name) => gettext!("Create <b>{}</b>", name),
             None => gettext("Create anonymous"),
         }
+    } else if let Some(action) = action.downcast_ref::<model::VolumeExportAction>() {
+        action
+            .volume()
+            .map(|volume| {
+                gettext!(
+                    "Export <b>{}</b>",
+                    utils::format_volume_name(&volume.name())
+                )
+            })
+            .unwrap_or_else(|| gettext("Export volume"))
+    } else if let Some(action) = action.downcast_ref::<model::VolumeImportAction>() {
+        action
+            .volume()
+            .map(|volume| {
+                gettext!(
+                    "Import into <b>{}</b>",
+                    utils::format_volume_name(&volume.name())
+                )
+            })
+            .unwrap_or_else(|| gettext("Import into volume"))
     } else if action.downcast_ref::<model::VolumesPruneAction>().is_some() {
         gettext("Prune Volumes")
+    } else if let Some(action) = action.downcast_ref::<model::NetworkCreateAction>() {
+        gettext!("Create <b>{}</b>", action.opts().name)
+    } else if action
+        .downcast_ref::<model::NetworksPruneAction>()
+        .is_some()
+    {
+        gettext("Prune Networks")
     } else {
         gettext("Unknown Action")
     }
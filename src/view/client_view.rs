@@ -55,6 +55,10 @@ mod imp {
         #[template_child]
         pub(super) volumes_panel: TemplateChild<view::VolumesPanel>,
         #[template_child]
+        pub(super) networks_panel: TemplateChild<view::NetworksPanel>,
+        #[template_child]
+        pub(super) secrets_panel: TemplateChild<view::SecretsPanel>,
+        #[template_child]
         pub(super) search_navigation_view: TemplateChild<adw::NavigationView>,
         #[template_child]
         pub(super) color_bin: TemplateChild<adw::Bin>,
@@ -217,7 +221,9 @@ mod imp {
                 1 => "pods",
                 2 => "images",
                 3 => "volumes",
-                4 => "info",
+                4 => "networks",
+                5 => "secrets",
+                6 => "info",
                 _ => unreachable!(),
             });
 
@@ -246,6 +252,8 @@ mod imp {
                 "pods" => gettext("Pods"),
                 "images" => gettext("Images"),
                 "volumes" => gettext("Volumes"),
+                "networks" => gettext("Networks"),
+                "secrets" => gettext("Secrets"),
                 "info" => gettext("Info"),
                 _ => unreachable!(),
             });
@@ -262,7 +270,9 @@ mod imp {
                         "pods" => 1,
                         "images" => 2,
                         "volumes" => 3,
-                        "info" => 4,
+                        "networks" => 4,
+                        "secrets" => 5,
+                        "info" => 6,
                         _ => unreachable!(),
                     },
                 ),
@@ -275,6 +285,8 @@ mod imp {
             self.pods_panel.set_search_mode(false);
             self.images_panel.set_search_mode(false);
             self.volumes_panel.set_search_mode(false);
+            self.networks_panel.set_search_mode(false);
+            self.secrets_panel.set_search_mode(false);
         }
 
         fn exit_selection_mode(&self) {
@@ -282,6 +294,7 @@ mod imp {
             self.pods_panel.exit_selection_mode();
             self.images_panel.exit_selection_mode();
             self.volumes_panel.exit_selection_mode();
+            self.networks_panel.exit_selection_mode();
         }
 
         fn set_background(&self, bg_color: Option<gdk::RGBA>) {
@@ -327,6 +340,8 @@ impl ClientView {
                 "pods" => imp.pods_panel.toggle_search_mode(),
                 "images" => imp.images_panel.toggle_search_mode(),
                 "volumes" => imp.volumes_panel.toggle_search_mode(),
+                "networks" => imp.networks_panel.toggle_search_mode(),
+                "secrets" => imp.secrets_panel.toggle_search_mode(),
                 _ => {}
             }
         }
@@ -354,6 +369,10 @@ impl ClientView {
                 imp.images_panel.show_pull_dialog();
             } else if imp.volumes_panel.is_mapped() {
                 imp.volumes_panel.show_create_dialog();
+            } else if imp.networks_panel.is_mapped() {
+                imp.networks_panel.show_create_dialog();
+            } else if imp.secrets_panel.is_mapped() {
+                imp.secrets_panel.show_create_dialog();
             }
         }
     }
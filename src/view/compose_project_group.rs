@@ -0,0 +1,310 @@
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::PreferencesGroupImpl;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::Properties;
+use glib::clone;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::model::AbstractContainerListExt;
+use crate::utils;
+use crate::view;
+
+const ACTION_START: &str = "compose-project-group.start";
+const ACTION_STOP: &str = "compose-project-group.stop";
+const ACTION_RESTART: &str = "compose-project-group.restart";
+const ACTION_REMOVE: &str = "compose-project-group.remove";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ComposeProjectGroup)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/compose_project_group.ui")]
+    pub(crate) struct ComposeProjectGroup {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) project: RefCell<Option<String>>,
+        #[property(get = Self::container_list)]
+        pub(super) container_list: OnceCell<model::SimpleContainerList>,
+        #[template_child]
+        pub(super) actions_box: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ComposeProjectGroup {
+        const NAME: &'static str = "PdsComposeProjectGroup";
+        type Type = super::ComposeProjectGroup;
+        type ParentType = adw::PreferencesGroup;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_START, None, |widget, _, _| {
+                widget.start();
+            });
+            klass.install_action(ACTION_STOP, None, |widget, _, _| {
+                widget.stop();
+            });
+            klass.install_action(ACTION_RESTART, None, |widget, _, _| {
+                widget.restart();
+            });
+            klass.install_action(ACTION_REMOVE, None, |widget, _, _| {
+                widget.remove();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ComposeProjectGroup {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            let container_list = obj.container_list();
+
+            match obj.project() {
+                Some(project) => {
+                    obj.set_title(&project);
+
+                    let container_list_expr = Self::Type::this_expression("container-list");
+                    gtk::ClosureExpression::new::<String>(
+                        [
+                            &container_list_expr
+                                .chain_property::<model::SimpleContainerList>("containers"),
+                            &container_list_expr
+                                .chain_property::<model::SimpleContainerList>("running"),
+                        ],
+                        closure!(|_: Self::Type, containers: u32, running: u32| {
+                            ngettext!(
+                                "{} of {} container running",
+                                "{} of {} containers running",
+                                containers,
+                                running,
+                                containers,
+                            )
+                        }),
+                    )
+                    .bind(obj, "description", Some(obj));
+
+                    container_list.connect_notify_local(
+                        Some("running"),
+                        clone!(
+                            #[weak]
+                            obj,
+                            move |_, _| obj.update_actions()
+                        ),
+                    );
+                    obj.update_actions();
+                }
+                None => {
+                    obj.set_title(&gettext("Other Containers"));
+                    self.actions_box.set_visible(false);
+                }
+            }
+
+            self.list_box.bind_model(Some(&container_list), |item| {
+                view::ContainerRow::from(item.downcast_ref().unwrap()).upcast()
+            });
+        }
+    }
+
+    impl WidgetImpl for ComposeProjectGroup {}
+    impl PreferencesGroupImpl for ComposeProjectGroup {}
+
+    impl ComposeProjectGroup {
+        pub(super) fn container_list(&self) -> model::SimpleContainerList {
+            self.container_list.get_or_init(Default::default).to_owned()
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ComposeProjectGroup(ObjectSubclass<imp::ComposeProjectGroup>)
+        @extends gtk::Widget, adw::PreferencesGroup,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<Option<&str>> for ComposeProjectGroup {
+    fn from(project: Option<&str>) -> Self {
+        glib::Object::builder().property("project", project).build()
+    }
+}
+
+impl ComposeProjectGroup {
+    pub(crate) fn add_container(&self, container: &model::Container) {
+        self.container_list().add_container(container);
+    }
+
+    pub(crate) fn select_visible(&self) {
+        self.containers()
+            .iter()
+            .for_each(|container| container.set_selected(true));
+    }
+
+    fn containers(&self) -> Vec<model::Container> {
+        self.container_list()
+            .iter::<model::Container>()
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    fn update_actions(&self) {
+        let container_list = self.container_list();
+        let running = container_list.running();
+
+        self.action_set_enabled(ACTION_START, container_list.not_running() > 0);
+        self.action_set_enabled(ACTION_STOP, running > 0);
+        self.action_set_enabled(ACTION_RESTART, running > 0);
+    }
+
+    pub(crate) fn start(&self) {
+        self.containers()
+            .into_iter()
+            .filter(|container| {
+                !matches!(
+                    container.status(),
+                    model::ContainerStatus::Running | model::ContainerStatus::Paused
+                )
+            })
+            .for_each(|container| {
+                container.start(clone!(
+                    #[weak(rename_to = obj)]
+                    self,
+                    move |result| {
+                        if let Err(e) = result {
+                            utils::show_error_toast(
+                                &obj,
+                                &gettext("Error on starting container"),
+                                &e.to_string(),
+                            );
+                        }
+                    }
+                ));
+            });
+    }
+
+    pub(crate) fn stop(&self) {
+        self.containers()
+            .into_iter()
+            .filter(|container| matches!(container.status(), model::ContainerStatus::Running))
+            .for_each(|container| {
+                container.stop(
+                    false,
+                    clone!(
+                        #[weak(rename_to = obj)]
+                        self,
+                        move |result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    &obj,
+                                    &gettext("Error on stopping container"),
+                                    &e.to_string(),
+                                );
+                            }
+                        }
+                    ),
+                );
+            });
+    }
+
+    pub(crate) fn restart(&self) {
+        self.containers()
+            .into_iter()
+            .filter(|container| matches!(container.status(), model::ContainerStatus::Running))
+            .for_each(|container| {
+                container.restart(
+                    false,
+                    clone!(
+                        #[weak(rename_to = obj)]
+                        self,
+                        move |result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    &obj,
+                                    &gettext("Error on restarting container"),
+                                    &e.to_string(),
+                                );
+                            }
+                        }
+                    ),
+                );
+            });
+    }
+
+    pub(crate) fn remove(&self) {
+        let Some(project) = self.project() else {
+            return;
+        };
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Confirm Forced Deletion of Compose Project"))
+            .body_use_markup(true)
+            .body(gettext!(
+                "All containers of project <b>{}</b> will be removed. The data created inside them will be lost and running containers will be stopped!",
+                glib::markup_escape_text(&project),
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("delete", &gettext("_Delete")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+        dialog.connect_response(
+            None,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |_, response| {
+                    if response != "delete" {
+                        return;
+                    }
+
+                    obj.containers().iter().for_each(|container| {
+                        container.remove(clone!(
+                            #[weak]
+                            obj,
+                            move |result| {
+                                if let Err(e) = result {
+                                    utils::show_error_toast(
+                                        &obj,
+                                        &gettext("Error on deleting container"),
+                                        &e.to_string(),
+                                    );
+                                }
+                            }
+                        ));
+                    });
+                }
+            ),
+        );
+
+        dialog.present(Some(self));
+    }
+}
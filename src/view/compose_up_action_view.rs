@@ -0,0 +1,118 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+use crate::widget;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ComposeUpActionView)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/compose_up_action_view.ui")]
+    pub(crate) struct ComposeUpActionView {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) action: glib::WeakRef<model::ComposeUpAction>,
+
+        #[template_child]
+        pub(super) action_indicator: TemplateChild<widget::ActionStateIndicator>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ComposeUpActionView {
+        const NAME: &'static str = "PdsComposeUpActionView";
+        type Type = super::ComposeUpActionView;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ComposeUpActionView {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            Self::Type::this_expression("action")
+                .chain_property::<model::ComposeUpAction>("state")
+                .chain_closure::<String>(closure!(|_: Self::Type, state: model::ActionState| {
+                    match state {
+                        model::ActionState::Ongoing => "ongoing",
+                        model::ActionState::Cancelled => "cancelled",
+                        model::ActionState::Failed => "failed",
+                        model::ActionState::Finished => "finished",
+                    }
+                }))
+                .bind(&*self.action_indicator, "action-state-name", Some(obj));
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ComposeUpActionView {}
+
+    #[gtk::template_callbacks]
+    impl ComposeUpActionView {
+        #[template_callback]
+        fn format_progress(&self, completed_steps: u32, steps: u32) -> String {
+            gettext!("Step {} of {}", (completed_steps + 1).min(steps), steps)
+        }
+
+        #[template_callback]
+        fn fraction(&self, completed_steps: u32, steps: u32) -> f64 {
+            if steps == 0 {
+                0.0
+            } else {
+                completed_steps as f64 / steps as f64
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ComposeUpActionView(ObjectSubclass<imp::ComposeUpActionView>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::ComposeUpAction> for view::ActionDialog {
+    fn from(value: &model::ComposeUpAction) -> Self {
+        Self::new(
+            value.upcast_ref(),
+            &gettext("Import Compose File"),
+            Some(value.opts().file.as_str()),
+            &glib::Object::builder::<ComposeUpActionView>()
+                .property("action", value)
+                .build(),
+            500,
+        )
+    }
+}
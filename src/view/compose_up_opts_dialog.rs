@@ -0,0 +1,187 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_IMPORT: &str = "compose-up-opts-dialog.import";
+const ACTION_SELECT_HOST_PATH: &str = "compose-up-opts-dialog.select-host-path";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ComposeUpOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/compose_up_opts_dialog.ui")]
+    pub(crate) struct ComposeUpOptsDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedComposeUpOpts>,
+
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) project_name_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) pod_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) pull_latest_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) start_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ComposeUpOptsDialog {
+        const NAME: &'static str = "PdsComposeUpOptsDialog";
+        type Type = super::ComposeUpOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_IMPORT, None, |widget, _, _| {
+                widget.close_and_import();
+            });
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ComposeUpOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            let opts = obj.opts();
+
+            self.pod_switch_row.set_visible(
+                obj.client()
+                    .map(|client| client.engine().capabilities().pods())
+                    .unwrap_or(false),
+            );
+
+            self.host_path_row.set_subtitle(&opts.file);
+            self.project_name_entry_row
+                .set_text(opts.project_name.as_deref().unwrap_or_default());
+            self.pod_switch_row.set_active(opts.pod);
+            self.pull_latest_switch_row.set_active(opts.pull_latest);
+            self.start_switch_row.set_active(opts.start);
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for ComposeUpOptsDialog {}
+
+    impl AdwDialogImpl for ComposeUpOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl ComposeUpOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = self
+                .host_path_row
+                .subtitle()
+                .map(|s| !s.is_empty())
+                .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_IMPORT, enabled);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ComposeUpOptsDialog(ObjectSubclass<imp::ComposeUpOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl ComposeUpOptsDialog {
+    pub(crate) fn new(client: &model::Client, opts: Option<model::BoxedComposeUpOpts>) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("opts", opts.unwrap_or_default())
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Compose File").as_str())
+            .filter(FileFilter::new("YAML").mimetype("application/yaml"))
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_import(&self) {
+        self.close();
+
+        let Some(action_list) = self.client().map(|client| client.action_list()) else {
+            return;
+        };
+
+        let imp = self.imp();
+
+        let project_name = imp.project_name_entry_row.text();
+
+        let opts = engine::opts::ComposeUpOpts {
+            file: imp.host_path_row.subtitle().unwrap_or_default().into(),
+            project_name: Some(project_name.trim())
+                .filter(|project_name| !project_name.is_empty())
+                .map(ToOwned::to_owned),
+            pod: imp.pod_switch_row.is_visible() && imp.pod_switch_row.is_active(),
+            pull_latest: imp.pull_latest_switch_row.is_active(),
+            start: imp.start_switch_row.is_active(),
+        };
+
+        view::ActionDialog::from(&action_list.compose_up(opts)).present(Some(self));
+    }
+}
@@ -52,6 +52,31 @@ pub(crate) fn container_status_combined_css_class(
     }
 }
 
+pub(crate) fn checkpoint<W: IsA<gtk::Widget>>(widget: &W, container: Option<&model::Container>) {
+    if let Some(container) = container {
+        view::ContainerCheckpointOptsDialog::new(container, None, "").present(Some(widget));
+    }
+}
+
+pub(crate) fn restore<W: IsA<gtk::Widget>>(widget: &W, container: Option<&model::Container>) {
+    let Some(container) = container else {
+        return;
+    };
+
+    if let Some(client) = container
+        .container_list()
+        .and_then(|container_list| container_list.client())
+    {
+        view::ContainerRestoreOptsDialog::new(&client, Some(container), None).present(Some(widget));
+    }
+}
+
+pub(crate) fn export<W: IsA<gtk::Widget>>(widget: &W, container: Option<&model::Container>) {
+    if let Some(container) = container {
+        view::ContainerExportOptsDialog::new(container, "").present(Some(widget));
+    }
+}
+
 pub(crate) fn rename<W>(widget: &W, container: Option<&model::Container>)
 where
     W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
@@ -0,0 +1,299 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gdk;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::rt;
+use crate::utils;
+use crate::view;
+
+const ACTION_SEARCH: &str = "container-changes-page.search";
+const ACTION_REFRESH: &str = "container-changes-page.refresh";
+const ACTION_COMMIT: &str = "container-changes-page.commit";
+
+/// Directories are expanded automatically while filtering if no more changes than this match.
+const MAX_EXPANDED_CHANGES: usize = 200;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerChangesPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_changes_page.ui")]
+    pub(crate) struct ContainerChangesPage {
+        pub(super) changes: RefCell<Vec<engine::dto::ContainerChange>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[template_child]
+        pub(super) search_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) search_bar: TemplateChild<gtk::SearchBar>,
+        #[template_child]
+        pub(super) search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) error_status_page: TemplateChild<adw::StatusPage>,
+        #[template_child]
+        pub(super) kind_toggle_group: TemplateChild<adw::ToggleGroup>,
+        #[template_child]
+        pub(super) summary_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerChangesPage {
+        const NAME: &'static str = "PdsContainerChangesPage";
+        type Type = super::ContainerChangesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.add_binding_action(gdk::Key::F, gdk::ModifierType::CONTROL_MASK, ACTION_SEARCH);
+
+            klass.install_action(ACTION_SEARCH, None, |widget, _, _| {
+                widget.toggle_search_mode();
+            });
+            klass.install_action(ACTION_REFRESH, None, |widget, _, _| {
+                widget.load();
+            });
+            klass.install_action(ACTION_COMMIT, None, |widget, _, _| {
+                widget.show_commit_dialog();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerChangesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.search_entry.set_key_capture_widget(Some(obj));
+
+            self.list_box.set_placeholder(Some(
+                &gtk::Label::builder()
+                    .label(gettext("No matching changes"))
+                    .css_classes(["dimmed"])
+                    .margin_top(12)
+                    .margin_bottom(12)
+                    .build(),
+            ));
+
+            if let Some(container) = obj.container() {
+                container.property_expression_weak("name").bind(
+                    &*self.window_title,
+                    "subtitle",
+                    glib::Object::NONE,
+                );
+            }
+
+            obj.load();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerChangesPage {}
+
+    #[gtk::template_callbacks]
+    impl ContainerChangesPage {
+        #[template_callback]
+        fn on_search_started(&self) {
+            self.search_button.set_active(true);
+        }
+
+        #[template_callback]
+        fn on_search_changed(&self) {
+            self.obj().update_list();
+        }
+
+        #[template_callback]
+        fn on_kind_toggle_group_notify_active_name(&self) {
+            self.obj().update_list();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerChangesPage(ObjectSubclass<imp::ContainerChangesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerChangesPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerChangesPage {
+    pub(crate) fn toggle_search_mode(&self) {
+        let imp = self.imp();
+        imp.search_bar
+            .set_search_mode(!imp.search_bar.is_search_mode());
+    }
+
+    fn load(&self) {
+        let Some(api) = self.container().and_then(|container| container.api()) else {
+            return;
+        };
+
+        self.action_set_enabled(ACTION_REFRESH, false);
+        self.imp().stack.set_visible_child_name("loading");
+
+        rt::Promise::new(async move { api.changes().await }).defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                let imp = obj.imp();
+
+                match result {
+                    Ok(changes) => {
+                        imp.stack.set_visible_child_name(if changes.is_empty() {
+                            "empty"
+                        } else {
+                            "loaded"
+                        });
+                        imp.changes.replace(changes);
+                        obj.update_list();
+                    }
+                    Err(e) => {
+                        log::error!("Error on retrieving container changes: {e}");
+
+                        imp.error_status_page.set_description(Some(&e.to_string()));
+                        imp.stack.set_visible_child_name("error");
+                    }
+                }
+
+                obj.action_set_enabled(ACTION_REFRESH, true);
+            }
+        ));
+    }
+
+    fn update_list(&self) {
+        let imp = self.imp();
+        let changes = imp.changes.borrow();
+
+        let count = |kind| changes.iter().filter(|change| change.kind == kind).count() as u32;
+        imp.summary_label.set_label(&gettext!(
+            // Translators: "{}" are placeholders for numbers of files.
+            "{} added, {} changed, {} deleted",
+            count(engine::dto::ContainerChangeKind::Added),
+            count(engine::dto::ContainerChangeKind::Changed),
+            count(engine::dto::ContainerChangeKind::Deleted),
+        ));
+
+        let kind = match imp.kind_toggle_group.active_name().as_deref() {
+            Some("added") => Some(engine::dto::ContainerChangeKind::Added),
+            Some("changed") => Some(engine::dto::ContainerChangeKind::Changed),
+            Some("deleted") => Some(engine::dto::ContainerChangeKind::Deleted),
+            _ => None,
+        };
+        let term = imp.search_entry.text().trim().to_lowercase();
+
+        let filtered = changes
+            .iter()
+            .filter(|change| kind.is_none_or(|kind| change.kind == kind))
+            .filter(|change| change.path.to_lowercase().contains(&term))
+            .collect::<Vec<_>>();
+
+        let expand = (kind.is_some() || !term.is_empty()) && filtered.len() <= MAX_EXPANDED_CHANGES;
+
+        imp.list_box.remove_all();
+        engine::dto::ContainerChangeNode::tree(filtered)
+            .into_iter()
+            .for_each(|node| imp.list_box.append(&node_row(node, expand)));
+    }
+
+    fn show_commit_dialog(&self) {
+        if let Some(container) = self.container() {
+            view::ContainerCommitOptsDialog::new(&container, None).present(Some(self));
+        }
+    }
+}
+
+fn node_row(node: engine::dto::ContainerChangeNode, expand: bool) -> gtk::Widget {
+    if node.children.is_empty() {
+        let row = adw::ActionRow::builder()
+            .title(&node.name)
+            .use_markup(false)
+            .build();
+        if let Some(kind) = node.kind {
+            row.add_suffix(&kind_label(kind));
+        }
+
+        return row.upcast();
+    }
+
+    let count = node.count() as u32;
+    let row = adw::ExpanderRow::builder()
+        .title(&node.name)
+        .subtitle(ngettext!("{} change", "{} changes", count, count))
+        .use_markup(false)
+        .build();
+    if let Some(kind) = node.kind {
+        row.add_suffix(&kind_label(kind));
+    }
+
+    // Rows for the children are only created once the directory is expanded.
+    let children = RefCell::new(Some(node.children));
+    row.connect_expanded_notify(move |row| {
+        if let Some(children) = children.take() {
+            children
+                .into_iter()
+                .for_each(|child| row.add_row(&node_row(child, expand)));
+        }
+    });
+    row.set_expanded(expand);
+
+    row.upcast()
+}
+
+fn kind_label(kind: engine::dto::ContainerChangeKind) -> gtk::Label {
+    let (label, css_class) = match kind {
+        engine::dto::ContainerChangeKind::Added => (gettext("Added"), "success"),
+        engine::dto::ContainerChangeKind::Changed => (gettext("Changed"), "warning"),
+        engine::dto::ContainerChangeKind::Deleted => (gettext("Deleted"), "error"),
+    };
+
+    gtk::Label::builder()
+        .label(label)
+        .css_classes(["caption", css_class])
+        .valign(gtk::Align::Center)
+        .build()
+}
@@ -0,0 +1,218 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::SaveFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_CHECKPOINT: &str = "container-checkpoint-opts-dialog.checkpoint";
+const ACTION_SELECT_HOST_PATH: &str = "container-checkpoint-opts-dialog.select-host-path";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerCheckpointOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_checkpoint_opts_dialog.ui")]
+    pub(crate) struct ContainerCheckpointOptsDialog {
+        #[property(get, set, construct_only)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedContainerCheckpointOpts>,
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+
+        #[template_child]
+        pub(super) leave_running_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) keep_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) tcp_established_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) export_expander_row: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerCheckpointOptsDialog {
+        const NAME: &'static str = "PdsContainerCheckpointOptsDialog";
+        type Type = super::ContainerCheckpointOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_CHECKPOINT, None, |widget, _, _| {
+                widget.close_and_checkpoint();
+            });
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerCheckpointOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            let opts = obj.opts();
+
+            self.leave_running_switch_row.set_active(opts.leave_running);
+            self.keep_switch_row.set_active(opts.keep);
+            self.tcp_established_switch_row
+                .set_active(opts.tcp_established);
+
+            let host_path = obj.host_path();
+            self.export_expander_row
+                .set_enable_expansion(!host_path.is_empty());
+            self.host_path_row.set_subtitle(&host_path);
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for ContainerCheckpointOptsDialog {}
+
+    impl AdwDialogImpl for ContainerCheckpointOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl ContainerCheckpointOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = !self.export_expander_row.enables_expansion()
+                || self
+                    .host_path_row
+                    .subtitle()
+                    .map(|s| !s.is_empty())
+                    .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_CHECKPOINT, enabled);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerCheckpointOptsDialog(ObjectSubclass<imp::ContainerCheckpointOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl ContainerCheckpointOptsDialog {
+    pub(crate) fn new(
+        container: &model::Container,
+        opts: Option<model::BoxedContainerCheckpointOpts>,
+        host_path: &str,
+    ) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .property("opts", opts.unwrap_or_default())
+            .property("host-path", host_path)
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let container_name = self
+            .container()
+            .map(|container| container.name().to_string())
+            .unwrap_or_else(|| String::from("container"));
+
+        let suggested_archive_name = glib::DateTime::now_local()
+            .and_then(|now| now.format_iso8601())
+            .map(|date| format!("{container_name}-checkpoint-{date}.tar.gz"))
+            .unwrap_or_else(|_| format!("{container_name}-checkpoint.tar.gz"));
+
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Host Path").as_str())
+            .current_name(suggested_archive_name.as_str())
+            .filter(
+                FileFilter::new("Compressed Tar Archive").mimetype("application/x-compressed-tar"),
+            )
+            .modal(true);
+
+        utils::show_save_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_checkpoint(&self) {
+        self.close();
+
+        let Some(container) = self.container() else {
+            return;
+        };
+
+        let Some(action_list) = container
+            .container_list()
+            .and_then(|container_list| container_list.client())
+            .map(|client| client.action_list())
+        else {
+            return;
+        };
+
+        let imp = self.imp();
+
+        let host_path = if imp.export_expander_row.enables_expansion() {
+            imp.host_path_row.subtitle().unwrap_or_default()
+        } else {
+            glib::GString::default()
+        };
+
+        let opts = engine::opts::ContainerCheckpointOpts {
+            keep: imp.keep_switch_row.is_active(),
+            leave_running: imp.leave_running_switch_row.is_active(),
+            tcp_established: imp.tcp_established_switch_row.is_active(),
+        };
+
+        view::ActionDialog::from(&action_list.checkpoint_container(
+            &container,
+            opts,
+            host_path.as_str(),
+        ))
+        .present(Some(self));
+    }
+}
@@ -5,10 +5,13 @@ use glib::Properties;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
+use crate::engine;
 use crate::model;
 use crate::utils;
 use crate::view;
 
+const ACTION_LOGIN: &str = "container-create-action-view.login";
+
 mod imp {
     use super::*;
 
@@ -28,6 +31,8 @@ mod imp {
 
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
+
+            klass.install_action(ACTION_LOGIN, None, |widget, _, _| widget.login());
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -62,6 +67,29 @@ glib::wrapper! {
         @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
 }
 
+impl ContainerCreateActionView {
+    fn login(&self) {
+        let Some(action) = self.action() else {
+            return;
+        };
+        let Some(client) = action
+            .action_list()
+            .and_then(|action_list| action_list.client())
+        else {
+            return;
+        };
+
+        view::ImagePullOptsDialog::login(
+            &client,
+            engine::opts::ImagePullOpts {
+                reference: action.opts().image.clone(),
+                ..Default::default()
+            },
+        )
+        .present(Some(self));
+    }
+}
+
 impl From<&model::ContainerCreateAction> for view::ActionDialog {
     fn from(value: &model::ContainerCreateAction) -> Self {
         Self::new(
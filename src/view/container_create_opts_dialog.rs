@@ -16,11 +16,16 @@ use crate::view;
 use crate::widget;
 
 const ACTION_ADD_ENV_VAR: &str = "container-create-opts-dialog.add-env-var";
+const ACTION_ADD_EXTRA_HOST: &str = "container-create-opts-dialog.add-extra-host";
 const ACTION_ADD_LABEL: &str = "container-create-opts-dialog.add-label";
+const ACTION_ADD_NETWORK: &str = "container-create-opts-dialog.add-network";
+const ACTION_ADD_ULIMIT: &str = "container-create-opts-dialog.add-ulimit";
+const ACTION_ADD_SECRET: &str = "container-create-opts-dialog.add-secret";
 const ACTION_ADD_PORT_MAPPING: &str = "container-create-opts-dialog.add-port-mapping";
 const ACTION_ADD_VOLUME: &str = "container-create-opts-dialog.add-volume";
 const ACTION_CREATE: &str = "container-create-opts-dialog.create";
 const ACTION_CREATE_AND_RUN: &str = "container-create-opts-dialog.create-and-run";
+const ACTION_CREATE_SECRET: &str = "container-create-opts-dialog.create-secret";
 
 mod imp {
     use super::*;
@@ -33,6 +38,10 @@ mod imp {
         pub(super) volumes: OnceCell<gio::ListStore>,
         pub(super) env_vars: OnceCell<gio::ListStore>,
         pub(super) labels: OnceCell<gio::ListStore>,
+        pub(super) networks: OnceCell<gio::ListStore>,
+        pub(super) extra_hosts: OnceCell<gio::ListStore>,
+        pub(super) secrets: OnceCell<gio::ListStore>,
+        pub(super) ulimits: OnceCell<gio::ListStore>,
 
         #[property(get, set, construct_only, nullable)]
         pub(super) client: glib::WeakRef<model::Client>,
@@ -48,6 +57,8 @@ mod imp {
         #[template_child]
         pub(super) pull_latest_image_switch_row: TemplateChild<adw::SwitchRow>,
         #[template_child]
+        pub(super) platform_entry_row: TemplateChild<widget::PlatformEntryRow>,
+        #[template_child]
         pub(super) command_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
         pub(super) terminal_switch_row: TemplateChild<adw::SwitchRow>,
@@ -72,6 +83,22 @@ mod imp {
         #[template_child]
         pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
         #[template_child]
+        pub(super) secrets_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) network_mode_preferences_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) network_mode_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) network_mode_container_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) networks_preferences_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) networks_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) dns_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) extra_hosts_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
         pub(super) health_check_command_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
         pub(super) health_check_interval_value: TemplateChild<gtk::Adjustment>,
@@ -81,6 +108,36 @@ mod imp {
         pub(super) health_check_start_period_value: TemplateChild<gtk::Adjustment>,
         #[template_child]
         pub(super) health_check_retries_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) entrypoint_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) user_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) workdir_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) hostname_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) init_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) cpus_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) cpu_shares_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) cpuset_cpus_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) pids_limit_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) shm_size_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) ulimits_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) read_only_rootfs_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) cap_add_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) cap_drop_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) security_opts_entry_row: TemplateChild<adw::EntryRow>,
     }
 
     #[glib::object_subclass]
@@ -105,6 +162,21 @@ mod imp {
             klass.install_action(ACTION_ADD_LABEL, None, |widget, _, _| {
                 widget.add_label(None);
             });
+            klass.install_action(ACTION_ADD_NETWORK, None, |widget, _, _| {
+                widget.add_network(None);
+            });
+            klass.install_action(ACTION_ADD_EXTRA_HOST, None, |widget, _, _| {
+                widget.add_extra_host(None);
+            });
+            klass.install_action(ACTION_ADD_ULIMIT, None, |widget, _, _| {
+                widget.add_ulimit(None);
+            });
+            klass.install_action(ACTION_ADD_SECRET, None, |widget, _, _| {
+                widget.add_secret(None);
+            });
+            klass.install_action(ACTION_CREATE_SECRET, None, |widget, _, _| {
+                widget.create_secret();
+            });
             klass.install_action(ACTION_CREATE_AND_RUN, None, |widget, _, _| {
                 widget.close_and_create(true);
             });
@@ -136,7 +208,7 @@ mod imp {
 
             let obj = &*self.obj();
 
-            gtk::ClosureExpression::new::<bool>(
+            let no_pod_expr = gtk::ClosureExpression::new::<bool>(
                 [
                     self.pod_selection_combo_row.property_expression("active"),
                     self.pod_selection_combo_row
@@ -146,12 +218,29 @@ mod imp {
                     |_: Option<Self::Type>, active: bool, pod: Option<&model::Pod>| !active
                         || pod.is_none()
                 ),
-            )
-            .bind(
+            );
+            no_pod_expr.bind(
                 &self.port_mapping_preferences_group.get(),
                 "visible",
                 Some(obj),
             );
+            // Containers of a pod share the network namespace of its infra container.
+            no_pod_expr.bind(
+                &self.network_mode_preferences_group.get(),
+                "visible",
+                Some(obj),
+            );
+            let network_mode_expr = self.network_mode_row.property_expression("selected");
+            gtk::ClosureExpression::new::<bool>(
+                [
+                    no_pod_expr.upcast_ref::<gtk::Expression>(),
+                    network_mode_expr.upcast_ref(),
+                ],
+                closure!(|_: Option<Self::Type>, no_pod: bool, network_mode: u32| {
+                    no_pod && network_mode == 0
+                }),
+            )
+            .bind(&self.networks_preferences_group.get(), "visible", Some(obj));
 
             bind_model(
                 &self.port_mapping_list_box,
@@ -192,6 +281,60 @@ mod imp {
                 &gettext("Add Label"),
             );
 
+            bind_model(
+                &self.secrets_list_box,
+                self.secrets(),
+                |item| {
+                    view::SecretRefRow::from(item.downcast_ref::<model::SecretRef>().unwrap())
+                        .upcast()
+                },
+                ACTION_ADD_SECRET,
+                &gettext("Add Secret"),
+            );
+
+            bind_model(
+                &self.networks_list_box,
+                self.networks(),
+                |item| {
+                    view::NetworkAttachmentRow::from(
+                        item.downcast_ref::<model::NetworkAttachment>().unwrap(),
+                    )
+                    .upcast()
+                },
+                ACTION_ADD_NETWORK,
+                &gettext("Add Network"),
+            );
+
+            bind_model(
+                &self.extra_hosts_list_box,
+                self.extra_hosts(),
+                |item| {
+                    view::KeyValRow::new(
+                        &gettext("Hostname"),
+                        &gettext("IP"),
+                        item.downcast_ref::<model::KeyVal>().unwrap(),
+                    )
+                    .upcast()
+                },
+                ACTION_ADD_EXTRA_HOST,
+                &gettext("Add Host"),
+            );
+
+            bind_model(
+                &self.ulimits_list_box,
+                self.ulimits(),
+                |item| {
+                    view::KeyValRow::new(
+                        &gettext("Name"),
+                        &gettext("Soft:Hard"),
+                        item.downcast_ref::<model::KeyVal>().unwrap(),
+                    )
+                    .upcast()
+                },
+                ACTION_ADD_ULIMIT,
+                &gettext("Add Ulimit"),
+            );
+
             let mut opts = self.obj().opts();
 
             self.name_entry_row.set_text(&opts.name);
@@ -199,6 +342,7 @@ mod imp {
             self.pod_selection_combo_row.select_pod(opts.pod.as_deref());
             self.pull_latest_image_switch_row
                 .set_active(opts.pull_latest);
+            self.platform_entry_row.set_platform(opts.platform.as_ref());
             self.terminal_switch_row.set_active(opts.terminal);
             self.privileged_switch_row.set_active(opts.privileged);
             self.restart_policy_row
@@ -237,6 +381,43 @@ mod imp {
                 obj.add_label(Some(model::KeyVal::from((key.as_str(), val.as_str()))));
             });
 
+            opts.secrets.iter().for_each(|secret_opts| {
+                if let Some(secret_ref) = obj
+                    .client()
+                    .map(|client| model::SecretRef::new(&client, Some(secret_opts)))
+                {
+                    obj.add_secret(Some(secret_ref));
+                }
+            });
+
+            match opts.network_mode {
+                engine::opts::ContainerCreateNetworkMode::Default => {
+                    self.network_mode_row.set_selected(0)
+                }
+                engine::opts::ContainerCreateNetworkMode::Host => {
+                    self.network_mode_row.set_selected(1)
+                }
+                engine::opts::ContainerCreateNetworkMode::None => {
+                    self.network_mode_row.set_selected(2)
+                }
+                engine::opts::ContainerCreateNetworkMode::Container(ref container) => {
+                    self.network_mode_row.set_selected(3);
+                    self.network_mode_container_entry_row.set_text(container);
+                }
+            }
+            opts.networks.iter().for_each(|network_opts| {
+                if let Some(network_attachment) = obj
+                    .client()
+                    .map(|client| model::NetworkAttachment::new(&client, Some(network_opts)))
+                {
+                    obj.add_network(Some(network_attachment));
+                }
+            });
+            self.dns_entry_row.set_text(&opts.dns.join(", "));
+            opts.extra_hosts.iter().for_each(|host| {
+                obj.add_extra_host(Some(host.into()));
+            });
+
             let health_config = opts.health_config.take().unwrap_or_default();
             self.health_check_command_entry_row.set_text(
                 &health_config
@@ -253,6 +434,42 @@ mod imp {
                 .set_value(health_config.start_period.unwrap_or_default() as f64);
             self.health_check_retries_value
                 .set_value(health_config.retries.unwrap_or_default() as f64);
+
+            self.entrypoint_entry_row.set_text(
+                &opts
+                    .entrypoint
+                    .as_ref()
+                    .map(|entrypoint| entrypoint.join(" "))
+                    .unwrap_or_default(),
+            );
+            self.user_entry_row
+                .set_text(opts.user.as_deref().unwrap_or_default());
+            self.workdir_entry_row
+                .set_text(opts.workdir.as_deref().unwrap_or_default());
+            self.hostname_entry_row
+                .set_text(opts.hostname.as_deref().unwrap_or_default());
+            self.init_switch_row.set_active(opts.init);
+            self.cpus_value.set_value(opts.cpus.unwrap_or_default());
+            self.cpu_shares_value
+                .set_value(opts.cpu_shares.unwrap_or_default() as f64);
+            self.cpuset_cpus_entry_row
+                .set_text(opts.cpuset_cpus.as_deref().unwrap_or_default());
+            self.pids_limit_value
+                .set_value(opts.pids_limit.unwrap_or_default() as f64);
+            self.shm_size_value
+                .set_value((opts.shm_size.unwrap_or_default() / 1_000_000) as f64);
+            opts.ulimits.iter().for_each(|ulimit| {
+                obj.add_ulimit(Some(model::KeyVal::from((
+                    ulimit.name.as_str(),
+                    format!("{}:{}", ulimit.soft, ulimit.hard).as_str(),
+                ))));
+            });
+            self.read_only_rootfs_switch_row
+                .set_active(opts.read_only_rootfs);
+            self.cap_add_entry_row.set_text(&opts.cap_add.join(", "));
+            self.cap_drop_entry_row.set_text(&opts.cap_drop.join(", "));
+            self.security_opts_entry_row
+                .set_text(&opts.security_opts.join(", "));
         }
     }
 
@@ -267,6 +484,12 @@ mod imp {
 
     #[gtk::template_callbacks]
     impl ContainerCreateOptsDialog {
+        #[template_callback]
+        fn on_network_mode_row_notify_selected(&self) {
+            self.network_mode_container_entry_row
+                .set_visible(self.network_mode_row.selected() == 3);
+        }
+
         #[template_callback]
         fn on_name_entry_row_notify_text(&self) {
             let enabled = !self.name_entry_row.text().is_empty();
@@ -295,6 +518,26 @@ mod imp {
             self.labels
                 .get_or_init(gio::ListStore::new::<model::KeyVal>)
         }
+
+        pub(super) fn secrets(&self) -> &gio::ListStore {
+            self.secrets
+                .get_or_init(gio::ListStore::new::<model::SecretRef>)
+        }
+
+        pub(super) fn networks(&self) -> &gio::ListStore {
+            self.networks
+                .get_or_init(gio::ListStore::new::<model::NetworkAttachment>)
+        }
+
+        pub(super) fn extra_hosts(&self) -> &gio::ListStore {
+            self.extra_hosts
+                .get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+
+        pub(super) fn ulimits(&self) -> &gio::ListStore {
+            self.ulimits
+                .get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
     }
 }
 
@@ -330,20 +573,29 @@ impl ContainerCreateOptsDialog {
         let imp = self.imp();
 
         engine::opts::ContainerCreateOpts {
-            cmd: Some(imp.command_entry_row.text().trim())
-                .filter(|cmd| !cmd.is_empty())
-                .map(|cmd| {
-                    cmd.split(' ')
-                        .filter(|part| !part.is_empty())
-                        .map(ToOwned::to_owned)
-                        .collect()
-                }),
+            cap_add: split_comma_separated(&imp.cap_add_entry_row.text()),
+            cap_drop: split_comma_separated(&imp.cap_drop_entry_row.text()),
+            cmd: split_command(&imp.command_entry_row.text()),
+            cpu_shares: Some(imp.cpu_shares_value.value() as u64).filter(|shares| *shares > 0),
+            cpus: Some(imp.cpus_value.value()).filter(|cpus| *cpus > 0.0),
+            cpuset_cpus: non_empty(&imp.cpuset_cpus_entry_row.text()),
+            dns: split_comma_separated(&imp.dns_entry_row.text()),
+            entrypoint: split_command(&imp.entrypoint_entry_row.text()),
             env: imp
                 .env_vars()
                 .iter::<model::KeyVal>()
                 .map(Result::unwrap)
                 .map(|entry| (entry.key(), entry.value()))
                 .collect(),
+            extra_hosts: imp
+                .extra_hosts()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .map(|entry| engine::opts::PodHost {
+                    ip: entry.value(),
+                    name: entry.key(),
+                })
+                .collect(),
             health_config: Some(imp.health_check_command_entry_row.text().trim())
                 .filter(|test| !test.is_empty())
                 .map(|test| engine::dto::HealthConfig {
@@ -355,7 +607,9 @@ impl ContainerCreateOptsDialog {
                     test: Some(test.split(' ').map(str::to_string).collect()),
                     timeout: Some(imp.health_check_timeout_value.value() as i64 * 1_000_000_000),
                 }),
+            hostname: non_empty(&imp.hostname_entry_row.text()),
             image: imp.image_suggestion_entry_row.text().into(),
+            init: imp.init_switch_row.is_active(),
             labels: imp
                 .labels()
                 .iter::<model::KeyVal>()
@@ -378,6 +632,26 @@ impl ContainerCreateOptsDialog {
                 })
                 .collect(),
             name: imp.name_entry_row.text().into(),
+            network_mode: match imp.network_mode_row.selected() {
+                1 => engine::opts::ContainerCreateNetworkMode::Host,
+                2 => engine::opts::ContainerCreateNetworkMode::None,
+                3 => engine::opts::ContainerCreateNetworkMode::Container(
+                    imp.network_mode_container_entry_row
+                        .text()
+                        .trim()
+                        .to_owned(),
+                ),
+                _ => engine::opts::ContainerCreateNetworkMode::Default,
+            },
+            networks: imp
+                .networks()
+                .iter::<model::NetworkAttachment>()
+                .map(Result::unwrap)
+                .filter(|network_attachment| !network_attachment.network().is_empty())
+                .map(|network_attachment| (&network_attachment).into())
+                .collect(),
+            pids_limit: Some(imp.pids_limit_value.value() as i64).filter(|limit| *limit > 0),
+            platform: imp.platform_entry_row.platform(),
             pod: imp
                 .pod_selection_combo_row
                 .active()
@@ -392,13 +666,33 @@ impl ContainerCreateOptsDialog {
                 .collect(),
             pull_latest: imp.pull_latest_image_switch_row.is_active(),
             privileged: imp.privileged_switch_row.is_active(),
+            read_only_rootfs: imp.read_only_rootfs_switch_row.is_active(),
             restart_policy: match imp.restart_policy_row.selected() {
                 1 => engine::dto::RestartPolicy::Always,
                 2 => engine::dto::RestartPolicy::OnFailure,
                 3 => engine::dto::RestartPolicy::UnlessStopped,
                 _ => engine::dto::RestartPolicy::No,
             },
+            secrets: imp
+                .secrets()
+                .iter::<model::SecretRef>()
+                .map(Result::unwrap)
+                .filter(|secret_ref| {
+                    !secret_ref.secret().is_empty()
+                        && !(secret_ref.env() && secret_ref.target().trim().is_empty())
+                })
+                .map(|secret_ref| (&secret_ref).into())
+                .collect(),
+            security_opts: split_comma_separated(&imp.security_opts_entry_row.text()),
+            shm_size: Some(imp.shm_size_value.value() as u64 * 1_000_000).filter(|size| *size > 0),
             terminal: imp.terminal_switch_row.is_active(),
+            ulimits: imp
+                .ulimits()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .filter_map(|entry| parse_ulimit(entry.key().trim(), entry.value().trim()))
+                .collect(),
+            user: non_empty(&imp.user_entry_row.text()),
             volumes: imp
                 .volumes()
                 .iter::<model::Mount>()
@@ -415,6 +709,7 @@ impl ContainerCreateOptsDialog {
                         .unwrap_or_default(),
                 })
                 .collect(),
+            workdir: non_empty(&imp.workdir_entry_row.text()),
         }
     }
 
@@ -435,6 +730,87 @@ impl ContainerCreateOptsDialog {
     fn add_label(&self, entry: Option<model::KeyVal>) {
         add_key_val(self.imp().labels(), entry);
     }
+
+    fn add_secret(&self, secret_ref: Option<model::SecretRef>) -> Option<model::SecretRef> {
+        secret_ref
+            .or_else(|| {
+                self.client()
+                    .map(|client| model::SecretRef::new(&client, None))
+            })
+            .map(|secret_ref| add_secret(self.imp().secrets(), secret_ref))
+    }
+
+    fn create_secret(&self) {
+        let Some(secret_list) = self.client().and_then(|client| client.secret_list()) else {
+            return;
+        };
+
+        view::SecretCreateOptsDialog::from(&secret_list).present(Some(self));
+    }
+
+    fn add_network(
+        &self,
+        network_attachment: Option<model::NetworkAttachment>,
+    ) -> Option<model::NetworkAttachment> {
+        network_attachment
+            .or_else(|| {
+                self.client()
+                    .map(|client| model::NetworkAttachment::new(&client, None))
+            })
+            .map(|network_attachment| add_network(self.imp().networks(), network_attachment))
+    }
+
+    fn add_extra_host(&self, entry: Option<model::KeyVal>) {
+        add_key_val(self.imp().extra_hosts(), entry);
+    }
+
+    fn add_ulimit(&self, entry: Option<model::KeyVal>) {
+        add_key_val(self.imp().ulimits(), entry);
+    }
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    Some(text.trim())
+        .filter(|text| !text.is_empty())
+        .map(ToOwned::to_owned)
+}
+
+fn split_command(text: &str) -> Option<Vec<String>> {
+    non_empty(text).map(|cmd| {
+        cmd.split(' ')
+            .filter(|part| !part.is_empty())
+            .map(ToOwned::to_owned)
+            .collect()
+    })
+}
+
+fn split_comma_separated(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Parses a ulimit in the form of `soft[:hard]`. The hard limit defaults to the soft limit.
+fn parse_ulimit(name: &str, limits: &str) -> Option<engine::opts::ContainerCreateUlimit> {
+    if name.is_empty() {
+        return None;
+    }
+
+    let (soft, hard) = match limits.split_once(':') {
+        Some((soft, hard)) => (soft.trim().parse().ok()?, hard.trim().parse().ok()?),
+        None => {
+            let limit = limits.parse().ok()?;
+            (limit, limit)
+        }
+    };
+
+    Some(engine::opts::ContainerCreateUlimit {
+        name: name.to_owned(),
+        soft,
+        hard,
+    })
 }
 
 fn bind_model<F>(
@@ -498,6 +874,39 @@ fn add_mount(model: &gio::ListStore, mount: model::Mount) -> model::Mount {
     mount
 }
 
+fn add_secret(model: &gio::ListStore, secret_ref: model::SecretRef) -> model::SecretRef {
+    secret_ref.connect_remove_request(clone!(
+        #[weak]
+        model,
+        move |secret_ref| {
+            if let Some(pos) = model.find(secret_ref) {
+                model.remove(pos);
+            }
+        }
+    ));
+
+    model.append(&secret_ref);
+    secret_ref
+}
+
+fn add_network(
+    model: &gio::ListStore,
+    network_attachment: model::NetworkAttachment,
+) -> model::NetworkAttachment {
+    network_attachment.connect_remove_request(clone!(
+        #[weak]
+        model,
+        move |network_attachment| {
+            if let Some(pos) = model.find(network_attachment) {
+                model.remove(pos);
+            }
+        }
+    ));
+
+    model.append(&network_attachment);
+    network_attachment
+}
+
 fn add_key_val(model: &gio::ListStore, entry: Option<model::KeyVal>) {
     let entry = entry.unwrap_or_default();
 
@@ -31,9 +31,14 @@ const ACTION_DELETE: &str = "container-details-page.delete";
 
 const ACTION_INSPECT: &str = "container-details-page.inspect";
 const ACTION_GENERATE_KUBE: &str = "container-details-page.generate-kube";
+const ACTION_GENERATE_QUADLET: &str = "container-details-page.generate-quadlet";
+const ACTION_GENERATE_SYSTEMD: &str = "container-details-page.generate-systemd";
 const ACTION_SHOW_TTY: &str = "container-details-page.show-tty";
 const ACTION_SHOW_LOG: &str = "container-details-page.show-log";
 const ACTION_SHOW_PROCESSES: &str = "container-details-page.show-processes";
+const ACTION_SHOW_FILES: &str = "container-details-page.show-files";
+const ACTION_SHOW_CHANGES: &str = "container-details-page.show-changes";
+const ACTION_EDIT_RESOURCE_LIMITS: &str = "container-details-page.edit-resource-limits";
 
 mod imp {
     use super::*;
@@ -129,6 +134,12 @@ mod imp {
             klass.install_action(ACTION_GENERATE_KUBE, None, |widget, _, _| {
                 widget.show_kube();
             });
+            klass.install_action(ACTION_GENERATE_QUADLET, None, |widget, _, _| {
+                widget.show_kube_inspection_or_kube(view::ScalableTextViewMode::Quadlet);
+            });
+            klass.install_action(ACTION_GENERATE_SYSTEMD, None, |widget, _, _| {
+                widget.show_kube_inspection_or_kube(view::ScalableTextViewMode::Systemd);
+            });
             klass.install_action(ACTION_SHOW_TTY, None, |widget, _, _| {
                 widget.show_tty();
             });
@@ -138,6 +149,15 @@ mod imp {
             klass.install_action(ACTION_SHOW_PROCESSES, None, |widget, _, _| {
                 widget.show_processes();
             });
+            klass.install_action(ACTION_SHOW_FILES, None, |widget, _, _| {
+                widget.show_files();
+            });
+            klass.install_action(ACTION_SHOW_CHANGES, None, |widget, _, _| {
+                widget.show_changes();
+            });
+            klass.install_action(ACTION_EDIT_RESOURCE_LIMITS, None, |widget, _, _| {
+                widget.edit_resource_limits();
+            });
 
             klass.add_binding_action(gdk::Key::F2, gdk::ModifierType::empty(), ACTION_RENAME);
             klass.add_binding_action(gdk::Key::K, gdk::ModifierType::CONTROL_MASK, ACTION_COMMIT);
@@ -469,6 +489,38 @@ impl ContainerDetailsPage {
         });
     }
 
+    pub(crate) fn show_files(&self) {
+        self.exec_action(|| {
+            if let Some(container) = self.container() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ContainerFilesPage::from(&container))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) fn show_changes(&self) {
+        self.exec_action(|| {
+            if let Some(container) = self.container() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ContainerChangesPage::from(&container))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) fn edit_resource_limits(&self) {
+        self.exec_action(|| {
+            if let Some(container) = self.container() {
+                view::ContainerResourceLimitsDialog::from(&container).present(Some(self));
+            }
+        });
+    }
+
     pub(crate) fn show_tty(&self) {
         self.exec_action(|| {
             if let Some(container) = self.container() {
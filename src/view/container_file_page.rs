@@ -0,0 +1,331 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gdk;
+use gtk::glib;
+use sourceview5::prelude::*;
+
+use crate::engine;
+use crate::model;
+use crate::rt;
+use crate::utils;
+use crate::view;
+use crate::widget;
+
+const ACTION_DOWNLOAD: &str = "container-file-page.download";
+const ACTION_EDIT: &str = "container-file-page.edit";
+const ACTION_SAVE: &str = "container-file-page.save";
+
+/// Files larger than this are not loaded into the text view.
+const MAX_PREVIEW_SIZE: u64 = 4 * 1024 * 1024;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerFilePage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_file_page.ui")]
+    pub(crate) struct ContainerFilePage {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[property(get, set, construct_only)]
+        pub(super) path: OnceCell<String>,
+        #[property(get, set)]
+        pub(super) editing: Cell<bool>,
+        /// The file as it was last read from or written to the container.
+        pub(super) loaded: RefCell<Option<(engine::dto::ContainerFile, Vec<u8>)>>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) unavailable_status_page: TemplateChild<adw::StatusPage>,
+        #[template_child]
+        pub(super) source_view: TemplateChild<widget::ScalableTextView>,
+        #[template_child]
+        pub(super) source_buffer: TemplateChild<sourceview5::Buffer>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerFilePage {
+        const NAME: &'static str = "PdsContainerFilePage";
+        type Type = super::ContainerFilePage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_DOWNLOAD, None, |widget, _, _| {
+                widget.show_download_dialog();
+            });
+
+            klass.install_property_action(ACTION_EDIT, "editing");
+
+            klass.install_action(ACTION_SAVE, None, |widget, _, _| {
+                widget.save(false);
+            });
+            klass.add_binding_action(gdk::Key::S, gdk::ModifierType::CONTROL_MASK, ACTION_SAVE);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerFilePage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let path = obj.path();
+            let (dir, name) = match path.rsplit_once('/') {
+                Some((dir, name)) => (if dir.is_empty() { "/" } else { dir }, name),
+                None => ("/", path.as_str()),
+            };
+            self.window_title.set_title(name);
+            self.window_title.set_subtitle(dir);
+
+            if let Some(language) =
+                sourceview5::LanguageManager::default().guess_language(Some(name), None)
+            {
+                self.source_buffer.set_language(Some(&language));
+            }
+
+            let adw_style_manager = adw::StyleManager::default();
+            self.on_notify_dark(&adw_style_manager);
+            adw_style_manager.connect_dark_notify(clone!(
+                #[weak]
+                obj,
+                move |style_manager| {
+                    obj.imp().on_notify_dark(style_manager);
+                }
+            ));
+
+            self.source_buffer.connect_modified_changed(clone!(
+                #[weak]
+                obj,
+                move |_| obj.update_actions()
+            ));
+            obj.connect_editing_notify(|obj| obj.update_actions());
+
+            obj.load();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerFilePage {}
+
+    impl ContainerFilePage {
+        fn on_notify_dark(&self, style_manager: &adw::StyleManager) {
+            self.source_buffer.set_style_scheme(
+                sourceview5::StyleSchemeManager::default()
+                    .scheme(if style_manager.is_dark() {
+                        "Adwaita-dark"
+                    } else {
+                        "Adwaita"
+                    })
+                    .as_ref(),
+            );
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerFilePage(ObjectSubclass<imp::ContainerFilePage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl ContainerFilePage {
+    pub(crate) fn new(container: &model::Container, path: &str) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .property("path", path)
+            .build()
+    }
+
+    fn load(&self) {
+        let Some(api) = self.container().and_then(|container| container.api()) else {
+            return;
+        };
+
+        let imp = self.imp();
+        imp.loaded.replace(None);
+        self.set_editing(false);
+        self.update_actions();
+        imp.stack.set_visible_child_name("loading");
+
+        rt::Promise::new({
+            let path = self.path();
+            async move { api.read_file(path, MAX_PREVIEW_SIZE).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                let imp = obj.imp();
+
+                match result {
+                    Ok((file, content)) => match std::str::from_utf8(&content) {
+                        Ok(text) if !text.contains('\0') => {
+                            imp.source_buffer.set_text(text);
+                            imp.source_buffer.set_modified(false);
+                            imp.loaded.replace(Some((file, content)));
+                            obj.update_actions();
+                            imp.stack.set_visible_child_name("loaded");
+                        }
+                        _ => {
+                            imp.unavailable_status_page.set_description(Some(&gettext(
+                                "Binary files can only be downloaded",
+                            )));
+                            imp.stack.set_visible_child_name("unavailable");
+                        }
+                    },
+                    Err(e) => {
+                        log::error!("Error on reading file: {e}");
+
+                        imp.unavailable_status_page
+                            .set_description(Some(&e.to_string()));
+                        imp.stack.set_visible_child_name("unavailable");
+                    }
+                }
+            }
+        ));
+    }
+
+    fn update_actions(&self) {
+        let imp = self.imp();
+        let loaded = imp.loaded.borrow().is_some();
+
+        self.action_set_enabled(ACTION_EDIT, loaded);
+        self.action_set_enabled(
+            ACTION_SAVE,
+            loaded && self.editing() && imp.source_buffer.is_modified(),
+        );
+    }
+
+    /// Writes the buffer back to the container. Unless `force` is set, the file is read again
+    /// first and the write is refused if it has been changed since it was loaded.
+    fn save(&self, force: bool) {
+        let Some(api) = self.container().and_then(|container| container.api()) else {
+            return;
+        };
+        let Some((file, original)) = self.imp().loaded.borrow().clone() else {
+            return;
+        };
+
+        let buffer = &*self.imp().source_buffer;
+        let content =
+            String::from(buffer.text(&buffer.start_iter(), &buffer.end_iter(), true)).into_bytes();
+
+        self.action_set_enabled(ACTION_SAVE, false);
+
+        rt::Promise::new({
+            let path = self.path();
+            async move {
+                if !force {
+                    let (_, current) = api.read_file(path.clone(), u64::MAX).await?;
+                    if current != original {
+                        return Ok(None);
+                    }
+                }
+
+                let dir = match path.rsplit_once('/') {
+                    Some((dir, _)) if !dir.is_empty() => dir.to_owned(),
+                    _ => "/".to_owned(),
+                };
+                api.write_file(dir, &file, content.clone()).await?;
+
+                anyhow::Ok(Some((file, content)))
+            }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                match result {
+                    Ok(Some(loaded)) => {
+                        let imp = obj.imp();
+                        imp.loaded.replace(Some(loaded));
+                        imp.source_buffer.set_modified(false);
+
+                        utils::show_toast(&obj, gettext("File saved"));
+                    }
+                    Ok(None) => obj.show_conflict_dialog(),
+                    Err(e) => {
+                        log::error!("Error on saving file: {e}");
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on saving file"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+
+                obj.update_actions();
+            }
+        ));
+    }
+
+    fn show_conflict_dialog(&self) {
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("File Changed in Container"))
+            .body(gettext(
+                "The file has been modified in the container since it was opened",
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("reload", &gettext("_Discard and Reload")),
+            ("overwrite", &gettext("_Overwrite")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("overwrite", adw::ResponseAppearance::Destructive);
+
+        dialog.connect_response(
+            None,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |_, response| match response {
+                    "reload" => obj.load(),
+                    "overwrite" => obj.save(true),
+                    _ => {}
+                }
+            ),
+        );
+
+        dialog.present(Some(self));
+    }
+
+    fn show_download_dialog(&self) {
+        if let Some(container) = self.container() {
+            view::ContainerCopyFromOptsDialog::new(&container, &self.path(), "")
+                .present(Some(self));
+        }
+    }
+}
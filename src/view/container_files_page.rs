@@ -0,0 +1,520 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gdk;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::model::prelude::*;
+use crate::rt;
+use crate::utils;
+use crate::view;
+
+const ACTION_GO_UP: &str = "container-files-page.go-up";
+const ACTION_REFRESH: &str = "container-files-page.refresh";
+const ACTION_UPLOAD: &str = "container-files-page.upload";
+const ACTION_DOWNLOAD: &str = "container-files-page.download";
+const ACTION_RENAME: &str = "container-files-page.rename";
+const ACTION_DELETE: &str = "container-files-page.delete";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerFilesPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_files_page.ui")]
+    pub(crate) struct ContainerFilesPage {
+        /// the directory that is currently shown
+        pub(super) path: RefCell<String>,
+        pub(super) files: RefCell<Vec<engine::dto::ContainerFile>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) error_status_page: TemplateChild<adw::StatusPage>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerFilesPage {
+        const NAME: &'static str = "PdsContainerFilesPage";
+        type Type = super::ContainerFilesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_GO_UP, None, |widget, _, _| {
+                widget.go_up();
+            });
+            klass.install_action(ACTION_REFRESH, None, |widget, _, _| {
+                widget.load();
+            });
+            klass.install_action(ACTION_UPLOAD, None, |widget, _, _| {
+                widget.show_upload_dialog();
+            });
+            klass.install_action(
+                ACTION_DOWNLOAD,
+                Some(glib::VariantTy::STRING),
+                |widget, _, name| {
+                    if let Some(name) = name.and_then(String::from_variant) {
+                        widget.show_download_dialog(&name);
+                    }
+                },
+            );
+            klass.install_action(
+                ACTION_RENAME,
+                Some(glib::VariantTy::STRING),
+                |widget, _, name| {
+                    if let Some(name) = name.and_then(String::from_variant) {
+                        widget.rename(name);
+                    }
+                },
+            );
+            klass.install_action(
+                ACTION_DELETE,
+                Some(glib::VariantTy::STRING),
+                |widget, _, name| {
+                    if let Some(name) = name.and_then(String::from_variant) {
+                        widget.delete(name);
+                    }
+                },
+            );
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerFilesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let drop_target =
+                gtk::DropTarget::new(gdk::FileList::static_type(), gdk::DragAction::COPY);
+            drop_target.connect_drop(clone!(
+                #[weak]
+                obj,
+                #[upgrade_or]
+                false,
+                move |_, value, _, _| match value.get::<gdk::FileList>() {
+                    Ok(file_list) => {
+                        obj.upload(&file_list.files());
+                        true
+                    }
+                    Err(_) => false,
+                }
+            ));
+            obj.add_controller(drop_target);
+
+            if let Some(container) = obj.container() {
+                container.connect_status_notify(clone!(
+                    #[weak]
+                    obj,
+                    move |_| obj.update_actions()
+                ));
+            }
+            obj.update_actions();
+
+            obj.navigate(String::from("/"));
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerFilesPage {}
+
+    #[gtk::template_callbacks]
+    impl ContainerFilesPage {
+        #[template_callback]
+        fn on_list_box_row_activated(&self, row: &gtk::ListBoxRow) {
+            let obj = &*self.obj();
+
+            let Some(file) = usize::try_from(row.index())
+                .ok()
+                .and_then(|index| self.files.borrow().get(index).cloned())
+            else {
+                return;
+            };
+
+            let path = join_path(&self.path.borrow(), &file.name);
+            if file.is_dir {
+                obj.navigate(path);
+            } else if let Some(container) = obj.container() {
+                utils::navigation_view(obj).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ContainerFilePage::new(&container, &path))
+                        .build(),
+                );
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerFilesPage(ObjectSubclass<imp::ContainerFilesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerFilesPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerFilesPage {
+    fn navigate(&self, path: String) {
+        let imp = self.imp();
+
+        imp.window_title.set_subtitle(&path);
+        self.action_set_enabled(ACTION_GO_UP, path != "/");
+        imp.path.replace(path);
+
+        self.load();
+    }
+
+    fn go_up(&self) {
+        let parent = parent_path(&self.imp().path.borrow());
+        self.navigate(parent);
+    }
+
+    fn update_actions(&self) {
+        // Renaming and deleting requires running commands inside the container.
+        let running = self
+            .container()
+            .is_some_and(|container| container.status() == model::ContainerStatus::Running);
+
+        self.action_set_enabled(ACTION_RENAME, running);
+        self.action_set_enabled(ACTION_DELETE, running);
+    }
+
+    fn load(&self) {
+        let Some(container) = self.container() else {
+            return;
+        };
+        let Some(api) = container.api() else {
+            return;
+        };
+
+        let imp = self.imp();
+        imp.stack.set_visible_child_name("loading");
+
+        let path = imp.path.borrow().clone();
+        let running = container.status() == model::ContainerStatus::Running;
+
+        rt::Promise::new({
+            let path = path.clone();
+            async move { api.list_dir(path, running).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                // Ignore results of directories that have been left in the meantime.
+                if *obj.imp().path.borrow() == path {
+                    obj.set_files(result);
+                }
+            }
+        ));
+    }
+
+    fn set_files(&self, result: anyhow::Result<Vec<engine::dto::ContainerFile>>) {
+        let imp = self.imp();
+
+        imp.list_box.remove_all();
+
+        match result {
+            Ok(files) => {
+                files
+                    .iter()
+                    .for_each(|file| imp.list_box.append(&file_row(file)));
+
+                imp.stack
+                    .set_visible_child_name(if files.is_empty() { "empty" } else { "loaded" });
+                imp.files.replace(files);
+            }
+            Err(e) => {
+                log::error!("Error on listing directory: {e}");
+
+                imp.files.replace(Vec::new());
+                imp.error_status_page.set_description(Some(&e.to_string()));
+                imp.stack.set_visible_child_name("error");
+            }
+        }
+    }
+
+    fn show_upload_dialog(&self) {
+        if let Some(container) = self.container() {
+            view::ContainerCopyToOptsDialog::new(&container, false, "", &self.imp().path.borrow())
+                .present(Some(self));
+        }
+    }
+
+    fn upload(&self, files: &[gio::File]) {
+        let Some(container) = self.container() else {
+            return;
+        };
+        let Some(action_list) = container
+            .container_list()
+            .and_then(|container_list| container_list.client())
+            .map(|client| client.action_list())
+        else {
+            return;
+        };
+
+        let path = self.imp().path.borrow().clone();
+        let host_paths = files.iter().filter_map(gio::File::path).collect::<Vec<_>>();
+
+        host_paths.iter().for_each(|host_path| {
+            action_list
+                .copy_to_container(
+                    &container,
+                    host_path.is_dir(),
+                    &host_path.to_string_lossy(),
+                    &path,
+                )
+                .connect_state_notify(clone!(
+                    #[weak(rename_to = obj)]
+                    self,
+                    move |action| if action.state() == model::ActionState::Finished {
+                        obj.load();
+                    }
+                ));
+        });
+
+        let len = host_paths.len() as u32;
+        utils::show_toast(
+            self,
+            ngettext!("Uploading {} file", "Uploading {} files", len, len),
+        );
+    }
+
+    fn show_download_dialog(&self, name: &str) {
+        if let Some(container) = self.container() {
+            view::ContainerCopyFromOptsDialog::new(
+                &container,
+                &join_path(&self.imp().path.borrow(), name),
+                "",
+            )
+            .present(Some(self));
+        }
+    }
+
+    fn rename(&self, name: String) {
+        let entry = gtk::Entry::builder()
+            .text(&name)
+            .activates_default(true)
+            .build();
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Rename File"))
+            .body(&name)
+            .extra_child(&entry)
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("rename", &gettext("_Rename")),
+        ]);
+        dialog.set_default_response(Some("rename"));
+        dialog.set_response_appearance("rename", adw::ResponseAppearance::Suggested);
+
+        dialog.connect_response(
+            Some("rename"),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                #[weak]
+                entry,
+                move |_, _| {
+                    let new_name = entry.text();
+                    let new_name = new_name.trim();
+                    if new_name.is_empty() || new_name == name || new_name.contains('/') {
+                        return;
+                    }
+
+                    let Some(api) = obj.container().and_then(|container| container.api()) else {
+                        return;
+                    };
+
+                    let path = obj.imp().path.borrow().clone();
+                    let from = join_path(&path, &name);
+                    let to = join_path(&path, new_name);
+
+                    obj.run_file_operation(
+                        async move { api.rename_path(from, to).await },
+                        gettext("Error on renaming file"),
+                    );
+                }
+            ),
+        );
+
+        dialog.present(Some(self));
+    }
+
+    fn delete(&self, name: String) {
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Delete File?"))
+            .body(gettext!(
+                "'{}' will be deleted permanently, including the content of directories.",
+                name
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("delete", &gettext("_Delete")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+        dialog.connect_response(
+            Some("delete"),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |_, _| {
+                    let Some(api) = obj.container().and_then(|container| container.api()) else {
+                        return;
+                    };
+
+                    let path = join_path(&obj.imp().path.borrow(), &name);
+
+                    obj.run_file_operation(
+                        async move { api.remove_path(path).await },
+                        gettext("Error on deleting file"),
+                    );
+                }
+            ),
+        );
+
+        dialog.present(Some(self));
+    }
+
+    fn run_file_operation<F>(&self, future: F, error_title: String)
+    where
+        F: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        rt::Promise::new(future).defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                if let Err(e) = result {
+                    utils::show_error_toast(&obj, &error_title, &e.to_string());
+                }
+                obj.load();
+            }
+        ));
+    }
+}
+
+fn file_row(file: &engine::dto::ContainerFile) -> adw::ActionRow {
+    let mut details = Vec::new();
+    if !file.is_dir {
+        details.push(glib::format_size(file.size).to_string());
+    }
+    details.push(file.permissions());
+    details.push(format!("{}:{}", file.user, file.group));
+    if let Some(modified) = utils::date_time_from_unix_local(file.modified) {
+        details.push(
+            modified
+                // Translators: This is a date time format (https://valadoc.org/glib-2.0/GLib.DateTime.format.html)
+                .format(&gettext("%x %X"))
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    let row = adw::ActionRow::builder()
+        .title(&file.name)
+        .subtitle(details.join(" · "))
+        .use_markup(false)
+        .activatable(file.is_dir || file.kind == engine::dto::ContainerFileKind::File)
+        .build();
+
+    row.add_prefix(&gtk::Image::from_icon_name(
+        if file.kind == engine::dto::ContainerFileKind::Symlink {
+            "emblem-symbolic-link-symbolic"
+        } else if file.is_dir {
+            "folder-symbolic"
+        } else {
+            "text-x-generic-symbolic"
+        },
+    ));
+
+    let menu = gio::Menu::new();
+    [
+        (gettext("_Download"), ACTION_DOWNLOAD),
+        (gettext("_Rename…"), ACTION_RENAME),
+        (gettext("_Delete"), ACTION_DELETE),
+    ]
+    .into_iter()
+    .for_each(|(label, action)| {
+        let item = gio::MenuItem::new(Some(&label), None);
+        item.set_action_and_target_value(Some(action), Some(&file.name.to_variant()));
+        menu.append_item(&item);
+    });
+
+    row.add_suffix(
+        &gtk::MenuButton::builder()
+            .icon_name("view-more-symbolic")
+            .menu_model(&menu)
+            .valign(gtk::Align::Center)
+            .tooltip_text(gettext("Menu"))
+            .css_classes(vec!["flat".to_string()])
+            .build(),
+    );
+    if file.is_dir {
+        row.add_suffix(&gtk::Image::from_icon_name("go-next-symbolic"));
+    }
+
+    row
+}
+
+fn join_path(dir: &str, name: &str) -> String {
+    if dir.ends_with('/') {
+        format!("{dir}{name}")
+    } else {
+        format!("{dir}/{name}")
+    }
+}
+
+fn parent_path(path: &str) -> String {
+    match path.trim_end_matches('/').rsplit_once('/') {
+        Some((parent, _)) if !parent.is_empty() => parent.to_owned(),
+        _ => String::from("/"),
+    }
+}
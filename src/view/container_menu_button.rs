@@ -17,6 +17,9 @@ const ACTION_RESTART: &str = "container-menu-button.restart";
 const ACTION_PAUSE: &str = "container-menu-button.pause";
 const ACTION_RESUME: &str = "container-menu-button.resume";
 const ACTION_RENAME: &str = "container-menu-button.rename";
+const ACTION_CHECKPOINT: &str = "container-menu-button.checkpoint";
+const ACTION_RESTORE: &str = "container-menu-button.restore";
+const ACTION_EXPORT: &str = "container-menu-button.export";
 const ACTION_DELETE: &str = "container-menu-button.delete";
 
 mod imp {
@@ -63,6 +66,15 @@ mod imp {
             klass.install_action(ACTION_RENAME, None, |widget, _, _| {
                 view::container::rename(widget, widget.container().as_ref());
             });
+            klass.install_action(ACTION_CHECKPOINT, None, |widget, _, _| {
+                view::container::checkpoint(widget, widget.container().as_ref());
+            });
+            klass.install_action(ACTION_RESTORE, None, |widget, _, _| {
+                view::container::restore(widget, widget.container().as_ref());
+            });
+            klass.install_action(ACTION_EXPORT, None, |widget, _, _| {
+                view::container::export(widget, widget.container().as_ref());
+            });
 
             klass.install_action(ACTION_DELETE, None, |widget, _, _| {
                 view::container::remove(widget, widget.container());
@@ -133,16 +145,28 @@ glib::wrapper! {
 
 impl ContainerMenuButton {
     fn update_actions(&self) {
-        let Some(status) = self.container().as_ref().map(model::Container::status) else {
+        let Some(container) = self.container() else {
             return;
         };
 
+        let status = container.status();
+        let checkpoint = container
+            .container_list()
+            .and_then(|container_list| container_list.client())
+            .map(|client| client.engine().capabilities().checkpoint())
+            .unwrap_or(false);
+
         self.action_set_enabled(ACTION_START, status.can_start());
         self.action_set_enabled(ACTION_STOP, status.can_stop());
         self.action_set_enabled(ACTION_KILL, status.can_kill());
         self.action_set_enabled(ACTION_RESTART, status.can_restart());
         self.action_set_enabled(ACTION_RESUME, status.can_resume());
         self.action_set_enabled(ACTION_PAUSE, status.can_pause());
+        self.action_set_enabled(
+            ACTION_CHECKPOINT,
+            checkpoint && status == model::ContainerStatus::Running,
+        );
+        self.action_set_enabled(ACTION_RESTORE, checkpoint && status.can_start());
         self.action_set_enabled(ACTION_DELETE, status.can_force_delete());
     }
 }
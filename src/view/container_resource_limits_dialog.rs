@@ -0,0 +1,198 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+
+const ACTION_APPLY: &str = "container-resource-limits-dialog.apply";
+
+const MB: i64 = 1_000_000;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerResourceLimitsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_resource_limits_dialog.ui")]
+    pub(crate) struct ContainerResourceLimitsDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+
+        #[template_child]
+        pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
+        #[template_child]
+        pub(super) restart_policy_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) cpu_shares_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) cpu_period_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) cpu_quota_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) cpuset_cpus_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) memory_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) memory_swap_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) pids_limit_value: TemplateChild<gtk::Adjustment>,
+        #[template_child]
+        pub(super) blkio_weight_value: TemplateChild<gtk::Adjustment>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerResourceLimitsDialog {
+        const NAME: &'static str = "PdsContainerResourceLimitsDialog";
+        type Type = super::ContainerResourceLimitsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_APPLY, None, |widget, _, _| {
+                widget.apply();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerResourceLimitsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let Some(details) = self
+                .obj()
+                .container()
+                .as_ref()
+                .and_then(model::Container::details)
+            else {
+                return;
+            };
+
+            self.restart_policy_row
+                .set_selected(match details.restart_policy() {
+                    model::ContainerRestartPolicy::No => 0,
+                    model::ContainerRestartPolicy::Always => 1,
+                    model::ContainerRestartPolicy::OnFailure => 2,
+                    model::ContainerRestartPolicy::UnlessStopped => 3,
+                });
+
+            let limits = details.resource_limits();
+
+            self.cpu_shares_value.set_value(limits.cpu_shares as f64);
+            self.cpu_period_value.set_value(limits.cpu_period as f64);
+            self.cpu_quota_value
+                .set_value(limits.cpu_quota.max(0) as f64);
+            self.cpuset_cpus_entry_row.set_text(&limits.cpuset_cpus);
+            self.memory_value.set_value((limits.memory / MB) as f64);
+            self.memory_swap_value.set_value(if limits.memory_swap < 0 {
+                -1.0
+            } else {
+                (limits.memory_swap / MB) as f64
+            });
+            self.pids_limit_value
+                .set_value(limits.pids_limit.max(0) as f64);
+            self.blkio_weight_value
+                .set_value(limits.blkio_weight as f64);
+        }
+    }
+
+    impl WidgetImpl for ContainerResourceLimitsDialog {}
+
+    impl AdwDialogImpl for ContainerResourceLimitsDialog {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerResourceLimitsDialog(ObjectSubclass<imp::ContainerResourceLimitsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl From<&model::Container> for ContainerResourceLimitsDialog {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerResourceLimitsDialog {
+    fn apply(&self) {
+        let Some(container) = self.container() else {
+            return;
+        };
+
+        self.action_set_enabled(ACTION_APPLY, false);
+
+        container.update_resources(
+            self.create_opts(),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| match result {
+                    Ok(_) => {
+                        obj.close();
+                    }
+                    Err(e) => {
+                        obj.action_set_enabled(ACTION_APPLY, true);
+                        utils::show_error_toast(
+                            &*obj.imp().toast_overlay,
+                            &gettext("Error on updating resource limits"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+            ),
+        );
+    }
+
+    fn create_opts(&self) -> engine::opts::ContainerUpdateOpts {
+        let imp = self.imp();
+
+        let memory_swap = imp.memory_swap_value.value() as i64;
+
+        engine::opts::ContainerUpdateOpts {
+            resource_limits: engine::dto::ResourceLimits {
+                blkio_weight: imp.blkio_weight_value.value() as u16,
+                cpu_period: imp.cpu_period_value.value() as i64,
+                cpu_quota: imp.cpu_quota_value.value() as i64,
+                cpu_shares: imp.cpu_shares_value.value() as i64,
+                cpuset_cpus: imp.cpuset_cpus_entry_row.text().trim().to_owned(),
+                memory: imp.memory_value.value() as i64 * MB,
+                memory_swap: if memory_swap < 0 {
+                    -1
+                } else {
+                    memory_swap * MB
+                },
+                pids_limit: imp.pids_limit_value.value() as i64,
+            },
+            restart_policy: match imp.restart_policy_row.selected() {
+                1 => engine::dto::RestartPolicy::Always,
+                2 => engine::dto::RestartPolicy::OnFailure,
+                3 => engine::dto::RestartPolicy::UnlessStopped,
+                _ => engine::dto::RestartPolicy::No,
+            },
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerRestoreActionView)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_restore_action_view.ui")]
+    pub(crate) struct ContainerRestoreActionView {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) action: glib::WeakRef<model::ContainerRestoreAction>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerRestoreActionView {
+        const NAME: &'static str = "PdsContainerRestoreActionView";
+        type Type = super::ContainerRestoreActionView;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerRestoreActionView {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerRestoreActionView {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerRestoreActionView(ObjectSubclass<imp::ContainerRestoreActionView>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::ContainerRestoreAction> for view::ActionDialog {
+    fn from(value: &model::ContainerRestoreAction) -> Self {
+        Self::new(
+            value.upcast_ref(),
+            &gettext("Restore Container"),
+            match value.opts().source {
+                engine::opts::ContainerRestoreSource::Container(_) => None,
+                engine::opts::ContainerRestoreSource::Archive(ref path) => Some(path.as_str()),
+            },
+            &glib::Object::builder::<ContainerRestoreActionView>()
+                .property("action", value)
+                .build(),
+            400,
+        )
+    }
+}
@@ -0,0 +1,217 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_RESTORE: &str = "container-restore-opts-dialog.restore";
+const ACTION_SELECT_HOST_PATH: &str = "container-restore-opts-dialog.select-host-path";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerRestoreOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_restore_opts_dialog.ui")]
+    pub(crate) struct ContainerRestoreOptsDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        /// the container to restore in place; an archive is restored if it is not set
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedContainerRestoreOpts>,
+
+        #[template_child]
+        pub(super) archive_preferences_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) keep_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) tcp_established_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) ignore_static_ip_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) ignore_static_mac_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerRestoreOptsDialog {
+        const NAME: &'static str = "PdsContainerRestoreOptsDialog";
+        type Type = super::ContainerRestoreOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_RESTORE, None, |widget, _, _| {
+                widget.close_and_restore();
+            });
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerRestoreOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            let opts = obj.opts();
+
+            self.archive_preferences_group
+                .set_visible(obj.container().is_none());
+
+            if let engine::opts::ContainerRestoreSource::Archive(ref path) = opts.source {
+                self.host_path_row.set_subtitle(path);
+            }
+            self.name_entry_row
+                .set_text(opts.name.as_deref().unwrap_or_default());
+            self.keep_switch_row.set_active(opts.keep);
+            self.tcp_established_switch_row
+                .set_active(opts.tcp_established);
+            self.ignore_static_ip_switch_row
+                .set_active(opts.ignore_static_ip);
+            self.ignore_static_mac_switch_row
+                .set_active(opts.ignore_static_mac);
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for ContainerRestoreOptsDialog {}
+
+    impl AdwDialogImpl for ContainerRestoreOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl ContainerRestoreOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = self.obj().container().is_some()
+                || self
+                    .host_path_row
+                    .subtitle()
+                    .map(|s| !s.is_empty())
+                    .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_RESTORE, enabled);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerRestoreOptsDialog(ObjectSubclass<imp::ContainerRestoreOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl ContainerRestoreOptsDialog {
+    pub(crate) fn new(
+        client: &model::Client,
+        container: Option<&model::Container>,
+        opts: Option<model::BoxedContainerRestoreOpts>,
+    ) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("container", container)
+            .property("opts", opts.unwrap_or_default())
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Checkpoint Archive").as_str())
+            .filter(
+                FileFilter::new("Compressed Tar Archive").mimetype("application/x-compressed-tar"),
+            )
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_restore(&self) {
+        self.close();
+
+        let Some(action_list) = self.client().map(|client| client.action_list()) else {
+            return;
+        };
+
+        let imp = self.imp();
+
+        let source = match self.container() {
+            Some(container) => {
+                engine::opts::ContainerRestoreSource::Container(container.id().into())
+            }
+            None => engine::opts::ContainerRestoreSource::Archive(
+                imp.host_path_row.subtitle().unwrap_or_default().into(),
+            ),
+        };
+
+        let name = imp.name_entry_row.text();
+
+        let opts = engine::opts::ContainerRestoreOpts {
+            source,
+            name: if name.is_empty() || self.container().is_some() {
+                None
+            } else {
+                Some(name.into())
+            },
+            keep: imp.keep_switch_row.is_active(),
+            tcp_established: imp.tcp_established_switch_row.is_active(),
+            ignore_static_ip: imp.ignore_static_ip_switch_row.is_active(),
+            ignore_static_mac: imp.ignore_static_mac_switch_row.is_active(),
+        };
+
+        view::ActionDialog::from(&action_list.restore_container(opts)).present(Some(self));
+    }
+}
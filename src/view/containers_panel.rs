@@ -24,6 +24,8 @@ use crate::utils;
 use crate::view;
 
 const ACTION_CREATE_CONTAINER: &str = "containers-panel.create-container";
+const ACTION_RESTORE_CHECKPOINT: &str = "containers-panel.restore-checkpoint";
+const ACTION_COMPOSE_UP: &str = "containers-panel.compose-up";
 const ACTION_PRUNE_UNUSED_CONTAINERS: &str = "containers-panel.prune-unused-containers";
 const ACTION_TOGGLE_CONTAINERS_VIEW: &str = "containers-panel.toggle-containers-view";
 const ACTION_ENTER_SELECTION_MODE: &str = "containers-panel.enter-selection-mode";
@@ -40,6 +42,7 @@ const ACTION_TOGGLE_SORT_DIRECTION: &str = "containers-panel.toggle-sort-directi
 const ACTION_CHANGE_SORT_ATTRIBUTE: &str = "containers-panel.change-sort-attribute";
 const ACTION_TOGGLE_SHOW_RUNNING_CONTAINERS_FIRST: &str =
     "containers-panel.toggle-show-running-containers-first";
+const ACTION_TOGGLE_GROUP_BY_PROJECT: &str = "containers-panel.toggle-group-by-project";
 const ACTION_SHOW_ALL_CONTAINERS: &str = "containers-panel.show-all-containers";
 
 const ACTIONS_SELECTION: &[&str] = &[
@@ -72,6 +75,7 @@ impl Deref for Settings {
 enum ContainersView {
     Grid(view::ContainersGridView),
     List(view::ContainersListView),
+    Projects(view::ContainersProjectsView),
 }
 
 impl ContainersView {
@@ -79,6 +83,7 @@ impl ContainersView {
         match self {
             Self::Grid(view) => view.upcast_ref(),
             Self::List(view) => view.upcast_ref(),
+            Self::Projects(view) => view.upcast_ref(),
         }
     }
 
@@ -86,6 +91,7 @@ impl ContainersView {
         match self {
             Self::Grid(view) => view.set_model(model),
             Self::List(view) => view.set_model(model),
+            Self::Projects(view) => view.set_model(model),
         }
     }
 
@@ -93,6 +99,7 @@ impl ContainersView {
         match self {
             Self::Grid(view) => view.select_visible(),
             Self::List(view) => view.select_visible(),
+            Self::Projects(view) => view.select_visible(),
         }
     }
 }
@@ -140,8 +147,10 @@ mod imp {
         pub(super) sort_attribute: RefCell<SortAttribute>,
         #[property(get, set)]
         pub(super) show_running_containers_first: Cell<bool>,
+        #[property(get, set)]
+        pub(super) group_by_project: Cell<bool>,
         #[template_child]
-        pub(super) create_container_button: TemplateChild<gtk::Button>,
+        pub(super) create_container_menu_button: TemplateChild<gtk::MenuButton>,
         #[template_child]
         pub(super) prune_button: TemplateChild<gtk::Button>,
         #[template_child]
@@ -155,7 +164,7 @@ mod imp {
         #[template_child]
         pub(super) header_stack: TemplateChild<gtk::Stack>,
         #[template_child]
-        pub(super) create_container_button_top_bin: TemplateChild<adw::Bin>,
+        pub(super) create_container_menu_button_top_bin: TemplateChild<adw::Bin>,
         #[template_child]
         pub(super) prune_button_top_bin: TemplateChild<adw::Bin>,
         #[template_child]
@@ -177,7 +186,7 @@ mod imp {
         #[template_child]
         pub(super) overhang_action_bar: TemplateChild<gtk::ActionBar>,
         #[template_child]
-        pub(super) create_container_button_bottom_bin: TemplateChild<adw::Bin>,
+        pub(super) create_container_menu_button_bottom_bin: TemplateChild<adw::Bin>,
         #[template_child]
         pub(super) prune_button_bottom_bin: TemplateChild<adw::Bin>,
         #[template_child]
@@ -204,6 +213,12 @@ mod imp {
             klass.install_action(ACTION_CREATE_CONTAINER, None, move |widget, _, _| {
                 widget.create_container();
             });
+            klass.install_action(ACTION_RESTORE_CHECKPOINT, None, |widget, _, _| {
+                widget.restore_checkpoint();
+            });
+            klass.install_action(ACTION_COMPOSE_UP, None, |widget, _, _| {
+                widget.compose_up();
+            });
 
             klass.install_action(ACTION_PRUNE_UNUSED_CONTAINERS, None, |widget, _, _| {
                 widget.show_prune_dialog();
@@ -258,6 +273,7 @@ mod imp {
                 ACTION_TOGGLE_SHOW_RUNNING_CONTAINERS_FIRST,
                 "show-running-containers-first",
             );
+            klass.install_property_action(ACTION_TOGGLE_GROUP_BY_PROJECT, "group-by-project");
 
             klass.install_action(ACTION_SHOW_ALL_CONTAINERS, None, |widget, _, _| {
                 widget.show_all_containers();
@@ -308,6 +324,9 @@ mod imp {
             self.settings
                 .bind("show-running-first", obj, "show-running-containers-first")
                 .build();
+            self.settings
+                .bind("group-by-project", obj, "group-by-project")
+                .build();
 
             let container_list_expr = Self::Type::this_expression("container-list");
             let container_list_containers_expr =
@@ -513,8 +532,15 @@ mod imp {
         }
 
         fn set_containers_view(&self) {
+            let obj = &*self.obj();
             let model = self.model.borrow();
-            let view = if self.settings.string("view") == "grid" {
+
+            // Grouping by project takes precedence over the grid and list representations.
+            obj.action_set_enabled(ACTION_TOGGLE_CONTAINERS_VIEW, !obj.group_by_project());
+
+            let view = if obj.group_by_project() {
+                ContainersView::Projects(view::ContainersProjectsView::from(model.as_ref()))
+            } else if self.settings.string("view") == "grid" {
                 self.view_button.set_icon_name("view-list-symbolic");
 
                 self.view_button
@@ -537,15 +563,15 @@ mod imp {
         #[template_callback]
         fn on_notify_collapsed(&self) {
             if self.obj().collapsed() {
-                self.create_container_button_top_bin
+                self.create_container_menu_button_top_bin
                     .set_child(gtk::Widget::NONE);
                 self.prune_button_top_bin.set_child(gtk::Widget::NONE);
                 self.view_options_split_button_top_bin
                     .set_child(gtk::Widget::NONE);
                 self.view_button_top_bin.set_child(gtk::Widget::NONE);
 
-                self.create_container_button_bottom_bin
-                    .set_child(Some(&self.create_container_button.get()));
+                self.create_container_menu_button_bottom_bin
+                    .set_child(Some(&self.create_container_menu_button.get()));
                 self.prune_button_bottom_bin
                     .set_child(Some(&self.prune_button.get()));
                 self.view_options_split_button_bottom_bin
@@ -553,15 +579,15 @@ mod imp {
                 self.view_button_bottom_bin
                     .set_child(Some(&self.view_button.get()));
             } else {
-                self.create_container_button_bottom_bin
+                self.create_container_menu_button_bottom_bin
                     .set_child(gtk::Widget::NONE);
                 self.prune_button_bottom_bin.set_child(gtk::Widget::NONE);
                 self.view_options_split_button_bottom_bin
                     .set_child(gtk::Widget::NONE);
                 self.view_button_bottom_bin.set_child(gtk::Widget::NONE);
 
-                self.create_container_button_top_bin
-                    .set_child(Some(&self.create_container_button.get()));
+                self.create_container_menu_button_top_bin
+                    .set_child(Some(&self.create_container_menu_button.get()));
                 self.prune_button_top_bin
                     .set_child(Some(&self.prune_button.get()));
                 self.view_options_split_button_top_bin
@@ -581,6 +607,13 @@ mod imp {
             self.update_sorter();
         }
 
+        #[template_callback]
+        fn on_notify_group_by_project(&self) {
+            if self.obj().is_realized() {
+                self.set_containers_view();
+            }
+        }
+
         #[template_callback]
         fn on_notify_search_mode_enabled(&self) {
             if self.search_bar.is_search_mode() {
@@ -612,6 +645,14 @@ mod imp {
                 .iter()
                 .for_each(|action_name| obj.action_set_enabled(action_name, false));
 
+            obj.action_set_enabled(
+                ACTION_RESTORE_CHECKPOINT,
+                value
+                    .client()
+                    .map(|client| client.engine().capabilities().checkpoint())
+                    .unwrap_or(false),
+            );
+
             value.connect_notify_local(
                 Some("num-selected"),
                 clone!(
@@ -769,6 +810,18 @@ impl ContainersPanel {
         }
     }
 
+    pub(crate) fn restore_checkpoint(&self) {
+        if let Some(client) = self.client() {
+            view::ContainerRestoreOptsDialog::new(&client, None, None).present(Some(self));
+        }
+    }
+
+    pub(crate) fn compose_up(&self) {
+        if let Some(client) = self.client() {
+            view::ComposeUpOptsDialog::new(&client, None).present(Some(self));
+        }
+    }
+
     pub(crate) fn show_prune_dialog(&self) {
         if let Some(client) = self.client() {
             view::ContainersPruneOptsDialog::new(&client, None).present(Some(self));
@@ -0,0 +1,173 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+use indexmap::IndexMap;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainersProjectsView)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/containers_projects_view.ui")]
+    pub(crate) struct ContainersProjectsView {
+        pub(super) groups: RefCell<Vec<view::ComposeProjectGroup>>,
+        pub(super) handler_id: RefCell<Option<glib::SignalHandlerId>>,
+        #[property(get, set = Self::set_model, nullable, construct)]
+        pub(super) model: glib::WeakRef<gio::ListModel>,
+        #[template_child]
+        pub(super) page: TemplateChild<adw::PreferencesPage>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainersProjectsView {
+        const NAME: &'static str = "PdsContainersProjectsView";
+        type Type = super::ContainersProjectsView;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainersProjectsView {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn dispose(&self) {
+            if let Some(model) = self.obj().model()
+                && let Some(handler_id) = self.handler_id.take()
+            {
+                model.disconnect(handler_id);
+            }
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainersProjectsView {}
+
+    impl ContainersProjectsView {
+        pub(super) fn set_model(&self, value: Option<&gio::ListModel>) {
+            let obj = &*self.obj();
+            if obj.model().as_ref() == value {
+                return;
+            }
+
+            if let Some(model) = obj.model()
+                && let Some(handler_id) = self.handler_id.take()
+            {
+                model.disconnect(handler_id);
+            }
+
+            if let Some(model) = value {
+                let handler_id = model.connect_items_changed(clone!(
+                    #[weak]
+                    obj,
+                    move |model, _, _, _| obj.imp().update_groups(model)
+                ));
+                self.handler_id.replace(Some(handler_id));
+            }
+
+            self.model.set(value);
+
+            match value {
+                Some(model) => self.update_groups(model),
+                None => self.clear_groups(),
+            }
+        }
+
+        fn clear_groups(&self) {
+            self.groups
+                .take()
+                .iter()
+                .for_each(|group| self.page.remove(group));
+        }
+
+        /// Folds the containers of the model into one group per compose project, keeping the
+        /// order of the model. Containers without a project are collected in a trailing group.
+        fn update_groups(&self, model: &gio::ListModel) {
+            self.clear_groups();
+
+            let mut projects = IndexMap::<Option<String>, Vec<model::Container>>::new();
+            model
+                .iter::<model::Container>()
+                .map(Result::unwrap)
+                .for_each(|container| {
+                    projects
+                        .entry(container.compose_project())
+                        .or_default()
+                        .push(container);
+                });
+
+            if let Some(ungrouped) = projects.shift_remove(&None) {
+                projects.insert(None, ungrouped);
+            }
+
+            let groups = projects
+                .into_iter()
+                .map(|(project, containers)| {
+                    let group = view::ComposeProjectGroup::from(project.as_deref());
+                    containers
+                        .iter()
+                        .for_each(|container| group.add_container(container));
+
+                    self.page.add(&group);
+
+                    group
+                })
+                .collect();
+
+            self.groups.replace(groups);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainersProjectsView(ObjectSubclass<imp::ContainersProjectsView>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for ContainersProjectsView {
+    fn default() -> Self {
+        glib::Object::new()
+    }
+}
+
+impl From<Option<&gio::ListModel>> for ContainersProjectsView {
+    fn from(model: Option<&gio::ListModel>) -> Self {
+        glib::Object::builder().property("model", model).build()
+    }
+}
+
+impl ContainersProjectsView {
+    pub(crate) fn select_visible(&self) {
+        self.imp()
+            .groups
+            .borrow()
+            .iter()
+            .for_each(view::ComposeProjectGroup::select_visible);
+    }
+}
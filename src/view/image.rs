@@ -109,3 +109,40 @@ pub(crate) fn create_container<W: IsA<gtk::Widget>>(widget: &W, image: Option<mo
     )
     .present(Some(widget));
 }
+
+pub(crate) fn save_images<W: IsA<gtk::Widget>>(widget: &W, images: &[model::Image]) {
+    let Some(client) = images
+        .first()
+        .and_then(model::Image::image_list)
+        .and_then(|image_list| image_list.client())
+    else {
+        return;
+    };
+
+    view::ImagesSaveOptsDialog::new(
+        &client,
+        engine::opts::ImagesSaveOpts {
+            references: images
+                .iter()
+                .flat_map(|image| {
+                    let repo_tags = image
+                        .repo_tags()
+                        .iter::<model::RepoTag>()
+                        .map(Result::unwrap)
+                        .map(|repo_tag| repo_tag.full())
+                        .collect::<Vec<_>>();
+
+                    if repo_tags.is_empty() {
+                        vec![image.id()]
+                    } else {
+                        repo_tags
+                    }
+                })
+                .collect(),
+            ..Default::default()
+        }
+        .into(),
+        "",
+    )
+    .present(Some(widget));
+}
@@ -1,7 +1,9 @@
 use adw::prelude::*;
 use adw::subclass::prelude::*;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
@@ -18,6 +20,10 @@ mod imp {
     pub(crate) struct ImageBuildActionView {
         #[property(get, set, construct_only, nullable)]
         pub(super) action: glib::WeakRef<model::ImageBuildAction>,
+        #[template_child]
+        pub(super) steps_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) steps_list_box: TemplateChild<gtk::ListBox>,
     }
 
     #[glib::object_subclass]
@@ -28,6 +34,7 @@ mod imp {
 
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
+            klass.bind_template_callbacks();
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -48,12 +55,54 @@ mod imp {
             self.derived_property(id, pspec)
         }
 
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let Some(action) = self.obj().action() else {
+                return;
+            };
+            let steps = action.steps();
+
+            self.steps_list_box.bind_model(Some(&steps), |item| {
+                view::ImageBuildStepRow::from(item.downcast_ref::<model::ImageBuildStep>().unwrap())
+                    .upcast()
+            });
+
+            let steps_group = &*self.steps_group;
+            steps_group.set_visible(steps.n_items() > 0);
+            steps.connect_items_changed(clone!(
+                #[weak]
+                steps_group,
+                move |steps, _, _, _| steps_group.set_visible(steps.n_items() > 0)
+            ));
+        }
+
         fn dispose(&self) {
             utils::unparent_children(&*self.obj());
         }
     }
 
     impl WidgetImpl for ImageBuildActionView {}
+
+    #[gtk::template_callbacks]
+    impl ImageBuildActionView {
+        #[template_callback]
+        fn is_positive(&self, files: u32) -> bool {
+            files > 0
+        }
+
+        #[template_callback]
+        fn format_context(&self, files: u32, size: u64) -> String {
+            ngettext!(
+                // Translators: "{}" are placeholders for the number of files and storage space.
+                "{} file, {}",
+                "{} files, {}",
+                files,
+                files,
+                glib::format_size(size),
+            )
+        }
+    }
 }
 
 glib::wrapper! {
@@ -15,10 +15,15 @@ use crate::engine;
 use crate::model;
 use crate::utils;
 use crate::view;
+use crate::widget;
 
 const ACTION_BUILD: &str = "image-build-opts-dialog.build";
 const ACTION_SELECT_CONTEXT_DIR: &str = "image-build-opts-dialog.select-context-dir";
 const ACTION_ADD_LABEL: &str = "image-build-opts-dialog.add-label";
+const ACTION_ADD_BUILD_ARG: &str = "image-build-opts-dialog.add-build-arg";
+const ACTION_ADD_SECRET: &str = "image-build-opts-dialog.add-secret";
+const ACTION_ADD_SSH: &str = "image-build-opts-dialog.add-ssh";
+const ACTION_ADD_TAG: &str = "image-build-opts-dialog.add-tag";
 
 mod imp {
     use super::*;
@@ -28,6 +33,10 @@ mod imp {
     #[template(resource = "/com/github/marhkb/Pods/ui/view/image_build_opts_dialog.ui")]
     pub(crate) struct ImageBuildOptsDialog {
         pub(super) labels: OnceCell<gio::ListStore>,
+        pub(super) build_args: OnceCell<gio::ListStore>,
+        pub(super) secrets: OnceCell<gio::ListStore>,
+        pub(super) ssh: OnceCell<gio::ListStore>,
+        pub(super) tags: OnceCell<gio::ListStore>,
 
         #[property(get, set, construct_only, nullable)]
         pub(super) client: glib::WeakRef<model::Client>,
@@ -43,6 +52,22 @@ mod imp {
         #[template_child]
         pub(super) container_file_path_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
+        pub(super) target_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) platform_entry_row: TemplateChild<widget::PlatformEntryRow>,
+        #[template_child]
+        pub(super) no_cache_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) pull_policy_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) build_args_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) secrets_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) ssh_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) tags_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
         pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
     }
 
@@ -71,6 +96,18 @@ mod imp {
             klass.install_action(ACTION_ADD_LABEL, None, |widget, _, _| {
                 widget.add_label();
             });
+            klass.install_action(ACTION_ADD_BUILD_ARG, None, |widget, _, _| {
+                widget.add_build_arg();
+            });
+            klass.install_action(ACTION_ADD_SECRET, None, |widget, _, _| {
+                widget.add_secret();
+            });
+            klass.install_action(ACTION_ADD_SSH, None, |widget, _, _| {
+                widget.add_ssh();
+            });
+            klass.install_action(ACTION_ADD_TAG, None, |widget, _, _| {
+                widget.add_tag(None);
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -114,10 +151,128 @@ mod imp {
                     .build(),
             );
 
+            self.build_args_list_box
+                .bind_model(Some(self.build_args()), |item| {
+                    view::KeyValRow::from(item.downcast_ref::<model::KeyVal>().unwrap()).upcast()
+                });
+            self.build_args_list_box.append(
+                &gtk::ListBoxRow::builder()
+                    .action_name(ACTION_ADD_BUILD_ARG)
+                    .selectable(false)
+                    .child(
+                        &gtk::Label::builder()
+                            .label(gettext("Add Build Argument"))
+                            .margin_top(12)
+                            .margin_bottom(12)
+                            .build(),
+                    )
+                    .build(),
+            );
+
+            self.secrets_list_box
+                .bind_model(Some(self.secrets()), |item| {
+                    view::KeyValRow::new(
+                        &gettext("ID"),
+                        &gettext("File Path"),
+                        item.downcast_ref::<model::KeyVal>().unwrap(),
+                    )
+                    .upcast()
+                });
+            self.secrets_list_box.append(
+                &gtk::ListBoxRow::builder()
+                    .action_name(ACTION_ADD_SECRET)
+                    .selectable(false)
+                    .child(
+                        &gtk::Label::builder()
+                            .label(gettext("Add Secret"))
+                            .margin_top(12)
+                            .margin_bottom(12)
+                            .build(),
+                    )
+                    .build(),
+            );
+
+            self.ssh_list_box.bind_model(Some(self.ssh()), |item| {
+                view::KeyValRow::new(
+                    &gettext("ID"),
+                    &gettext("Socket or Key Paths"),
+                    item.downcast_ref::<model::KeyVal>().unwrap(),
+                )
+                .upcast()
+            });
+            self.ssh_list_box.append(
+                &gtk::ListBoxRow::builder()
+                    .action_name(ACTION_ADD_SSH)
+                    .selectable(false)
+                    .child(
+                        &gtk::Label::builder()
+                            .label(gettext("Add SSH Agent"))
+                            .margin_top(12)
+                            .margin_bottom(12)
+                            .build(),
+                    )
+                    .build(),
+            );
+
+            self.tags_list_box.bind_model(Some(self.tags()), |item| {
+                view::ValueRow::new(item.downcast_ref().unwrap(), &gettext("Tag")).upcast()
+            });
+            self.tags_list_box.append(
+                &gtk::ListBoxRow::builder()
+                    .action_name(ACTION_ADD_TAG)
+                    .selectable(false)
+                    .child(
+                        &gtk::Label::builder()
+                            .label(gettext("Add Tag"))
+                            .margin_top(12)
+                            .margin_bottom(12)
+                            .build(),
+                    )
+                    .build(),
+            );
+
             let opts = obj.opts();
 
-            self.image_name_entry_row.set_text(&opts.tag);
+            let mut tags = opts.tags.iter();
+            self.image_name_entry_row
+                .set_text(tags.next().map(String::as_str).unwrap_or_default());
+            tags.for_each(|tag| {
+                obj.add_tag(Some(tag.as_str().into()));
+            });
             self.context_dir_row.set_subtitle(&opts.path);
+            self.container_file_path_entry_row
+                .set_text(&opts.dockerfile);
+            self.target_entry_row
+                .set_text(opts.target.as_deref().unwrap_or_default());
+            self.platform_entry_row.set_platform(opts.platform.as_ref());
+            self.no_cache_switch_row.set_active(opts.no_cache);
+            self.pull_policy_row.set_selected(match opts.pull_policy {
+                engine::opts::ImageBuildPullPolicy::Missing => 0,
+                engine::opts::ImageBuildPullPolicy::Always => 1,
+                engine::opts::ImageBuildPullPolicy::Newer => 2,
+                engine::opts::ImageBuildPullPolicy::Never => 3,
+            });
+            opts.build_args.iter().for_each(|(key, value)| {
+                let build_arg = obj.add_build_arg();
+                build_arg.set_key(key.to_owned());
+                build_arg.set_value(value.to_owned());
+            });
+            opts.secrets.iter().for_each(|secret| {
+                let entry = obj.add_secret();
+                entry.set_key(secret.id.clone());
+                entry.set_value(secret.path.to_string_lossy().into_owned());
+            });
+            opts.ssh.iter().for_each(|ssh| {
+                let entry = obj.add_ssh();
+                entry.set_key(ssh.id.clone());
+                entry.set_value(
+                    ssh.paths
+                        .iter()
+                        .map(|path| path.to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                );
+            });
             opts.labels.iter().for_each(|(key, value)| {
                 let label = obj.add_label();
                 label.set_key(key.to_owned());
@@ -155,6 +310,24 @@ mod imp {
             self.labels
                 .get_or_init(gio::ListStore::new::<model::KeyVal>)
         }
+
+        pub(super) fn build_args(&self) -> &gio::ListStore {
+            self.build_args
+                .get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+
+        pub(super) fn secrets(&self) -> &gio::ListStore {
+            self.secrets
+                .get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+
+        pub(super) fn ssh(&self) -> &gio::ListStore {
+            self.ssh.get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+
+        pub(super) fn tags(&self) -> &gio::ListStore {
+            self.tags.get_or_init(gio::ListStore::new::<model::Value>)
+        }
     }
 }
 
@@ -191,6 +364,82 @@ impl ImageBuildOptsDialog {
         label
     }
 
+    pub(crate) fn add_build_arg(&self) -> model::KeyVal {
+        let build_arg = model::KeyVal::default();
+
+        build_arg.connect_remove_request(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |build_arg| {
+                let build_args = obj.imp().build_args();
+                if let Some(pos) = build_args.find(build_arg) {
+                    build_args.remove(pos);
+                }
+            }
+        ));
+
+        self.imp().build_args().append(&build_arg);
+
+        build_arg
+    }
+
+    pub(crate) fn add_secret(&self) -> model::KeyVal {
+        let secret = model::KeyVal::default();
+
+        secret.connect_remove_request(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |secret| {
+                let secrets = obj.imp().secrets();
+                if let Some(pos) = secrets.find(secret) {
+                    secrets.remove(pos);
+                }
+            }
+        ));
+
+        self.imp().secrets().append(&secret);
+
+        secret
+    }
+
+    pub(crate) fn add_ssh(&self) -> model::KeyVal {
+        let ssh = model::KeyVal::default();
+
+        ssh.connect_remove_request(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |ssh| {
+                let ssh_list = obj.imp().ssh();
+                if let Some(pos) = ssh_list.find(ssh) {
+                    ssh_list.remove(pos);
+                }
+            }
+        ));
+
+        self.imp().ssh().append(&ssh);
+
+        ssh
+    }
+
+    pub(crate) fn add_tag(&self, tag: Option<model::Value>) -> model::Value {
+        let tag = tag.unwrap_or_default();
+
+        tag.connect_remove_request(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |tag| {
+                let tags = obj.imp().tags();
+                if let Some(pos) = tags.find(tag) {
+                    tags.remove(pos);
+                }
+            }
+        ));
+
+        self.imp().tags().append(&tag);
+
+        tag
+    }
+
     async fn choose_context_dir(&self) {
         let request = OpenFileRequest::default()
             .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
@@ -231,7 +480,16 @@ impl ImageBuildOptsDialog {
             return;
         };
 
+        let target = imp.target_entry_row.text();
+
         let opts = engine::opts::ImageBuildOpts {
+            build_args: imp
+                .build_args()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .filter(|entry| !entry.key().is_empty())
+                .map(|entry| (entry.key(), entry.value()))
+                .collect(),
             dockerfile: imp.container_file_path_entry_row.text().into(),
             labels: imp
                 .labels()
@@ -239,8 +497,52 @@ impl ImageBuildOptsDialog {
                 .map(Result::unwrap)
                 .map(|entry| (entry.key(), entry.value()))
                 .collect(),
+            no_cache: imp.no_cache_switch_row.is_active(),
             path: path.into(),
-            tag: imp.image_name_entry_row.text().into(),
+            platform: imp.platform_entry_row.platform(),
+            pull_policy: match imp.pull_policy_row.selected() {
+                1 => engine::opts::ImageBuildPullPolicy::Always,
+                2 => engine::opts::ImageBuildPullPolicy::Newer,
+                3 => engine::opts::ImageBuildPullPolicy::Never,
+                _ => engine::opts::ImageBuildPullPolicy::Missing,
+            },
+            secrets: imp
+                .secrets()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .filter(|entry| !entry.key().trim().is_empty() && !entry.value().trim().is_empty())
+                .map(|entry| engine::opts::ImageBuildSecret {
+                    id: entry.key().trim().to_owned(),
+                    path: entry.value().trim().into(),
+                })
+                .collect(),
+            ssh: imp
+                .ssh()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .map(|entry| engine::opts::ImageBuildSsh {
+                    id: Some(entry.key().trim().to_owned())
+                        .filter(|id| !id.is_empty())
+                        .unwrap_or_else(|| "default".to_owned()),
+                    paths: entry
+                        .value()
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|path| !path.is_empty())
+                        .map(Into::into)
+                        .collect(),
+                })
+                .collect(),
+            tags: std::iter::once(imp.image_name_entry_row.text().trim().to_owned())
+                .chain(
+                    imp.tags()
+                        .iter::<model::Value>()
+                        .map(Result::unwrap)
+                        .map(|tag| tag.value().trim().to_owned()),
+                )
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+            target: Some(target.trim().to_owned()).filter(|target| !target.is_empty()),
         };
 
         view::ActionDialog::from(&action_list.build_image(opts)).present(Some(self));
@@ -0,0 +1,152 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::widget;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImageBuildStepRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_build_step_row.ui")]
+    pub(crate) struct ImageBuildStepRow {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) step: glib::WeakRef<model::ImageBuildStep>,
+        #[template_child]
+        pub(super) action_indicator: TemplateChild<widget::ActionStateIndicator>,
+        #[template_child]
+        pub(super) duration_label: TemplateChild<gtk::Label>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageBuildStepRow {
+        const NAME: &'static str = "PdsImageBuildStepRow";
+        type Type = super::ImageBuildStepRow;
+        type ParentType = adw::ActionRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageBuildStepRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            let Some(step) = obj.step() else {
+                return;
+            };
+
+            obj.set_title(&match step.stage() {
+                Some(stage) => gettext!(
+                    // Translators: "{}" are placeholders for progress like "1/3".
+                    "Stage {} · Step {}/{}",
+                    stage,
+                    step.number(),
+                    step.total()
+                ),
+                None => gettext!("Step {}/{}", step.number(), step.total()),
+            });
+
+            let state_expr = Self::Type::this_expression("step")
+                .chain_property::<model::ImageBuildStep>("state");
+
+            state_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, state: model::ActionState| {
+                    match state {
+                        model::ActionState::Ongoing => "ongoing",
+                        model::ActionState::Cancelled => "cancelled",
+                        model::ActionState::Failed => "failed",
+                        model::ActionState::Finished => "finished",
+                    }
+                }))
+                .bind(&*self.action_indicator, "action-state-name", Some(obj));
+
+            step.connect_state_notify(clone!(
+                #[weak]
+                obj,
+                move |step| obj.update_state(step)
+            ));
+            obj.update_state(&step);
+
+            glib::timeout_add_seconds_local(
+                1,
+                clone!(
+                    #[weak]
+                    obj,
+                    #[weak]
+                    step,
+                    #[upgrade_or]
+                    glib::ControlFlow::Break,
+                    move || {
+                        obj.update_duration(&step);
+                        if step.state() == model::ActionState::Ongoing {
+                            glib::ControlFlow::Continue
+                        } else {
+                            glib::ControlFlow::Break
+                        }
+                    }
+                ),
+            );
+        }
+    }
+
+    impl WidgetImpl for ImageBuildStepRow {}
+    impl ListBoxRowImpl for ImageBuildStepRow {}
+    impl PreferencesRowImpl for ImageBuildStepRow {}
+    impl ActionRowImpl for ImageBuildStepRow {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageBuildStepRow(ObjectSubclass<imp::ImageBuildStepRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ActionRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::ImageBuildStep> for ImageBuildStepRow {
+    fn from(step: &model::ImageBuildStep) -> Self {
+        glib::Object::builder().property("step", step).build()
+    }
+}
+
+impl ImageBuildStepRow {
+    fn update_state(&self, step: &model::ImageBuildStep) {
+        if step.state() == model::ActionState::Failed {
+            self.add_css_class("error");
+        } else {
+            self.remove_css_class("error");
+        }
+        self.update_duration(step);
+    }
+
+    fn update_duration(&self, step: &model::ImageBuildStep) {
+        self.imp()
+            .duration_label
+            .set_label(&utils::human_friendly_duration(step.duration() / 1_000_000));
+    }
+}
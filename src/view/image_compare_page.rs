@@ -0,0 +1,430 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use gtk::CompositeTemplate;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::pango;
+
+use crate::engine;
+use crate::model;
+use crate::rt;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_compare_page.ui")]
+    pub(crate) struct ImageComparePage {
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) spinner: TemplateChild<adw::Spinner>,
+        #[template_child]
+        pub(super) images_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) sizes_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) config_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) env_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) labels_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) layers_group: TemplateChild<adw::PreferencesGroup>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageComparePage {
+        const NAME: &'static str = "PdsImageComparePage";
+        type Type = super::ImageComparePage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageComparePage {
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ImageComparePage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageComparePage(ObjectSubclass<imp::ImageComparePage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+/// The data of one of the compared images.
+struct Snapshot {
+    inspection: engine::dto::ImageInspection,
+    /// history entries with the oldest layer first
+    history: Vec<engine::dto::ImageHistoryEntry>,
+}
+
+impl ImageComparePage {
+    pub(crate) fn new(left: &model::Image, right: &model::Image) -> Self {
+        let obj = glib::Object::builder::<Self>().build();
+        let imp = obj.imp();
+
+        let left_name = image_name(left);
+        let right_name = image_name(right);
+
+        imp.window_title
+            .set_subtitle(&format!("{left_name} ↔ {right_name}"));
+        imp.images_group.add(&columns_row(
+            &gettext("Name"),
+            value_label(Some(&left_name), None),
+            value_label(Some(&right_name), None),
+        ));
+        imp.images_group.add(&columns_row(
+            &gettext("Id"),
+            value_label(Some(utils::format_id(&left.id())), None),
+            value_label(Some(utils::format_id(&right.id())), None),
+        ));
+
+        let (Some(left), Some(right)) = (left.api(), right.api()) else {
+            return obj;
+        };
+
+        rt::Promise::new(async move { futures::try_join!(snapshot(left), snapshot(right)) }).defer(
+            clone!(
+                #[weak]
+                obj,
+                move |result| match result {
+                    Ok((left, right)) => obj.set_snapshots(left, right),
+                    Err(e) => {
+                        log::error!("Error on comparing images: {e}");
+
+                        obj.imp().spinner.set_visible(false);
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on comparing images"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+            ),
+        );
+
+        obj
+    }
+
+    fn set_snapshots(&self, left: Snapshot, right: Snapshot) {
+        let imp = self.imp();
+
+        self.set_sizes(&left, &right);
+
+        let left_details = &left.inspection.details;
+        let right_details = &right.inspection.details;
+
+        [
+            (
+                gettext("Platform"),
+                platform(left_details),
+                platform(right_details),
+            ),
+            (
+                gettext("Entrypoint"),
+                left_details.entrypoint.clone(),
+                right_details.entrypoint.clone(),
+            ),
+            (
+                gettext("Command"),
+                left_details.cmd.clone(),
+                right_details.cmd.clone(),
+            ),
+            (
+                gettext("Exposed Ports"),
+                exposed_ports(left_details),
+                exposed_ports(right_details),
+            ),
+            (
+                gettext("Author"),
+                left_details.author.clone(),
+                right_details.author.clone(),
+            ),
+        ]
+        .into_iter()
+        .for_each(|(title, left, right)| {
+            imp.config_group
+                .add(&compare_row(&title, left.as_deref(), right.as_deref()));
+        });
+
+        add_map_rows(
+            &imp.env_group,
+            &env_vars(left_details),
+            &env_vars(right_details),
+        );
+        add_map_rows(
+            &imp.labels_group,
+            &labels(left_details),
+            &labels(right_details),
+        );
+
+        (0..left.history.len().max(right.history.len())).for_each(|index| {
+            let left = left.history.get(index).map(layer_description);
+            let right = right.history.get(index).map(layer_description);
+
+            imp.layers_group.add(&compare_row(
+                &gettext!("Layer {}", index + 1),
+                left.as_deref(),
+                right.as_deref(),
+            ));
+        });
+
+        imp.stack.set_visible_child_name("loaded");
+    }
+
+    fn set_sizes(&self, left: &Snapshot, right: &Snapshot) {
+        let imp = self.imp();
+
+        let left_size = left.inspection.summary.size;
+        let right_size = right.inspection.summary.size;
+
+        imp.sizes_group.add(&compare_row(
+            &gettext("Total Size"),
+            Some(&glib::format_size(left_size)),
+            Some(&glib::format_size(right_size)),
+        ));
+
+        let size_difference_row = adw::ActionRow::builder()
+            .title(gettext("Size Difference"))
+            .subtitle(match right_size.cmp(&left_size) {
+                std::cmp::Ordering::Equal => gettext("None"),
+                std::cmp::Ordering::Greater => {
+                    format!("+{}", glib::format_size(right_size - left_size))
+                }
+                std::cmp::Ordering::Less => {
+                    format!("−{}", glib::format_size(left_size - right_size))
+                }
+            })
+            .css_classes(vec!["property".to_string()])
+            .build();
+        imp.sizes_group.add(&size_difference_row);
+
+        // The images share the layers they have in common from the bottom up.
+        let shared = left
+            .inspection
+            .details
+            .layers
+            .iter()
+            .zip(&right.inspection.details.layers)
+            .take_while(|(left, right)| left == right)
+            .count();
+        // History entries without a size are metadata instructions that do not create a layer.
+        let shared_size = left
+            .history
+            .iter()
+            .filter_map(|entry| entry.size)
+            .filter(|size| *size > 0)
+            .take(shared)
+            .sum();
+
+        let shared_size_row = adw::ActionRow::builder()
+            .title(gettext("Shared Base Layers"))
+            .subtitle(format!(
+                "{}, {}",
+                ngettext!("{} layer", "{} layers", shared as u32, shared),
+                glib::format_size(shared_size),
+            ))
+            .css_classes(vec!["property".to_string()])
+            .build();
+        imp.sizes_group.add(&shared_size_row);
+    }
+}
+
+async fn snapshot(api: engine::api::Image) -> anyhow::Result<Snapshot> {
+    let (inspection, mut history) = futures::try_join!(api.inspect(), api.history())?;
+    // The engines report the most recent layer first.
+    history.reverse();
+
+    Ok(Snapshot {
+        inspection,
+        history,
+    })
+}
+
+fn image_name(image: &model::Image) -> String {
+    image
+        .repo_tags()
+        .get(0)
+        .map(|repo_tag| repo_tag.full())
+        .unwrap_or_else(|| utils::format_id(&image.id()).to_owned())
+}
+
+fn platform(details: &engine::dto::ImageDetails) -> Option<String> {
+    Some(
+        engine::opts::Platform {
+            os: details.os.clone().filter(|os| !os.is_empty())?,
+            arch: details
+                .architecture
+                .clone()
+                .filter(|arch| !arch.is_empty())?,
+            variant: details
+                .variant
+                .clone()
+                .filter(|variant| !variant.is_empty()),
+        }
+        .to_string(),
+    )
+}
+
+fn exposed_ports(details: &engine::dto::ImageDetails) -> Option<String> {
+    let mut exposed_ports = details.exposed_ports.clone();
+    exposed_ports.sort();
+
+    Some(exposed_ports.join(", ")).filter(|exposed_ports| !exposed_ports.is_empty())
+}
+
+fn env_vars(details: &engine::dto::ImageDetails) -> BTreeMap<&str, &str> {
+    details
+        .env
+        .iter()
+        .map(|var| var.split_once('=').unwrap_or((var, "")))
+        .collect()
+}
+
+fn labels(details: &engine::dto::ImageDetails) -> BTreeMap<&str, &str> {
+    details
+        .labels
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect()
+}
+
+fn layer_description(entry: &engine::dto::ImageHistoryEntry) -> String {
+    format!(
+        "{}\n{}",
+        entry
+            .created_by
+            .as_deref()
+            .map(str::trim)
+            .unwrap_or_default(),
+        entry
+            .size
+            .map(|size| String::from(glib::format_size(size)))
+            .unwrap_or_else(|| gettext("Unknown size")),
+    )
+}
+
+/// Adds rows for the entries that differ and mentions the number of identical entries in the
+/// description of the group.
+fn add_map_rows(
+    group: &adw::PreferencesGroup,
+    left: &BTreeMap<&str, &str>,
+    right: &BTreeMap<&str, &str>,
+) {
+    let keys = left.keys().chain(right.keys()).collect::<BTreeSet<_>>();
+
+    let mut identical = 0;
+    keys.iter().for_each(|key| {
+        let left = left.get(*key).copied();
+        let right = right.get(*key).copied();
+
+        if left == right {
+            identical += 1;
+        } else {
+            group.add(&compare_row(key, left, right));
+        }
+    });
+
+    if keys.is_empty() {
+        group.set_description(Some(&gettext("None in both images")));
+    } else if identical > 0 {
+        group.set_description(Some(&ngettext!(
+            "{} identical entry not shown",
+            "{} identical entries not shown",
+            identical,
+            identical,
+        )));
+    }
+}
+
+/// Creates a row showing the values of both images next to each other. Removed values are
+/// highlighted as errors, added values as successes and changed values as warnings.
+fn compare_row(title: &str, left: Option<&str>, right: Option<&str>) -> adw::PreferencesRow {
+    let (left_class, right_class) = match (left, right) {
+        _ if left == right => (None, None),
+        (Some(_), None) => (Some("error"), None),
+        (None, Some(_)) => (None, Some("success")),
+        _ => (Some("warning"), Some("warning")),
+    };
+
+    columns_row(
+        title,
+        value_label(left, left_class),
+        value_label(right, right_class),
+    )
+}
+
+fn columns_row(title: &str, left: gtk::Label, right: gtk::Label) -> adw::PreferencesRow {
+    let columns = gtk::Box::builder().spacing(12).homogeneous(true).build();
+    columns.append(&left);
+    columns.append(&right);
+
+    let box_ = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(6)
+        .margin_top(9)
+        .margin_end(12)
+        .margin_bottom(9)
+        .margin_start(12)
+        .build();
+    box_.append(
+        &gtk::Label::builder()
+            .label(title)
+            .xalign(0.0)
+            .wrap(true)
+            .wrap_mode(pango::WrapMode::WordChar)
+            .css_classes(vec!["heading".to_string()])
+            .build(),
+    );
+    box_.append(&columns);
+
+    adw::PreferencesRow::builder()
+        .activatable(false)
+        .child(&box_)
+        .build()
+}
+
+fn value_label(value: Option<&str>, css_class: Option<&str>) -> gtk::Label {
+    let label = gtk::Label::builder()
+        .label(
+            value
+                .map(str::to_owned)
+                .unwrap_or_else(|| gettext("<None>")),
+        )
+        .single_line_mode(false)
+        .xalign(0.0)
+        .yalign(0.0)
+        .wrap(true)
+        .wrap_mode(pango::WrapMode::WordChar)
+        .selectable(true)
+        .build();
+
+    if value.is_none() {
+        label.add_css_class("dim-label");
+    }
+    if let Some(css_class) = css_class {
+        label.add_css_class(css_class);
+    }
+
+    label
+}
@@ -18,6 +18,8 @@ use crate::view;
 const ACTION_TAG: &str = "image-details-page.tag";
 const ACTION_INSPECT_IMAGE: &str = "image-details-page.inspect-image";
 const ACTION_SHOW_HISTORY: &str = "image-details-page.show-history";
+const ACTION_SHOW_LAYERS: &str = "image-details-page.show-layers";
+const ACTION_SAVE_IMAGE: &str = "image-details-page.save-image";
 const ACTION_DELETE_IMAGE: &str = "image-details-page.delete-image";
 
 mod imp {
@@ -44,6 +46,8 @@ mod imp {
         #[template_child]
         pub(super) size_row: TemplateChild<adw::ActionRow>,
         #[template_child]
+        pub(super) platform_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
         pub(super) command_row: TemplateChild<adw::ActionRow>,
         #[template_child]
         pub(super) entrypoint_row: TemplateChild<adw::ActionRow>,
@@ -74,6 +78,14 @@ mod imp {
                 widget.show_history().await;
             });
 
+            klass.install_action_async(ACTION_SHOW_LAYERS, None, |widget, _, _| async move {
+                widget.show_layers().await;
+            });
+
+            klass.install_action_async(ACTION_SAVE_IMAGE, None, |widget, _, _| async move {
+                widget.save_image().await;
+            });
+
             klass.install_action_async(ACTION_DELETE_IMAGE, None, |widget, _, _| async move {
                 widget.delete_image().await;
             });
@@ -123,6 +135,8 @@ mod imp {
                     utils::format_id(id).to_owned()
                 }));
             let image_details_expr = image_expr.chain_property::<model::Image>("details");
+            let platform_expr =
+                image_details_expr.chain_property::<model::ImageDetails>("platform");
             let cmd_expr = image_details_expr.chain_property::<model::ImageDetails>("cmd");
             let entrypoint_expr =
                 image_details_expr.chain_property::<model::ImageDetails>("entrypoint");
@@ -221,6 +235,13 @@ mod imp {
             )
             .bind(&*self.size_row, "subtitle", Some(obj));
 
+            platform_expr.bind(&*self.platform_row, "subtitle", Some(obj));
+            platform_expr
+                .chain_closure::<bool>(closure!(|_: Self::Type, platform: Option<&str>| {
+                    platform.is_some()
+                }))
+                .bind(&*self.platform_row, "visible", Some(obj));
+
             cmd_expr.bind(&*self.command_row, "subtitle", Some(obj));
             cmd_expr
                 .chain_closure::<bool>(closure!(|_: Self::Type, cmd: Option<&str>| {
@@ -393,6 +414,28 @@ impl ImageDetailsPage {
         .await;
     }
 
+    async fn show_layers(&self) {
+        self.exec_action(async || {
+            if let Some(image) = self.image() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ImageLayersPage::from(&image))
+                        .build(),
+                );
+            }
+        })
+        .await;
+    }
+
+    async fn save_image(&self) {
+        self.exec_action(async || {
+            if let Some(image) = self.image() {
+                view::image::save_images(self, &[image]);
+            }
+        })
+        .await;
+    }
+
     async fn delete_image(&self) {
         self.exec_action(async || {
             view::image::delete_image_show_confirmation(self, self.image());
@@ -0,0 +1,77 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImageImportActionView)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_import_action_view.ui")]
+    pub(crate) struct ImageImportActionView {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) action: glib::WeakRef<model::ImageImportAction>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageImportActionView {
+        const NAME: &'static str = "PdsImageImportActionView";
+        type Type = super::ImageImportActionView;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageImportActionView {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ImageImportActionView {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageImportActionView(ObjectSubclass<imp::ImageImportActionView>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::ImageImportAction> for view::ActionDialog {
+    fn from(value: &model::ImageImportAction) -> Self {
+        Self::new(
+            value.upcast_ref(),
+            &gettext("Import Image"),
+            Some(&value.host_path()),
+            &glib::Object::builder::<ImageImportActionView>()
+                .property("action", value)
+                .build(),
+            400,
+        )
+    }
+}
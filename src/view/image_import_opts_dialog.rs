@@ -0,0 +1,258 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_HOST_PATH: &str = "image-import-opts-dialog.select-host-path";
+const ACTION_ADD_CHANGE: &str = "image-import-opts-dialog.add-change";
+const ACTION_IMPORT: &str = "image-import-opts-dialog.import";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImageImportOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_import_opts_dialog.ui")]
+    pub(crate) struct ImageImportOptsDialog {
+        pub(super) changes: OnceCell<gio::ListStore>,
+
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedImageImportOpts>,
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) repo_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) tag_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) message_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) changes_list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageImportOptsDialog {
+        const NAME: &'static str = "PdsImageImportOptsDialog";
+        type Type = super::ImageImportOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+            klass.install_action(ACTION_ADD_CHANGE, None, |widget, _, _| {
+                widget.add_change(None);
+            });
+            klass.install_action(ACTION_IMPORT, None, |widget, _, _| {
+                widget.close_and_import();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageImportOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.changes_list_box
+                .bind_model(Some(self.changes()), |item| {
+                    view::ValueRow::new(item.downcast_ref().unwrap(), &gettext("Change")).upcast()
+                });
+            self.changes_list_box.append(
+                &gtk::ListBoxRow::builder()
+                    .action_name(ACTION_ADD_CHANGE)
+                    .selectable(false)
+                    .child(
+                        &gtk::Image::builder()
+                            .icon_name("list-add-symbolic")
+                            .margin_top(12)
+                            .margin_bottom(12)
+                            .build(),
+                    )
+                    .build(),
+            );
+
+            let opts = obj.opts();
+
+            self.repo_entry_row
+                .set_text(opts.repo.as_deref().unwrap_or_default());
+            self.tag_entry_row
+                .set_text(opts.tag.as_deref().unwrap_or_default());
+            self.message_entry_row
+                .set_text(opts.message.as_deref().unwrap_or_default());
+
+            opts.changes.iter().for_each(|change| {
+                obj.add_change(Some(change.as_str().into()));
+            });
+
+            self.host_path_row.set_subtitle(&obj.host_path());
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for ImageImportOptsDialog {}
+    impl AdwDialogImpl for ImageImportOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl ImageImportOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = self
+                .host_path_row
+                .subtitle()
+                .map(|s| !s.is_empty())
+                .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_IMPORT, enabled);
+        }
+    }
+
+    impl ImageImportOptsDialog {
+        pub(super) fn changes(&self) -> &gio::ListStore {
+            self.changes
+                .get_or_init(gio::ListStore::new::<model::Value>)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageImportOptsDialog(ObjectSubclass<imp::ImageImportOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl ImageImportOptsDialog {
+    pub(crate) fn new(
+        client: &model::Client,
+        opts: Option<model::BoxedImageImportOpts>,
+        host_path: &str,
+    ) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("opts", opts.unwrap_or_default())
+            .property("host-path", host_path)
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Filesystem Archive").as_str())
+            .filter(FileFilter::new("Tar Archive").mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_import(&self) {
+        self.close();
+
+        let Some(action_list) = self.client().map(|client| client.action_list()) else {
+            return;
+        };
+
+        let Some(host_path) = self.imp().host_path_row.subtitle() else {
+            return;
+        };
+
+        view::ActionDialog::from(&action_list.import_image(self.create_opts(), host_path.as_str()))
+            .present(Some(self));
+    }
+
+    fn create_opts(&self) -> engine::opts::ImageImportOpts {
+        let imp = self.imp();
+
+        engine::opts::ImageImportOpts {
+            changes: imp
+                .changes()
+                .iter::<model::Value>()
+                .map(Result::unwrap)
+                .map(|change| change.value())
+                .filter(|change| !change.is_empty())
+                .collect(),
+            message: extract_option(&imp.message_entry_row),
+            repo: extract_option(&imp.repo_entry_row),
+            tag: extract_option(&imp.tag_entry_row),
+        }
+    }
+
+    fn add_change(&self, change: Option<model::Value>) -> model::Value {
+        let change = change.unwrap_or_default();
+
+        change.connect_remove_request(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |change| {
+                let changes = obj.imp().changes();
+                if let Some(pos) = changes.find(change) {
+                    changes.remove(pos);
+                }
+            }
+        ));
+
+        self.imp().changes().append(&change);
+
+        change
+    }
+}
+
+fn extract_option(row: &adw::EntryRow) -> Option<String> {
+    let text = row.text();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_owned())
+}
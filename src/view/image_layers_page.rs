@@ -0,0 +1,258 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use gtk::CompositeTemplate;
+use gtk::glib;
+use gtk::glib::clone;
+
+use crate::engine;
+use crate::model;
+use crate::rt;
+use crate::utils;
+
+/// Number of wasted files shown in the summary.
+const MAX_WASTED_FILES: usize = 10;
+/// Number of changes listed per layer to keep expanding large layers fast.
+const MAX_CHANGE_ROWS: usize = 500;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_layers_page.ui")]
+    pub(crate) struct ImageLayersPage {
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) spinner: TemplateChild<adw::Spinner>,
+        #[template_child]
+        pub(super) efficiency_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) total_size_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) wasted_size_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) wasted_files_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) layers_group: TemplateChild<adw::PreferencesGroup>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageLayersPage {
+        const NAME: &'static str = "PdsImageLayersPage";
+        type Type = super::ImageLayersPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageLayersPage {
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ImageLayersPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageLayersPage(ObjectSubclass<imp::ImageLayersPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Image> for ImageLayersPage {
+    fn from(image: &model::Image) -> Self {
+        let obj = glib::Object::builder::<Self>().build();
+        obj.imp()
+            .window_title
+            .set_subtitle(utils::format_id(&image.id()));
+
+        let Some(images) = image.image_list().and_then(|image_list| image_list.api()) else {
+            return obj;
+        };
+
+        rt::Promise::new({
+            let id = image.id();
+            async move { images.analyze_layers(id).await }
+        })
+        .defer(clone!(
+            #[weak]
+            obj,
+            move |result| match result {
+                Ok(analysis) => obj.set_analysis(analysis),
+                Err(e) => {
+                    log::error!("Error on analyzing image layers: {e}");
+
+                    obj.imp().spinner.set_visible(false);
+                    utils::show_error_toast(
+                        &obj,
+                        &gettext("Error on analyzing image layers"),
+                        &e.to_string(),
+                    );
+                }
+            }
+        ));
+
+        obj
+    }
+}
+
+impl ImageLayersPage {
+    fn set_analysis(&self, analysis: engine::dto::ImageLayerAnalysis) {
+        let imp = self.imp();
+
+        let efficiency = analysis.efficiency();
+        imp.efficiency_row
+            .set_subtitle(&format!("{:.1} %", efficiency * 100.0));
+        if efficiency < 0.9 {
+            imp.efficiency_row.add_css_class("warning");
+        }
+        imp.total_size_row
+            .set_subtitle(&glib::format_size(analysis.total_size));
+        imp.wasted_size_row
+            .set_subtitle(&glib::format_size(analysis.wasted_size));
+
+        imp.wasted_files_group
+            .set_visible(!analysis.wasted_files.is_empty());
+        analysis
+            .wasted_files
+            .iter()
+            .take(MAX_WASTED_FILES)
+            .for_each(|wasted_file| {
+                let row = adw::ActionRow::builder()
+                    .title(&wasted_file.path)
+                    .subtitle(ngettext!(
+                        "{} overwritten copy",
+                        "{} overwritten copies",
+                        wasted_file.occurrences,
+                        wasted_file.occurrences,
+                    ))
+                    .use_markup(false)
+                    .build();
+                row.add_suffix(&size_label(wasted_file.size));
+
+                imp.wasted_files_group.add(&row);
+            });
+
+        let layers = analysis.layers.len() as u32;
+        imp.layers_group
+            .set_description(Some(&ngettext!("{} layer", "{} layers", layers, layers)));
+
+        analysis.layers.into_iter().for_each(|layer| {
+            imp.layers_group.add(&layer_row(layer));
+        });
+
+        imp.stack.set_visible_child_name("loaded");
+    }
+}
+
+fn layer_row(layer: engine::dto::ImageLayer) -> adw::ExpanderRow {
+    let count = |kind| {
+        layer
+            .changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .count() as u32
+    };
+    let added = count(engine::dto::ImageLayerChangeKind::Added);
+    let modified = count(engine::dto::ImageLayerChangeKind::Modified);
+    let removed = count(engine::dto::ImageLayerChangeKind::Removed);
+
+    let row = adw::ExpanderRow::builder()
+        .title(
+            layer
+                .created_by
+                .as_deref()
+                .unwrap_or_else(|| utils::format_id(&layer.digest)),
+        )
+        .title_lines(2)
+        .subtitle(gettext!(
+            // Translators: "{}" are placeholders for numbers of files.
+            "{} added, {} modified, {} removed",
+            added,
+            modified,
+            removed,
+        ))
+        .use_markup(false)
+        .build();
+
+    row.add_suffix(&size_label(layer.size));
+    if layer.wasted_size > 0 {
+        let label = gtk::Label::builder()
+            .label(gettext!("{} wasted", glib::format_size(layer.wasted_size)))
+            .css_classes(["caption", "warning"])
+            .valign(gtk::Align::Center)
+            .build();
+        row.add_suffix(&label);
+    }
+
+    // Rows for the changes are only created once the layer is expanded.
+    let changes = std::cell::RefCell::new(Some(layer.changes));
+    row.connect_expanded_notify(move |row| {
+        let Some(changes) = changes.take() else {
+            return;
+        };
+
+        let len = changes.len();
+        changes
+            .into_iter()
+            .take(MAX_CHANGE_ROWS)
+            .for_each(|change| {
+                row.add_row(&change_row(change));
+            });
+
+        if len > MAX_CHANGE_ROWS {
+            let more = (len - MAX_CHANGE_ROWS) as u32;
+            row.add_row(
+                &adw::ActionRow::builder()
+                    .title(ngettext!("{} more change", "{} more changes", more, more))
+                    .css_classes(["dimmed"])
+                    .build(),
+            );
+        }
+    });
+
+    row
+}
+
+fn change_row(change: engine::dto::ImageLayerChange) -> adw::ActionRow {
+    let row = adw::ActionRow::builder()
+        .title(&change.path)
+        .subtitle(match change.kind {
+            engine::dto::ImageLayerChangeKind::Added => gettext("Added"),
+            engine::dto::ImageLayerChangeKind::Modified => gettext("Modified"),
+            engine::dto::ImageLayerChangeKind::Removed => gettext("Removed"),
+        })
+        .use_markup(false)
+        .build();
+
+    if change.overwritten {
+        row.add_css_class("warning");
+        row.set_tooltip_text(Some(&gettext(
+            "This file is overwritten or removed in a later layer",
+        )));
+    }
+    if change.kind != engine::dto::ImageLayerChangeKind::Removed {
+        row.add_suffix(&size_label(change.size));
+    }
+
+    row
+}
+
+fn size_label(size: u64) -> gtk::Label {
+    gtk::Label::builder()
+        .label(glib::format_size(size))
+        .css_classes(["dimmed"])
+        .valign(gtk::Align::Center)
+        .build()
+}
@@ -5,10 +5,13 @@ use glib::Properties;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
+use crate::engine;
 use crate::model;
 use crate::utils;
 use crate::view;
 
+const ACTION_LOGIN: &str = "image-pull-action-view.login";
+
 mod imp {
     use super::*;
 
@@ -28,6 +31,8 @@ mod imp {
 
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
+
+            klass.install_action(ACTION_LOGIN, None, |widget, _, _| widget.login());
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -62,6 +67,22 @@ glib::wrapper! {
         @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
 }
 
+impl ImagePullActionView {
+    fn login(&self) {
+        let Some(action) = self.action() else {
+            return;
+        };
+        let Some(client) = action
+            .action_list()
+            .and_then(|action_list| action_list.client())
+        else {
+            return;
+        };
+
+        view::ImagePullOptsDialog::login(&client, (*action.opts()).clone()).present(Some(self));
+    }
+}
+
 impl From<&model::ImagePullAction> for view::ActionDialog {
     fn from(value: &model::ImagePullAction) -> Self {
         Self::new(
@@ -1,14 +1,21 @@
+use std::cell::Cell;
 use std::cell::OnceCell;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
 use crate::engine;
+use crate::keyring;
 use crate::model;
+use crate::rt;
+use crate::utils;
 use crate::view;
+use crate::widget;
 
 const ACTION_PULL: &str = "image-pull-opts-dialog.pull";
 
@@ -23,9 +30,32 @@ mod imp {
         pub(super) client: glib::WeakRef<model::Client>,
         #[property(get, set, construct_only)]
         pub(super) opts: OnceCell<model::BoxedImagePullOpts>,
+        /// prompt for credentials because the registry denied a previous pull
+        #[property(get, set, construct_only)]
+        pub(super) login: Cell<bool>,
 
+        #[template_child]
+        pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
         #[template_child]
         pub(super) image_suggestion_entry_row: TemplateChild<view::ImageSuggestionEntryRow>,
+        #[template_child]
+        pub(super) platform_entry_row: TemplateChild<widget::PlatformEntryRow>,
+        #[template_child]
+        pub(super) tls_verify_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) login_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) login_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) auth_toggle_group: TemplateChild<adw::ToggleGroup>,
+        #[template_child]
+        pub(super) username_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) password_entry_row: TemplateChild<adw::PasswordEntryRow>,
+        #[template_child]
+        pub(super) token_entry_row: TemplateChild<adw::PasswordEntryRow>,
+        #[template_child]
+        pub(super) save_credentials_switch_row: TemplateChild<adw::SwitchRow>,
     }
 
     #[glib::object_subclass]
@@ -64,15 +94,52 @@ mod imp {
         fn constructed(&self) {
             self.parent_constructed();
 
-            self.image_suggestion_entry_row
-                .set_text(&self.obj().opts().reference);
+            let obj = &*self.obj();
+
+            self.auth_toggle_group.connect_active_name_notify(clone!(
+                #[weak]
+                obj,
+                move |toggle_group| {
+                    let is_basic_enabled = toggle_group.active_name().as_deref() == Some("basic");
+
+                    let imp = obj.imp();
+                    imp.username_entry_row.set_visible(is_basic_enabled);
+                    imp.password_entry_row.set_visible(is_basic_enabled);
+                    imp.token_entry_row.set_visible(!is_basic_enabled);
+                }
+            ));
+
+            let opts = obj.opts();
+
+            self.image_suggestion_entry_row.set_text(&opts.reference);
+            self.platform_entry_row.set_platform(opts.platform.as_ref());
+            self.tls_verify_switch_row.set_active(opts.tls_verify);
+
+            match opts.credentials {
+                Some(ref credentials) => {
+                    self.login_switch.set_active(true);
+                    obj.set_credentials(credentials);
+                }
+                None => self.login_switch.set_active(obj.login()),
+            }
+
+            if obj.login() {
+                self.login_group.set_description(Some(&gettext(
+                    "The registry denied access. Please provide valid credentials.",
+                )));
+            }
         }
     }
 
     impl WidgetImpl for ImagePullOptsDialog {
         fn map(&self) {
             self.parent_map();
-            self.image_suggestion_entry_row.grab_focus();
+
+            if self.obj().login() {
+                self.username_entry_row.grab_focus();
+            } else {
+                self.image_suggestion_entry_row.grab_focus();
+            }
         }
     }
 
@@ -92,6 +159,42 @@ mod imp {
         fn on_image_suggestion_entry_activated(&self) {
             self.obj().close_and_pull();
         }
+
+        #[template_callback]
+        fn on_login_switch_notify_active(&self) {
+            if !self.login_switch.is_active()
+                || !self.username_entry_row.text().is_empty()
+                || !self.token_entry_row.text().is_empty()
+            {
+                return;
+            }
+
+            let host =
+                engine::auth::registry_host(&self.image_suggestion_entry_row.text()).to_owned();
+
+            let obj = &*self.obj();
+            rt::Promise::new(async move { keyring::registry_credentials(&host).await }).defer(
+                clone!(
+                    #[weak]
+                    obj,
+                    move |result| match result {
+                        Ok(Some(credentials)) => {
+                            obj.set_credentials(&credentials);
+                            obj.imp().save_credentials_switch_row.set_active(true);
+                        }
+                        Ok(None) => {}
+                        Err(e) => {
+                            log::error!("Error on accessing keyring: {e}");
+                            utils::show_error_toast(
+                                &*obj.imp().toast_overlay,
+                                &gettext("Error on accessing keyring"),
+                                &e.to_string(),
+                            );
+                        }
+                    }
+                ),
+            );
+        }
     }
 }
 
@@ -109,6 +212,34 @@ impl ImagePullOptsDialog {
             .build()
     }
 
+    /// Creates a dialog asking for the credentials of the image's registry.
+    pub(crate) fn login(client: &model::Client, opts: engine::opts::ImagePullOpts) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("opts", model::BoxedImagePullOpts::from(opts))
+            .property("login", true)
+            .build()
+    }
+
+    fn set_credentials(&self, credentials: &engine::auth::Credentials) {
+        let imp = self.imp();
+
+        match credentials {
+            engine::auth::Credentials::BasicAuth { username, password } => {
+                imp.auth_toggle_group.set_active_name(Some("basic"));
+                imp.username_entry_row.set_text(username);
+                imp.password_entry_row.set_text(password);
+                imp.token_entry_row.set_text("");
+            }
+            engine::auth::Credentials::IdentityToken(token) => {
+                imp.auth_toggle_group.set_active_name(Some("token"));
+                imp.username_entry_row.set_text("");
+                imp.password_entry_row.set_text("");
+                imp.token_entry_row.set_text(token);
+            }
+        }
+    }
+
     fn close_and_pull(&self) {
         self.close();
 
@@ -116,12 +247,53 @@ impl ImagePullOptsDialog {
             return;
         };
 
+        view::ActionDialog::from(&action_list.pull_image(self.create_opts())).present(Some(self));
+    }
+
+    fn create_opts(&self) -> engine::opts::ImagePullOpts {
         let imp = self.imp();
 
-        let opts = engine::opts::ImagePullOpts {
-            reference: imp.image_suggestion_entry_row.text().into(),
+        let reference = imp.image_suggestion_entry_row.text().to_string();
+
+        let credentials = if imp.login_switch.is_active() {
+            let credentials = if imp.auth_toggle_group.active_name().as_deref() == Some("basic") {
+                engine::auth::Credentials::BasicAuth {
+                    username: imp.username_entry_row.text().into(),
+                    password: imp.password_entry_row.text().into(),
+                }
+            } else {
+                engine::auth::Credentials::IdentityToken(imp.token_entry_row.text().into())
+            };
+
+            if imp.save_credentials_switch_row.is_active() {
+                let host = engine::auth::registry_host(&reference).to_owned();
+
+                rt::Promise::new({
+                    let credentials = credentials.clone();
+                    async move { keyring::store_registry_credentials(&host, &credentials).await }
+                })
+                .defer(|result| {
+                    if let Err(e) = result {
+                        log::error!("Error on saving credentials: {e}");
+                        utils::show_error_toast(
+                            &utils::main_window(),
+                            &gettext("Error saving credentials"),
+                            &e.to_string(),
+                        );
+                    }
+                });
+            }
+
+            Some(credentials)
+        } else {
+            None
         };
 
-        view::ActionDialog::from(&action_list.pull_image(opts)).present(Some(self));
+        engine::opts::ImagePullOpts {
+            credentials,
+            platform: imp.platform_entry_row.platform(),
+            reference,
+            tls_verify: imp.tls_verify_switch_row.is_active(),
+        }
     }
 }
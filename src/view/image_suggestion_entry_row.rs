@@ -10,6 +10,7 @@ use gtk::CompositeTemplate;
 use gtk::gio;
 use gtk::glib;
 
+use crate::keyring;
 use crate::model;
 use crate::rt;
 use crate::utils;
@@ -118,7 +119,13 @@ mod imp {
             let result = rt::Promise::new({
                 let images = client.engine().images();
                 let term = term.clone().into();
-                async move { future::Abortable::new(images.search(term), abort_registration).await }
+                async move {
+                    future::Abortable::new(
+                        keyring::search_images(&images, term),
+                        abort_registration,
+                    )
+                    .await
+                }
             })
             .exec()
             .await;
@@ -0,0 +1,153 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_LOAD: &str = "images-load-opts-dialog.load";
+const ACTION_SELECT_HOST_PATH: &str = "images-load-opts-dialog.select-host-path";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImagesLoadOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/images_load_opts_dialog.ui")]
+    pub(crate) struct ImagesLoadOptsDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImagesLoadOptsDialog {
+        const NAME: &'static str = "PdsImagesLoadOptsDialog";
+        type Type = super::ImagesLoadOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_LOAD, None, |widget, _, _| {
+                widget.close_and_load();
+            });
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImagesLoadOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            self.host_path_row.set_subtitle(&self.obj().host_path());
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for ImagesLoadOptsDialog {}
+    impl AdwDialogImpl for ImagesLoadOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl ImagesLoadOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = self
+                .host_path_row
+                .subtitle()
+                .map(|s| !s.is_empty())
+                .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_LOAD, enabled);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImagesLoadOptsDialog(ObjectSubclass<imp::ImagesLoadOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl ImagesLoadOptsDialog {
+    pub(crate) fn new(client: &model::Client, host_path: &str) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("host-path", host_path)
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Image Archive").as_str())
+            .filter(FileFilter::new("Tar Archive").mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_load(&self) {
+        self.close();
+
+        let Some(action_list) = self.client().map(|client| client.action_list()) else {
+            return;
+        };
+
+        let Some(host_path) = self.imp().host_path_row.subtitle() else {
+            return;
+        };
+
+        view::ActionDialog::from(&action_list.load_images(host_path.as_str())).present(Some(self));
+    }
+}
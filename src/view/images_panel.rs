@@ -24,12 +24,16 @@ use crate::view;
 
 const ACTION_PULL_IMAGE: &str = "images-panel.pull-image";
 const ACTION_BUILD_IMAGE: &str = "images-panel.build-image";
+const ACTION_LOAD_IMAGES: &str = "images-panel.load-images";
+const ACTION_IMPORT_IMAGE: &str = "images-panel.import-image";
 const ACTION_PRUNE_UNUSED_IMAGES: &str = "images-panel.prune-unused-images";
 const ACTION_ENTER_SELECTION_MODE: &str = "images-panel.enter-selection-mode";
 const ACTION_EXIT_SELECTION_MODE: &str = "images-panel.exit-selection-mode";
 const ACTION_SELECT_VISIBLE: &str = "images-panel.select-visible";
 const ACTION_SELECT_NONE: &str = "images-panel.select-none";
+const ACTION_SAVE_SELECTION: &str = "images-panel.save-selection";
 const ACTION_DELETE_SELECTION: &str = "images-panel.delete-selection";
+const ACTION_COMPARE_SELECTION: &str = "images-panel.compare-selection";
 const ACTION_TOGGLE_SORT_DIRECTION: &str = "images-panel.toggle-sort-direction";
 const ACTION_CHANGE_SORT_ATTRIBUTE: &str = "images-panel.change-sort-attribute";
 const ACTION_SHOW_ALL_IMAGES: &str = "images-panel.show-all-images";
@@ -155,6 +159,12 @@ mod imp {
             klass.install_action(ACTION_BUILD_IMAGE, None, |widget, _, _| {
                 widget.show_build_dialog();
             });
+            klass.install_action(ACTION_LOAD_IMAGES, None, |widget, _, _| {
+                widget.show_load_dialog();
+            });
+            klass.install_action(ACTION_IMPORT_IMAGE, None, |widget, _, _| {
+                widget.show_import_dialog();
+            });
 
             klass.install_action(ACTION_PRUNE_UNUSED_IMAGES, None, |widget, _, _| {
                 widget.show_prune_dialog();
@@ -174,9 +184,15 @@ mod imp {
                 widget.select_none();
             });
 
+            klass.install_action(ACTION_SAVE_SELECTION, None, |widget, _, _| {
+                widget.save_selection();
+            });
             klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
                 widget.remove_selection();
             });
+            klass.install_action(ACTION_COMPARE_SELECTION, None, |widget, _, _| {
+                widget.compare_selection();
+            });
 
             klass.install_action(ACTION_TOGGLE_SORT_DIRECTION, None, |widget, _, _| {
                 widget.toggle_sort_direction();
@@ -528,6 +544,7 @@ mod imp {
             ));
 
             obj.action_set_enabled(ACTION_DELETE_SELECTION, false);
+            obj.action_set_enabled(ACTION_COMPARE_SELECTION, false);
             value.connect_notify_local(
                 Some("num-selected"),
                 clone!(
@@ -535,6 +552,7 @@ mod imp {
                     obj,
                     move |list, _| {
                         obj.action_set_enabled(ACTION_DELETE_SELECTION, list.num_selected() > 0);
+                        obj.action_set_enabled(ACTION_COMPARE_SELECTION, list.num_selected() == 2);
                     }
                 ),
             );
@@ -616,6 +634,18 @@ impl ImagesPanel {
         }
     }
 
+    pub(crate) fn show_load_dialog(&self) {
+        if let Some(client) = self.client() {
+            view::ImagesLoadOptsDialog::new(&client, "").present(Some(self));
+        }
+    }
+
+    pub(crate) fn show_import_dialog(&self) {
+        if let Some(client) = self.client() {
+            view::ImageImportOptsDialog::new(&client, None, "").present(Some(self));
+        }
+    }
+
     pub(crate) fn show_prune_dialog(&self) {
         if let Some(client) = self.image_list().and_then(|list| list.client()) {
             view::ImagesPruneOptsDialog::new(&client, None).present(Some(self));
@@ -655,6 +685,42 @@ impl ImagesPanel {
         }
     }
 
+    pub(crate) fn save_selection(&self) {
+        let Some(list) = self.image_list().filter(|list| list.num_selected() > 0) else {
+            return;
+        };
+
+        let images = list
+            .selected_items()
+            .iter()
+            .map(|obj| obj.downcast_ref::<model::Image>().unwrap().to_owned())
+            .collect::<Vec<_>>();
+
+        list.set_selection_mode(false);
+
+        view::image::save_images(self, &images);
+    }
+
+    pub(crate) fn compare_selection(&self) {
+        let Some(list) = self.image_list().filter(|list| list.num_selected() == 2) else {
+            return;
+        };
+
+        let images = list
+            .selected_items()
+            .iter()
+            .map(|obj| obj.downcast_ref::<model::Image>().unwrap().to_owned())
+            .collect::<Vec<_>>();
+
+        list.set_selection_mode(false);
+
+        utils::navigation_view(self).push(
+            &adw::NavigationPage::builder()
+                .child(&view::ImageComparePage::new(&images[0], &images[1]))
+                .build(),
+        );
+    }
+
     pub(crate) fn remove_selection(&self) {
         if self
             .image_list()
@@ -0,0 +1,216 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::SaveFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_SAVE: &str = "images-save-opts-dialog.save";
+const ACTION_SELECT_HOST_PATH: &str = "images-save-opts-dialog.select-host-path";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImagesSaveOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/images_save_opts_dialog.ui")]
+    pub(crate) struct ImagesSaveOptsDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedImagesSaveOpts>,
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+
+        #[template_child]
+        pub(super) references_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) format_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImagesSaveOptsDialog {
+        const NAME: &'static str = "PdsImagesSaveOptsDialog";
+        type Type = super::ImagesSaveOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_SAVE, None, |widget, _, _| {
+                widget.close_and_save();
+            });
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImagesSaveOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let opts = obj.opts();
+
+            opts.references.iter().for_each(|reference| {
+                self.references_list_box.append(
+                    &adw::ActionRow::builder()
+                        .title(reference)
+                        .title_selectable(true)
+                        .use_markup(false)
+                        .build(),
+                );
+            });
+
+            match obj
+                .client()
+                .and_then(|client| client.engine().capabilities().image_formats())
+            {
+                Some(format_list) => {
+                    self.format_combo_row.set_visible(true);
+                    self.format_combo_row.set_model(Some(&format_list));
+
+                    if let Some(ref format) = opts.format {
+                        self.format_combo_row.set_selected(format_list.find(format));
+                    }
+                }
+                None => {
+                    self.format_combo_row.set_visible(false);
+                    self.format_combo_row.set_model(gio::ListModel::NONE);
+                }
+            }
+
+            self.host_path_row.set_subtitle(&obj.host_path());
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for ImagesSaveOptsDialog {}
+    impl AdwDialogImpl for ImagesSaveOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl ImagesSaveOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = self
+                .host_path_row
+                .subtitle()
+                .map(|s| !s.is_empty())
+                .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_SAVE, enabled);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImagesSaveOptsDialog(ObjectSubclass<imp::ImagesSaveOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl ImagesSaveOptsDialog {
+    pub(crate) fn new(
+        client: &model::Client,
+        opts: model::BoxedImagesSaveOpts,
+        host_path: &str,
+    ) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("opts", opts)
+            .property("host-path", host_path)
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let suggested_archive_name = match self.opts().references.as_slice() {
+            [reference] => format!("{}.tar", reference.replace(['/', ':'], "_")),
+            _ => String::from("images.tar"),
+        };
+
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Host Path").as_str())
+            .current_name(suggested_archive_name.as_str())
+            .filter(FileFilter::new("Tar Archive").mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_save_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_save(&self) {
+        self.close();
+
+        let Some(action_list) = self.client().map(|client| client.action_list()) else {
+            return;
+        };
+
+        let imp = self.imp();
+
+        let Some(host_path) = imp.host_path_row.subtitle() else {
+            return;
+        };
+
+        let opts = engine::opts::ImagesSaveOpts {
+            format: imp.format_combo_row.selected_item().map(|item| {
+                item.downcast_ref::<gtk::StringObject>()
+                    .unwrap()
+                    .string()
+                    .to_string()
+            }),
+            ..(*self.opts()).clone()
+        };
+
+        view::ActionDialog::from(&action_list.save_images(opts, host_path.as_str()))
+            .present(Some(self));
+    }
+}
@@ -3,6 +3,9 @@ mod action_row;
 mod actions_button;
 mod actions_sidebar;
 mod client_view;
+mod compose_project_group;
+mod compose_up_action_view;
+mod compose_up_opts_dialog;
 mod connection;
 mod connection_chooser_page;
 mod connection_creation_page;
@@ -11,6 +14,9 @@ mod connection_row;
 mod connections_sidebar;
 mod container;
 mod container_card;
+mod container_changes_page;
+mod container_checkpoint_action_view;
+mod container_checkpoint_opts_dialog;
 mod container_commit_action_view;
 mod container_commit_opts_dialog;
 mod container_copy_from_action_view;
@@ -20,13 +26,20 @@ mod container_copy_to_opts_dialog;
 mod container_create_action_view;
 mod container_create_opts_dialog;
 mod container_details_page;
+mod container_export_action_view;
+mod container_export_opts_dialog;
+mod container_file_page;
+mod container_files_page;
 mod container_health_check_log_row;
 mod container_health_check_page;
 mod container_log_page;
 mod container_menu_button;
 mod container_properties_group;
 mod container_renamer;
+mod container_resource_limits_dialog;
 mod container_resources;
+mod container_restore_action_view;
+mod container_restore_opts_dialog;
 mod container_row;
 mod container_terminal;
 mod container_terminal_page;
@@ -36,6 +49,7 @@ mod containers_grid_view;
 mod containers_group;
 mod containers_list_view;
 mod containers_panel;
+mod containers_projects_view;
 mod containers_prune_action_view;
 mod containers_prune_opts_dialog;
 mod device_row;
@@ -43,20 +57,39 @@ mod id_or_name_row;
 mod image;
 mod image_build_action_view;
 mod image_build_opts_dialog;
+mod image_build_step_row;
+mod image_compare_page;
 mod image_details_page;
 mod image_history_page;
+mod image_import_action_view;
+mod image_import_opts_dialog;
+mod image_layers_page;
 mod image_menu_button;
 mod image_pull_action_view;
 mod image_pull_opts_dialog;
 mod image_row;
 mod image_search_response_row;
 mod image_suggestion_entry_row;
+mod images_load_action_view;
+mod images_load_opts_dialog;
 mod images_panel;
 mod images_prune_action_view;
 mod images_prune_opts_dialog;
+mod images_save_action_view;
+mod images_save_opts_dialog;
 mod info_panel;
 mod key_val_row;
 mod mount_row;
+mod network;
+mod network_attachment_row;
+mod network_connect_container_dialog;
+mod network_create_action_view;
+mod network_create_opts_dialog;
+mod network_details_page;
+mod network_row;
+mod networks_panel;
+mod networks_prune_action_view;
+mod networks_prune_opts_dialog;
 mod pod;
 mod pod_create_action_view;
 mod pod_create_opts_dialog;
@@ -65,9 +98,14 @@ mod pod_menu_button;
 mod pod_row;
 mod pod_selection_combo_row;
 mod pods_panel;
+mod pods_play_kube_action_view;
+mod pods_play_kube_opts_dialog;
 mod pods_prune_action_view;
 mod pods_prune_opts_dialog;
 mod port_mapping_row;
+mod registry_account_dialog;
+mod registry_account_row;
+mod registry_accounts_dialog;
 mod repo_tag_add_dialog;
 mod repo_tag_push_action_view;
 mod repo_tag_push_opts_dialog;
@@ -75,12 +113,21 @@ mod repo_tag_row;
 mod repo_tag_simple_row;
 mod scalable_text_view_page;
 mod search_panel;
+mod secret_create_opts_dialog;
+mod secret_ref_row;
+mod secret_row;
+mod secrets_panel;
 mod top_page;
 mod value_row;
 mod volume;
 mod volume_create_action_view;
 mod volume_create_opts_dialog;
 mod volume_details_page;
+mod volume_export_action_view;
+mod volume_export_opts_dialog;
+mod volume_files_page;
+mod volume_import_action_view;
+mod volume_import_opts_dialog;
 mod volume_row;
 mod volume_selection_combo_row;
 mod volumes_group;
@@ -103,12 +150,18 @@ export_gobjects![
     pub(crate) use self::actions_button::ActionsButton;
     pub(crate) use self::actions_sidebar::ActionsSidebar;
     pub(crate) use self::client_view::ClientView;
+    pub(crate) use self::compose_project_group::ComposeProjectGroup;
+    pub(crate) use self::compose_up_action_view::ComposeUpActionView;
+    pub(crate) use self::compose_up_opts_dialog::ComposeUpOptsDialog;
     pub(crate) use self::connection_chooser_page::ConnectionChooserPage;
     pub(crate) use self::connection_creation_page::ConnectionCreationPage;
     pub(crate) use self::connection_custom_info_page::ConnectionCustomInfoDialog;
     pub(crate) use self::connection_row::ConnectionRow;
     pub(crate) use self::connections_sidebar::ConnectionsSidebar;
     pub(crate) use self::container_card::ContainerCard;
+    pub(crate) use self::container_changes_page::ContainerChangesPage;
+    pub(crate) use self::container_checkpoint_action_view::ContainerCheckpointActionView;
+    pub(crate) use self::container_checkpoint_opts_dialog::ContainerCheckpointOptsDialog;
     pub(crate) use self::container_commit_action_view::ContainerCommitActionView;
     pub(crate) use self::container_commit_opts_dialog::ContainerCommitOptsDialog;
     pub(crate) use self::container_copy_from_action_view::ContainerCopyFromActionView;
@@ -118,13 +171,20 @@ export_gobjects![
     pub(crate) use self::container_create_action_view::ContainerCreateActionView;
     pub(crate) use self::container_create_opts_dialog::ContainerCreateOptsDialog;
     pub(crate) use self::container_details_page::ContainerDetailsPage;
+    pub(crate) use self::container_export_action_view::ContainerExportActionView;
+    pub(crate) use self::container_export_opts_dialog::ContainerExportOptsDialog;
+    pub(crate) use self::container_file_page::ContainerFilePage;
+    pub(crate) use self::container_files_page::ContainerFilesPage;
     pub(crate) use self::container_health_check_log_row::ContainerHealthCheckLogRow;
     pub(crate) use self::container_health_check_page::ContainerHealthCheckPage;
     pub(crate) use self::container_log_page::ContainerLogPage;
     pub(crate) use self::container_menu_button::ContainerMenuButton;
     pub(crate) use self::container_properties_group::ContainerPropertiesGroup;
     pub(crate) use self::container_renamer::ContainerRenamer;
+    pub(crate) use self::container_resource_limits_dialog::ContainerResourceLimitsDialog;
     pub(crate) use self::container_resources::ContainerResources;
+    pub(crate) use self::container_restore_action_view::ContainerRestoreActionView;
+    pub(crate) use self::container_restore_opts_dialog::ContainerRestoreOptsDialog;
     pub(crate) use self::container_row::ContainerRow;
     pub(crate) use self::container_terminal::ContainerTerminal;
     pub(crate) use self::container_terminal_page::ContainerTerminalPage;
@@ -133,6 +193,7 @@ export_gobjects![
     pub(crate) use self::containers_grid_view::ContainersGridView;
     pub(crate) use self::containers_group::ContainersGroup;
     pub(crate) use self::containers_list_view::ContainersListView;
+    pub(crate) use self::containers_projects_view::ContainersProjectsView;
     pub(crate) use self::containers_panel::ContainersPanel;
     pub(crate) use self::containers_prune_action_view::ContainersPruneActionView;
     pub(crate) use self::containers_prune_opts_dialog::ContainersPruneOptsDialog;
@@ -140,20 +201,38 @@ export_gobjects![
     pub(crate) use self::id_or_name_row::IdOrNameRow;
     pub(crate) use self::image_build_action_view::ImageBuildActionView;
     pub(crate) use self::image_build_opts_dialog::ImageBuildOptsDialog;
+    pub(crate) use self::image_build_step_row::ImageBuildStepRow;
+    pub(crate) use self::image_compare_page::ImageComparePage;
     pub(crate) use self::image_details_page::ImageDetailsPage;
     pub(crate) use self::image_history_page::ImageHistoryPage;
+    pub(crate) use self::image_layers_page::ImageLayersPage;
+    pub(crate) use self::image_import_action_view::ImageImportActionView;
+    pub(crate) use self::image_import_opts_dialog::ImageImportOptsDialog;
     pub(crate) use self::image_menu_button::ImageMenuButton;
     pub(crate) use self::image_pull_action_view::ImagePullActionView;
     pub(crate) use self::image_pull_opts_dialog::ImagePullOptsDialog;
     pub(crate) use self::image_row::ImageRow;
     pub(crate) use self::image_search_response_row::ImageSearchResponseRow;
     pub(crate) use self::image_suggestion_entry_row::ImageSuggestionEntryRow;
+    pub(crate) use self::images_load_action_view::ImagesLoadActionView;
+    pub(crate) use self::images_load_opts_dialog::ImagesLoadOptsDialog;
     pub(crate) use self::images_panel::ImagesPanel;
     pub(crate) use self::images_prune_action_view::ImagesPruneActionView;
     pub(crate) use self::images_prune_opts_dialog::ImagesPruneOptsDialog;
+    pub(crate) use self::images_save_action_view::ImagesSaveActionView;
+    pub(crate) use self::images_save_opts_dialog::ImagesSaveOptsDialog;
     pub(crate) use self::info_panel::InfoPanel;
     pub(crate) use self::key_val_row::KeyValRow;
     pub(crate) use self::mount_row::MountRow;
+    pub(crate) use self::network_attachment_row::NetworkAttachmentRow;
+    pub(crate) use self::network_connect_container_dialog::NetworkConnectContainerDialog;
+    pub(crate) use self::network_create_action_view::NetworkCreateActionView;
+    pub(crate) use self::network_create_opts_dialog::NetworkCreateOptsDialog;
+    pub(crate) use self::network_details_page::NetworkDetailsPage;
+    pub(crate) use self::network_row::NetworkRow;
+    pub(crate) use self::networks_panel::NetworksPanel;
+    pub(crate) use self::networks_prune_action_view::NetworksPruneActionView;
+    pub(crate) use self::networks_prune_opts_dialog::NetworksPruneOptsDialog;
     pub(crate) use self::pod_create_action_view::PodCreateActionView;
     pub(crate) use self::pod_create_opts_dialog::PodCreateOptsDialog;
     pub(crate) use self::pod_details_page::PodDetailsPage;
@@ -161,20 +240,34 @@ export_gobjects![
     pub(crate) use self::pod_row::PodRow;
     pub(crate) use self::pod_selection_combo_row::PodSelectionComboRow;
     pub(crate) use self::pods_panel::PodsPanel;
+    pub(crate) use self::pods_play_kube_action_view::PodsPlayKubeActionView;
+    pub(crate) use self::pods_play_kube_opts_dialog::PodsPlayKubeOptsDialog;
     pub(crate) use self::pods_prune_action_view::PodsPruneActionView;
     pub(crate) use self::pods_prune_opts_dialog::PodsPruneOptsDialog;
     pub(crate) use self::port_mapping_row::PortMappingRow;
+    pub(crate) use self::registry_account_dialog::RegistryAccountDialog;
+    pub(crate) use self::registry_account_row::RegistryAccountRow;
+    pub(crate) use self::registry_accounts_dialog::RegistryAccountsDialog;
     pub(crate) use self::repo_tag_add_dialog::RepoTagAddDialog;
     pub(crate) use self::repo_tag_push_opts_dialog::RepoTagPushOptsDialog;
     pub(crate) use self::repo_tag_row::RepoTagRow;
     pub(crate) use self::repo_tag_simple_row::RepoTagSimpleRow;
     pub(crate) use self::scalable_text_view_page::ScalableTextViewPage;
     pub(crate) use self::search_panel::SearchPanel;
+    pub(crate) use self::secret_create_opts_dialog::SecretCreateOptsDialog;
+    pub(crate) use self::secret_ref_row::SecretRefRow;
+    pub(crate) use self::secret_row::SecretRow;
+    pub(crate) use self::secrets_panel::SecretsPanel;
     pub(crate) use self::top_page::TopPage;
     pub(crate) use self::value_row::ValueRow;
     pub(crate) use self::volume_create_action_view::VolumeCreateActionView;
     pub(crate) use self::volume_create_opts_dialog::VolumeCreateOptsDialog;
     pub(crate) use self::volume_details_page::VolumeDetailsPage;
+    pub(crate) use self::volume_export_action_view::VolumeExportActionView;
+    pub(crate) use self::volume_export_opts_dialog::VolumeExportOptsDialog;
+    pub(crate) use self::volume_files_page::VolumeFilesPage;
+    pub(crate) use self::volume_import_action_view::VolumeImportActionView;
+    pub(crate) use self::volume_import_opts_dialog::VolumeImportOptsDialog;
     pub(crate) use self::volume_row::VolumeRow;
     pub(crate) use self::volume_selection_combo_row::VolumeSelectionComboRow;
     pub(crate) use self::volumes_group::VolumesGroup;
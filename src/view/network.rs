@@ -0,0 +1,110 @@
+use adw::prelude::*;
+use gettextrs::gettext;
+use glib::clone::Downgrade;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+pub(crate) async fn delete_network_show_confirmation<W>(
+    widget: &W,
+    network: Option<&model::Network>,
+) where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    let Some(network) = network else {
+        return;
+    };
+
+    match network.container_list().get(0) {
+        Some(container) => {
+            let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Confirm Network Deletion"))
+                .body_use_markup(true)
+                .body(gettext!(
+                    // Translators: The "{}" is a placeholder for the container name.
+                    "Network is used by container <b>{}</b>. Deleting the network may also delete these containers.",
+                    container.name(),
+                ))
+                .build();
+
+            dialog.add_responses(&[
+                ("cancel", &gettext("_Cancel")),
+                ("delete", &gettext("_Delete")),
+            ]);
+            dialog.set_default_response(Some("cancel"));
+            dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+            if "delete" == dialog.choose_future(Some(widget)).await {
+                delete_network(widget, network, true).await;
+            }
+        }
+        None => delete_network(widget, network, false).await,
+    }
+}
+
+async fn delete_network<W>(widget: &W, network: &model::Network, force: bool)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    if let Err(e) = network.delete(force).await {
+        utils::show_error_toast(
+            widget,
+            // Translators: The "{}" is a placeholder for the network name.
+            &gettext!("Error on deleting network '{}'", &network.name()),
+            &e.to_string(),
+        );
+    }
+}
+
+pub(crate) async fn disconnect_container<W>(
+    widget: &W,
+    network: &model::Network,
+    container: &model::Container,
+) where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    if let Err(e) = network.disconnect_container(container, false).await {
+        utils::show_error_toast(
+            widget,
+            // Translators: The first "{}" is a placeholder for the container name, the second for the network name.
+            &gettext!(
+                "Error on disconnecting container '{}' from network '{}'",
+                container.name(),
+                network.name()
+            ),
+            &e.to_string(),
+        );
+    }
+}
+
+pub(crate) async fn connect_container<W>(
+    widget: &W,
+    network: &model::Network,
+    opts: engine::opts::NetworkConnectOpts,
+) where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    if let Err(e) = network.connect_container(opts).await {
+        utils::show_error_toast(
+            widget,
+            // Translators: The "{}" is a placeholder for the network name.
+            &gettext!(
+                "Error on connecting container to network '{}'",
+                network.name()
+            ),
+            &e.to_string(),
+        );
+    }
+}
+
+pub(crate) fn show_connect_container_dialog<W: IsA<gtk::Widget>>(
+    widget: &W,
+    network: Option<&model::Network>,
+) {
+    if let Some(network) = network {
+        view::NetworkConnectContainerDialog::from(network).present(Some(widget));
+    }
+}
@@ -0,0 +1,227 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::ExpanderRowImpl;
+use adw::subclass::prelude::PreferencesRowImpl;
+use adw::subclass::prelude::*;
+use glib::Properties;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+
+const ACTION_REMOVE: &str = "network-attachment-row.remove";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkAttachmentRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_attachment_row.ui")]
+    pub(crate) struct NetworkAttachmentRow {
+        #[property(get, set = Self::set_network_attachment, construct, explicit_notify, nullable)]
+        pub(super) network_attachment: RefCell<Option<model::NetworkAttachment>>,
+        pub(super) bindings: RefCell<Vec<glib::Binding>>,
+        #[template_child]
+        pub(super) network_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) addresses_colon_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) addresses_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) network_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) aliases_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ipv4_address_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ipv6_address_entry_row: TemplateChild<adw::EntryRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkAttachmentRow {
+        const NAME: &'static str = "PdsNetworkAttachmentRow";
+        type Type = super::NetworkAttachmentRow;
+        type ParentType = adw::ExpanderRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_REMOVE, None, |widget, _, _| {
+                if let Some(network_attachment) = widget.network_attachment() {
+                    network_attachment.remove_request();
+                }
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkAttachmentRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let network_attachment_expr = Self::Type::this_expression("network-attachment");
+
+            network_attachment_expr
+                .chain_property::<model::NetworkAttachment>("network")
+                .chain_closure::<String>(closure!(|_: Self::Type, network: &str| {
+                    if network.is_empty() { "?" } else { network }.to_owned()
+                }))
+                .bind(&self.network_label.get(), "label", Some(obj));
+
+            let addresses_expr = gtk::ClosureExpression::new::<String>(
+                [
+                    network_attachment_expr
+                        .chain_property::<model::NetworkAttachment>("ipv4-address"),
+                    network_attachment_expr
+                        .chain_property::<model::NetworkAttachment>("ipv6-address"),
+                ],
+                closure!(|_: Self::Type, ipv4_address: &str, ipv6_address: &str| {
+                    [ipv4_address.trim(), ipv6_address.trim()]
+                        .into_iter()
+                        .filter(|address| !address.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }),
+            );
+
+            addresses_expr.bind(&self.addresses_label.get(), "label", Some(obj));
+
+            let addresses_visible_expr =
+                addresses_expr.chain_closure::<bool>(closure!(|_: Self::Type, addresses: &str| {
+                    !addresses.is_empty()
+                }));
+            addresses_visible_expr.bind(&self.addresses_label.get(), "visible", Some(obj));
+            addresses_visible_expr.bind(&self.addresses_colon_label.get(), "visible", Some(obj));
+        }
+    }
+
+    impl WidgetImpl for NetworkAttachmentRow {}
+    impl ListBoxRowImpl for NetworkAttachmentRow {}
+    impl PreferencesRowImpl for NetworkAttachmentRow {}
+    impl ExpanderRowImpl for NetworkAttachmentRow {}
+
+    #[gtk::template_callbacks]
+    impl NetworkAttachmentRow {
+        #[template_callback]
+        fn on_network_combo_row_notify_selected_item(&self) {
+            let Some(network) = self
+                .network_combo_row
+                .selected_item()
+                .and_downcast::<model::Network>()
+            else {
+                return;
+            };
+
+            if let Some(network_attachment) = self.obj().network_attachment() {
+                network_attachment.set_network(network.name());
+            }
+        }
+
+        pub(super) fn set_network_attachment(&self, value: Option<model::NetworkAttachment>) {
+            let obj = &*self.obj();
+
+            if obj.network_attachment() == value {
+                return;
+            }
+
+            let mut bindings = self.bindings.borrow_mut();
+
+            while let Some(binding) = bindings.pop() {
+                binding.unbind();
+            }
+
+            if let Some(ref network_attachment) = value {
+                let network = network_attachment.network();
+
+                let model = network_attachment.client().map(|client| {
+                    gtk::SortListModel::new(
+                        Some(client.network_list()),
+                        Some(
+                            gtk::StringSorter::builder()
+                                .expression(model::Network::this_expression("name"))
+                                .build(),
+                        ),
+                    )
+                });
+                self.network_combo_row.set_model(model.as_ref());
+
+                // Preselect the first network for new attachments.
+                let position = model
+                    .as_ref()
+                    .and_then(|model| {
+                        model
+                            .iter::<glib::Object>()
+                            .map(Result::unwrap)
+                            .map(|item| item.downcast::<model::Network>().unwrap())
+                            .position(|item| item.name() == network)
+                    })
+                    .map(|position| position as u32)
+                    .unwrap_or(if network.is_empty() {
+                        0
+                    } else {
+                        gtk::INVALID_LIST_POSITION
+                    });
+                self.network_combo_row.set_selected(position);
+
+                let binding = network_attachment
+                    .bind_property("aliases", &*self.aliases_entry_row, "text")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+                bindings.push(binding);
+
+                let binding = network_attachment
+                    .bind_property("ipv4-address", &*self.ipv4_address_entry_row, "text")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+                bindings.push(binding);
+
+                let binding = network_attachment
+                    .bind_property("ipv6-address", &*self.ipv6_address_entry_row, "text")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+                bindings.push(binding);
+            }
+
+            drop(bindings);
+
+            self.network_attachment.replace(value);
+            self.on_network_combo_row_notify_selected_item();
+
+            obj.notify_network_attachment();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkAttachmentRow(ObjectSubclass<imp::NetworkAttachmentRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ExpanderRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::NetworkAttachment> for NetworkAttachmentRow {
+    fn from(network_attachment: &model::NetworkAttachment) -> Self {
+        glib::Object::builder()
+            .property("network-attachment", network_attachment)
+            .build()
+    }
+}
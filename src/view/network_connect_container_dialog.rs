@@ -0,0 +1,179 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::view;
+
+const ACTION_CONNECT_CONTAINER: &str = "network-connect-container-dialog.connect-container";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkConnectContainerDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_connect_container_dialog.ui")]
+    pub(crate) struct NetworkConnectContainerDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) network: glib::WeakRef<model::Network>,
+        #[template_child]
+        pub(super) container_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) aliases_entry_row: TemplateChild<adw::EntryRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkConnectContainerDialog {
+        const NAME: &'static str = "PdsNetworkConnectContainerDialog";
+        type Type = super::NetworkConnectContainerDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_CONNECT_CONTAINER, None, async |widget, _, _| {
+                widget.close_and_connect().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkConnectContainerDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let Some(network) = obj.network() else {
+                return;
+            };
+            let Some(client) = network
+                .network_list()
+                .and_then(|network_list| network_list.client())
+            else {
+                return;
+            };
+
+            let filter = gtk::CustomFilter::new(clone!(
+                #[weak]
+                network,
+                #[upgrade_or]
+                false,
+                move |item| {
+                    let container = item.downcast_ref::<model::Container>().unwrap();
+                    !container.is_infra() && !network.container_list().contains(&container.id())
+                }
+            ));
+
+            self.container_combo_row
+                .set_model(Some(&gtk::SortListModel::new(
+                    Some(gtk::FilterListModel::new(
+                        Some(client.container_list()),
+                        Some(filter),
+                    )),
+                    Some(
+                        gtk::StringSorter::builder()
+                            .expression(model::Container::this_expression("name"))
+                            .build(),
+                    ),
+                )));
+
+            obj.action_set_enabled(
+                ACTION_CONNECT_CONTAINER,
+                self.container_combo_row.selected_item().is_some(),
+            );
+        }
+    }
+
+    impl WidgetImpl for NetworkConnectContainerDialog {}
+    impl AdwDialogImpl for NetworkConnectContainerDialog {}
+
+    #[gtk::template_callbacks]
+    impl NetworkConnectContainerDialog {
+        #[template_callback]
+        fn on_notify_selected_item(&self) {
+            self.obj().action_set_enabled(
+                ACTION_CONNECT_CONTAINER,
+                self.container_combo_row.selected_item().is_some(),
+            );
+        }
+
+        #[template_callback]
+        fn on_setup(&self, list_item: &gtk::ListItem) {
+            list_item.set_child(Some(&gtk::Label::builder().xalign(0.0).build()));
+        }
+
+        #[template_callback]
+        fn on_bind(&self, list_item: &gtk::ListItem) {
+            let container = list_item.item().and_downcast::<model::Container>().unwrap();
+            let label = list_item.child().and_downcast::<gtk::Label>().unwrap();
+
+            label.set_label(&container.name());
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkConnectContainerDialog(ObjectSubclass<imp::NetworkConnectContainerDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl From<&model::Network> for NetworkConnectContainerDialog {
+    fn from(network: &model::Network) -> Self {
+        glib::Object::builder().property("network", network).build()
+    }
+}
+
+impl NetworkConnectContainerDialog {
+    pub(crate) async fn close_and_connect(&self) {
+        let imp = self.imp();
+
+        let Some(network) = self.network() else {
+            return;
+        };
+        let Some(container) = imp
+            .container_combo_row
+            .selected_item()
+            .and_downcast::<model::Container>()
+        else {
+            return;
+        };
+
+        self.close();
+
+        let opts = engine::opts::NetworkConnectOpts {
+            container: container.id(),
+            aliases: imp
+                .aliases_entry_row
+                .text()
+                .split(',')
+                .map(str::trim)
+                .filter(|alias| !alias.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        };
+
+        view::network::connect_container(self, &network, opts).await;
+    }
+}
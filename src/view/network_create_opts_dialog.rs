@@ -0,0 +1,150 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use glib::Properties;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::view;
+use crate::widget;
+
+const ACTION_CREATE_NETWORK: &str = "network-create-opts-dialog.create-network";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkCreateOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_create_opts_dialog.ui")]
+    pub(crate) struct NetworkCreateOptsDialog {
+        #[property(get, set, construct_only)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedNetworkCreateOpts>,
+
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<widget::RandomNameEntryRow>,
+        #[template_child]
+        pub(super) driver_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) subnet_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) gateway_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) internal_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) ipv6_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkCreateOptsDialog {
+        const NAME: &'static str = "PdsNetworkCreateOptsDialog";
+        type Type = super::NetworkCreateOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_CREATE_NETWORK, None, |widget, _, _| {
+                widget.close_and_create();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkCreateOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let opts = self.obj().opts();
+
+            if !opts.name.is_empty() {
+                self.name_entry_row.set_text(&opts.name);
+            }
+            if let Some(driver) = opts.driver.as_deref() {
+                self.driver_entry_row.set_text(driver);
+            }
+            if let Some(subnet) = opts.subnet.as_deref() {
+                self.subnet_entry_row.set_text(subnet);
+            }
+            if let Some(gateway) = opts.gateway.as_deref() {
+                self.gateway_entry_row.set_text(gateway);
+            }
+            self.internal_switch_row.set_active(opts.internal);
+            self.ipv6_switch_row.set_active(opts.ipv6_enabled);
+        }
+    }
+
+    impl WidgetImpl for NetworkCreateOptsDialog {
+        fn map(&self) {
+            self.parent_map();
+            self.name_entry_row.grab_focus();
+        }
+    }
+
+    impl AdwDialogImpl for NetworkCreateOptsDialog {}
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkCreateOptsDialog(ObjectSubclass<imp::NetworkCreateOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl From<&model::Client> for NetworkCreateOptsDialog {
+    fn from(value: &model::Client) -> Self {
+        Self::new(value, None)
+    }
+}
+
+impl NetworkCreateOptsDialog {
+    pub(crate) fn new(client: &model::Client, opts: Option<model::BoxedNetworkCreateOpts>) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("opts", opts.unwrap_or_default())
+            .build()
+    }
+
+    pub(crate) fn close_and_create(&self) {
+        self.close();
+
+        let Some(action_list) = self.client().map(|client| client.action_list()) else {
+            return;
+        };
+
+        let imp = self.imp();
+
+        let opts = engine::opts::NetworkCreateOpts {
+            name: imp.name_entry_row.text().into(),
+            driver: Some(imp.driver_entry_row.text().trim().to_owned())
+                .filter(|driver| !driver.is_empty()),
+            internal: imp.internal_switch_row.is_active(),
+            ipv6_enabled: imp.ipv6_switch_row.is_active(),
+            subnet: Some(imp.subnet_entry_row.text().trim().to_owned())
+                .filter(|subnet| !subnet.is_empty()),
+            gateway: Some(imp.gateway_entry_row.text().trim().to_owned())
+                .filter(|gateway| !gateway.is_empty()),
+            labels: self.opts().labels.clone(),
+        };
+
+        view::ActionDialog::from(&action_list.create_network(opts)).present(Some(self));
+    }
+}
@@ -0,0 +1,384 @@
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_INSPECT_NETWORK: &str = "network-details-page.inspect-network";
+const ACTION_DELETE_NETWORK: &str = "network-details-page.delete-network";
+const ACTION_CONNECT_CONTAINER: &str = "network-details-page.connect-container";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkDetailsPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_details_page.ui")]
+    pub(crate) struct NetworkDetailsPage {
+        pub(super) handler_id: RefCell<Option<glib::SignalHandlerId>>,
+        pub(super) sorter: OnceCell<gtk::Sorter>,
+        #[property(get, set = Self::set_network, construct, explicit_notify, nullable)]
+        pub(super) network: glib::WeakRef<model::Network>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) name_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) id_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) driver_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) created_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) subnets_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) internal_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) ipv6_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) containers_list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkDetailsPage {
+        const NAME: &'static str = "PdsNetworkDetailsPage";
+        type Type = super::NetworkDetailsPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action_async(ACTION_INSPECT_NETWORK, None, async |widget, _, _| {
+                widget.show_inspection().await;
+            });
+
+            klass.install_action_async(ACTION_DELETE_NETWORK, None, async |widget, _, _| {
+                widget.delete_network().await;
+            });
+
+            klass.install_action_async(ACTION_CONNECT_CONTAINER, None, async |widget, _, _| {
+                widget.connect_container().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkDetailsPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let network_expr = Self::Type::this_expression("network");
+
+            gtk::ClosureExpression::new::<bool>(
+                [
+                    network_expr.chain_property::<model::Network>("to-be-deleted"),
+                    network_expr.chain_property::<model::Network>("action-ongoing"),
+                ],
+                closure!(|_: Self::Type, to_be_deleted: bool, action_ongoing: bool| {
+                    !to_be_deleted && !action_ongoing
+                }),
+            )
+            .watch(
+                Some(obj),
+                clone!(
+                    #[weak]
+                    obj,
+                    move || {
+                        let enabled = obj
+                            .network()
+                            .map(|network| !network.to_be_deleted() && !network.action_ongoing())
+                            .unwrap_or(false);
+                        obj.action_set_enabled(ACTION_DELETE_NETWORK, enabled);
+                        obj.action_set_enabled(ACTION_CONNECT_CONTAINER, enabled);
+                    }
+                ),
+            );
+
+            network_expr.chain_property::<model::Network>("name").bind(
+                &*self.name_row,
+                "subtitle",
+                Some(obj),
+            );
+
+            network_expr
+                .chain_property::<model::Network>("id")
+                .chain_closure::<String>(closure!(|_: Self::Type, id: &str| {
+                    utils::format_id(id).to_owned()
+                }))
+                .bind(&*self.id_row, "subtitle", Some(obj));
+
+            network_expr
+                .chain_property::<model::Network>("driver")
+                .bind(&*self.driver_row, "subtitle", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                &[
+                    &Self::Type::this_expression("root")
+                        .chain_property::<gtk::Window>("application")
+                        .chain_property::<crate::Application>("ticks"),
+                    &network_expr.chain_property::<model::Network>("created-at"),
+                ],
+                closure!(|_: Self::Type, _ticks: u64, created_at: i64| {
+                    utils::format_ago(utils::timespan_now(created_at))
+                }),
+            )
+            .bind(&*self.created_row, "subtitle", Some(obj));
+
+            let subnets_expr = network_expr.chain_property::<model::Network>("subnets");
+            subnets_expr
+                .chain_closure::<String>(closure!(
+                    |_: Self::Type, subnets: Option<gtk::StringList>| {
+                        subnets
+                            .map(|subnets| {
+                                utils::format_iter(
+                                    &subnets
+                                        .iter::<gtk::StringObject>()
+                                        .map(Result::unwrap)
+                                        .map(|item| item.string())
+                                        .collect::<Vec<_>>(),
+                                    ", ",
+                                )
+                            })
+                            .unwrap_or_default()
+                    }
+                ))
+                .bind(&*self.subnets_row, "subtitle", Some(obj));
+            subnets_expr
+                .chain_closure::<bool>(closure!(
+                    |_: Self::Type, subnets: Option<gtk::StringList>| {
+                        subnets
+                            .map(|subnets| subnets.n_items() > 0)
+                            .unwrap_or(false)
+                    }
+                ))
+                .bind(&*self.subnets_row, "visible", Some(obj));
+
+            network_expr
+                .chain_property::<model::Network>("internal")
+                .chain_closure::<String>(closure!(|_: Self::Type, internal: bool| {
+                    if internal {
+                        gettext("Yes")
+                    } else {
+                        gettext("No")
+                    }
+                }))
+                .bind(&*self.internal_row, "subtitle", Some(obj));
+
+            network_expr
+                .chain_property::<model::Network>("ipv6-enabled")
+                .chain_closure::<String>(closure!(|_: Self::Type, ipv6_enabled: bool| {
+                    if ipv6_enabled {
+                        gettext("Enabled")
+                    } else {
+                        gettext("Disabled")
+                    }
+                }))
+                .bind(&*self.ipv6_row, "subtitle", Some(obj));
+
+            let sorter = gtk::CustomSorter::new(|item1, item2| {
+                item1
+                    .downcast_ref::<model::Container>()
+                    .unwrap()
+                    .name()
+                    .to_lowercase()
+                    .cmp(
+                        &item2
+                            .downcast_ref::<model::Container>()
+                            .unwrap()
+                            .name()
+                            .to_lowercase(),
+                    )
+                    .into()
+            });
+            self.sorter.set(sorter.upcast()).unwrap();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for NetworkDetailsPage {}
+
+    impl NetworkDetailsPage {
+        pub(super) fn set_network(&self, value: Option<&model::Network>) {
+            let obj = &*self.obj();
+            if obj.network().as_ref() == value {
+                return;
+            }
+
+            self.window_title.set_subtitle("");
+            if let Some(network) = obj.network() {
+                network.disconnect(self.handler_id.take().unwrap());
+            }
+
+            if let Some(network) = value {
+                self.window_title.set_subtitle(&network.name());
+
+                let handler_id = network.connect_deleted(clone!(
+                    #[weak]
+                    obj,
+                    move |network| {
+                        utils::show_toast(
+                            &obj,
+                            gettext!("Network '{}' has been deleted", network.name()),
+                        );
+                        utils::navigation_view(&obj).pop();
+                    }
+                ));
+                self.handler_id.replace(Some(handler_id));
+
+                let model = gtk::SortListModel::new(
+                    Some(network.container_list()),
+                    self.sorter.get().cloned(),
+                );
+
+                self.containers_list_box.bind_model(
+                    Some(&model),
+                    clone!(
+                        #[weak]
+                        obj,
+                        #[weak]
+                        network,
+                        #[upgrade_or_panic]
+                        move |item| {
+                            obj.create_container_row(&network, item.downcast_ref().unwrap())
+                                .upcast()
+                        }
+                    ),
+                );
+            }
+
+            self.network.set(value);
+            obj.notify("network");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkDetailsPage(ObjectSubclass<imp::NetworkDetailsPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Network> for NetworkDetailsPage {
+    fn from(network: &model::Network) -> Self {
+        glib::Object::builder().property("network", network).build()
+    }
+}
+
+impl NetworkDetailsPage {
+    pub(crate) async fn show_inspection(&self) {
+        self.exec_action(async || {
+            let Some(network) = self.network() else {
+                return;
+            };
+
+            let weak_ref = glib::WeakRef::new();
+            weak_ref.set(Some(&network));
+
+            utils::navigation_view(self).push(
+                &adw::NavigationPage::builder()
+                    .child(&view::ScalableTextViewPage::from(view::Entity::Network(
+                        weak_ref,
+                    )))
+                    .build(),
+            );
+        })
+        .await;
+    }
+
+    pub(crate) async fn delete_network(&self) {
+        self.exec_action(async || {
+            view::network::delete_network_show_confirmation(self, self.network().as_ref()).await;
+        })
+        .await;
+    }
+
+    pub(crate) async fn connect_container(&self) {
+        self.exec_action(async || {
+            view::network::show_connect_container_dialog(self, self.network().as_ref());
+        })
+        .await;
+    }
+
+    fn create_container_row(
+        &self,
+        network: &model::Network,
+        container: &model::Container,
+    ) -> adw::ActionRow {
+        let row = adw::ActionRow::builder()
+            .title(container.name())
+            .subtitle(utils::format_id(&container.id()))
+            .build();
+        container
+            .bind_property("name", &row, "title")
+            .sync_create()
+            .build();
+
+        let button = gtk::Button::builder()
+            .css_classes(["flat"])
+            .icon_name("network-offline-symbolic")
+            .tooltip_text(gettext("Disconnect Container"))
+            .valign(gtk::Align::Center)
+            .build();
+        network
+            .bind_property("action-ongoing", &button, "sensitive")
+            .invert_boolean()
+            .sync_create()
+            .build();
+        button.connect_clicked(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[weak]
+            network,
+            #[weak]
+            container,
+            move |_| {
+                glib::spawn_future_local(async move {
+                    view::network::disconnect_container(&obj, &network, &container).await;
+                });
+            }
+        ));
+        row.add_suffix(&button);
+
+        row
+    }
+
+    async fn exec_action<F: AsyncFn()>(&self, op: F) {
+        if utils::navigation_view(self)
+            .visible_page()
+            .filter(|page| page.child().as_ref() == Some(self.upcast_ref()))
+            .is_some()
+        {
+            op().await;
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::closure;
+use glib::property::PropertySet;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::model::SelectableExt;
+use crate::model::SelectableListExt;
+use crate::utils;
+use crate::view;
+
+const ACTION_DELETE_NETWORK: &str = "network-row.delete-network";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_row.ui")]
+    pub(crate) struct NetworkRow {
+        #[property(get, set = Self::set_network, construct, nullable)]
+        pub(super) network: RefCell<Option<model::Network>>,
+        pub(super) bindings: RefCell<Vec<glib::Binding>>,
+        #[template_child]
+        pub(super) check_button_revealer: TemplateChild<gtk::Revealer>,
+        #[template_child]
+        pub(super) check_button: TemplateChild<gtk::CheckButton>,
+        #[template_child]
+        pub(super) name_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) age_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) spinner: TemplateChild<adw::Spinner>,
+        #[template_child]
+        pub(super) containers_count_bar: TemplateChild<view::ContainersCountBar>,
+        #[template_child]
+        pub(super) end_box_revealer: TemplateChild<gtk::Revealer>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkRow {
+        const NAME: &'static str = "PdsNetworkRow";
+        type Type = super::NetworkRow;
+        type ParentType = gtk::ListBoxRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action("network-row.activate", None, |widget, _, _| {
+                widget.activate();
+            });
+
+            klass.install_action_async(ACTION_DELETE_NETWORK, None, async |widget, _, _| {
+                widget.delete_network().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let ticks_expr = Self::Type::this_expression("root")
+                .chain_property::<gtk::Window>("application")
+                .chain_property::<crate::Application>("ticks");
+
+            let network_expr = Self::Type::this_expression("network");
+            let network_name_expr = network_expr.chain_property::<model::Network>("name");
+            let network_to_be_deleted_expr =
+                network_expr.chain_property::<model::Network>("to-be-deleted");
+            let container_list_expr =
+                network_expr.chain_property::<model::Network>("container-list");
+
+            let selection_mode_expr = network_expr
+                .chain_property::<model::Network>("network-list")
+                .chain_property::<model::NetworkList>("selection-mode");
+
+            selection_mode_expr.bind(&*self.check_button_revealer, "reveal-child", Some(obj));
+            selection_mode_expr
+                .chain_closure::<bool>(closure!(|_: Self::Type, is_selection_mode: bool| {
+                    !is_selection_mode
+                }))
+                .bind(&*self.end_box_revealer, "reveal-child", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [
+                    network_name_expr.upcast_ref(),
+                    network_to_be_deleted_expr.upcast_ref(),
+                ],
+                closure!(|_: Self::Type, name: String, to_be_deleted: bool| {
+                    if to_be_deleted {
+                        format!("<s>{name}</s>")
+                    } else {
+                        name
+                    }
+                }),
+            )
+            .bind(&*self.name_label, "label", Some(obj));
+
+            let css_classes = utils::css_classes(&*self.name_label);
+            container_list_expr
+                .chain_property::<model::SimpleContainerList>("len")
+                .chain_closure::<Vec<String>>(closure!(|_: Self::Type, len: u32| {
+                    css_classes
+                        .iter()
+                        .cloned()
+                        .chain(if len == 0 {
+                            Some(String::from("dim-label"))
+                        } else {
+                            None
+                        })
+                        .collect::<Vec<_>>()
+                }))
+                .bind(&*self.name_label, "css-classes", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [
+                    &ticks_expr,
+                    &network_expr.chain_property::<model::Network>("driver"),
+                    &network_expr.chain_property::<model::Network>("created-at"),
+                ],
+                closure!(
+                    |_: Self::Type, _ticks: u64, driver: String, created_at: i64| {
+                        // Translators: This will resolve to sth. like "bridge, {a few minutes} old".
+                        gettext!(
+                            "{}, {} old",
+                            driver,
+                            utils::human_friendly_timespan(utils::timespan_now(created_at))
+                        )
+                    }
+                ),
+            )
+            .bind(&*self.age_label, "label", Some(obj));
+
+            network_expr
+                .chain_property::<model::Network>("searching-containers")
+                .bind(&self.spinner.get(), "visible", Some(obj));
+
+            container_list_expr.bind(&*self.containers_count_bar, "container-list", Some(obj));
+
+            network_to_be_deleted_expr.watch(
+                Some(obj),
+                clone!(
+                    #[weak]
+                    obj,
+                    #[strong]
+                    network_to_be_deleted_expr,
+                    move || {
+                        obj.action_set_enabled(
+                            ACTION_DELETE_NETWORK,
+                            !network_to_be_deleted_expr
+                                .evaluate_as::<bool, _>(Some(&obj))
+                                .unwrap(),
+                        );
+                    }
+                ),
+            );
+
+            if let Some(network) = obj.network() {
+                obj.action_set_enabled("network.show-details", !network.to_be_deleted());
+                network.connect_notify_local(
+                    Some("to-be-deleted"),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move |network, _| {
+                            obj.action_set_enabled(
+                                "network.show-details",
+                                !network.to_be_deleted(),
+                            );
+                        }
+                    ),
+                );
+            }
+        }
+    }
+
+    impl WidgetImpl for NetworkRow {}
+    impl ListBoxRowImpl for NetworkRow {}
+
+    impl NetworkRow {
+        pub(super) fn set_network(&self, value: Option<model::Network>) {
+            let obj = &*self.obj();
+            if obj.network() == value {
+                return;
+            }
+
+            let mut bindings = self.bindings.borrow_mut();
+            while let Some(binding) = bindings.pop() {
+                binding.unbind();
+            }
+
+            if let Some(ref network) = value {
+                let binding = network
+                    .bind_property("selected", &*self.check_button, "active")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+
+                bindings.push(binding);
+            }
+
+            self.network.set(value);
+            obj.notify("network")
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkRow(ObjectSubclass<imp::NetworkRow>)
+        @extends gtk::Widget, gtk::ListBoxRow,
+        @implements gtk::Accessible, gtk::Buildable, gtk::Actionable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Network> for NetworkRow {
+    fn from(network: &model::Network) -> Self {
+        glib::Object::builder().property("network", network).build()
+    }
+}
+
+impl NetworkRow {
+    pub(crate) fn activate(&self) {
+        if let Some(network) = self.network().as_ref() {
+            if network
+                .network_list()
+                .map(|list| list.is_selection_mode())
+                .unwrap_or(false)
+            {
+                network.select();
+            } else {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .title(gettext!("Network {}", network.name()))
+                        .child(&view::NetworkDetailsPage::from(network))
+                        .build(),
+                );
+            }
+        }
+    }
+
+    pub(crate) async fn delete_network(&self) {
+        view::network::delete_network_show_confirmation(self, self.network().as_ref()).await;
+    }
+}
@@ -23,6 +23,8 @@ const ACTION_RESUME: &str = "pod-details-page.resume";
 const ACTION_DELETE: &str = "pod-details-page.delete";
 const ACTION_INSPECT_POD: &str = "pod-details-page.inspect-pod";
 const ACTION_GENERATE_KUBE: &str = "pod-details-page.generate-kube";
+const ACTION_GENERATE_QUADLET: &str = "pod-details-page.generate-quadlet";
+const ACTION_GENERATE_SYSTEMD: &str = "pod-details-page.generate-systemd";
 const ACTION_SHOW_PROCESSES: &str = "pod-details-page.show-processes";
 
 mod imp {
@@ -102,6 +104,12 @@ mod imp {
             klass.install_action(ACTION_GENERATE_KUBE, None, |widget, _, _| {
                 widget.show_kube();
             });
+            klass.install_action(ACTION_GENERATE_QUADLET, None, |widget, _, _| {
+                widget.show_kube_inspection_or_kube(view::ScalableTextViewMode::Quadlet);
+            });
+            klass.install_action(ACTION_GENERATE_SYSTEMD, None, |widget, _, _| {
+                widget.show_kube_inspection_or_kube(view::ScalableTextViewMode::Systemd);
+            });
             klass.install_action(ACTION_SHOW_PROCESSES, None, |widget, _, _| {
                 widget.show_processes();
             });
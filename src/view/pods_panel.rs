@@ -23,6 +23,7 @@ use crate::utils;
 use crate::view;
 
 const ACTION_CREATE_POD: &str = "pods-panel.create-pod";
+const ACTION_PLAY_KUBE: &str = "pods-panel.play-kube";
 const ACTION_PRUNE_PODS: &str = "pods-panel.prune-pods";
 const ACTION_ENTER_SELECTION_MODE: &str = "pods-panel.enter-selection-mode";
 
@@ -108,7 +109,7 @@ mod imp {
         #[property(get, set)]
         pub(super) show_running_pods_first: Cell<bool>,
         #[template_child]
-        pub(super) create_pod_button: TemplateChild<gtk::Button>,
+        pub(super) create_pod_button: TemplateChild<gtk::MenuButton>,
         #[template_child]
         pub(super) prune_button: TemplateChild<gtk::Button>,
         #[template_child]
@@ -165,6 +166,9 @@ mod imp {
             klass.install_action(ACTION_CREATE_POD, None, |widget, _, _| {
                 widget.create_pod();
             });
+            klass.install_action(ACTION_PLAY_KUBE, None, |widget, _, _| {
+                widget.play_kube();
+            });
 
             klass.install_action(ACTION_PRUNE_PODS, None, |widget, _, _| {
                 widget.show_prune_dialog();
@@ -639,6 +643,12 @@ impl PodsPanel {
         }
     }
 
+    pub(crate) fn play_kube(&self) {
+        if let Some(client) = self.pod_list().and_then(|pod_list| pod_list.client()) {
+            view::PodsPlayKubeOptsDialog::new(&client, None).present(Some(self));
+        }
+    }
+
     pub(crate) fn show_prune_dialog(&self) {
         if let Some(client) = self.pod_list().and_then(|pod_list| pod_list.client()) {
             view::PodsPruneOptsDialog::from(&client).present(Some(self));
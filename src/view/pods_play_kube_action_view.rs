@@ -0,0 +1,83 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::PodsPlayKubeActionView)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/pods_play_kube_action_view.ui")]
+    pub(crate) struct PodsPlayKubeActionView {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) action: glib::WeakRef<model::PodsPlayKubeAction>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for PodsPlayKubeActionView {
+        const NAME: &'static str = "PdsPodsPlayKubeActionView";
+        type Type = super::PodsPlayKubeActionView;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for PodsPlayKubeActionView {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for PodsPlayKubeActionView {}
+}
+
+glib::wrapper! {
+    pub(crate) struct PodsPlayKubeActionView(ObjectSubclass<imp::PodsPlayKubeActionView>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::PodsPlayKubeAction> for view::ActionDialog {
+    fn from(value: &model::PodsPlayKubeAction) -> Self {
+        let opts = value.opts();
+
+        Self::new(
+            value.upcast_ref(),
+            &if opts.down {
+                gettext("Tear Down Kubernetes YAML")
+            } else {
+                gettext("Play Kubernetes YAML")
+            },
+            Some(&opts.file),
+            &glib::Object::builder::<PodsPlayKubeActionView>()
+                .property("action", value)
+                .build(),
+            400,
+        )
+    }
+}
@@ -0,0 +1,183 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_PLAY: &str = "pods-play-kube-opts-dialog.play";
+const ACTION_SELECT_HOST_PATH: &str = "pods-play-kube-opts-dialog.select-host-path";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::PodsPlayKubeOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/pods_play_kube_opts_dialog.ui")]
+    pub(crate) struct PodsPlayKubeOptsDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) opts: OnceCell<model::BoxedPodsPlayKubeOpts>,
+
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) networks_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) replace_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) start_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) down_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for PodsPlayKubeOptsDialog {
+        const NAME: &'static str = "PdsPodsPlayKubeOptsDialog";
+        type Type = super::PodsPlayKubeOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_PLAY, None, |widget, _, _| {
+                widget.close_and_play();
+            });
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for PodsPlayKubeOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            let opts = obj.opts();
+
+            self.host_path_row.set_subtitle(&opts.file);
+            self.networks_entry_row.set_text(&opts.networks.join(","));
+            self.replace_switch_row.set_active(opts.replace);
+            self.start_switch_row.set_active(opts.start);
+            self.down_switch_row.set_active(opts.down);
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for PodsPlayKubeOptsDialog {}
+
+    impl AdwDialogImpl for PodsPlayKubeOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl PodsPlayKubeOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = self
+                .host_path_row
+                .subtitle()
+                .map(|s| !s.is_empty())
+                .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_PLAY, enabled);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct PodsPlayKubeOptsDialog(ObjectSubclass<imp::PodsPlayKubeOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl PodsPlayKubeOptsDialog {
+    pub(crate) fn new(client: &model::Client, opts: Option<model::BoxedPodsPlayKubeOpts>) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("opts", opts.unwrap_or_default())
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Kubernetes YAML").as_str())
+            .filter(FileFilter::new("YAML").mimetype("application/yaml"))
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_play(&self) {
+        self.close();
+
+        let Some(action_list) = self.client().map(|client| client.action_list()) else {
+            return;
+        };
+
+        let imp = self.imp();
+
+        let opts = engine::opts::PodsPlayKubeOpts {
+            file: imp.host_path_row.subtitle().unwrap_or_default().into(),
+            down: imp.down_switch_row.is_active(),
+            networks: imp
+                .networks_entry_row
+                .text()
+                .split(',')
+                .map(str::trim)
+                .filter(|network| !network.is_empty())
+                .map(ToOwned::to_owned)
+                .collect(),
+            replace: imp.replace_switch_row.is_active(),
+            start: imp.start_switch_row.is_active(),
+        };
+
+        view::ActionDialog::from(&action_list.play_kube(opts)).present(Some(self));
+    }
+}
@@ -0,0 +1,241 @@
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::subclass::Signal;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::engine;
+use crate::keyring;
+use crate::model;
+use crate::registry;
+use crate::rt;
+use crate::utils;
+
+const ACTION_SAVE: &str = "registry-account-dialog.save";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::RegistryAccountDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/registry_account_dialog.ui")]
+    pub(crate) struct RegistryAccountDialog {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) account: RefCell<Option<model::RegistryAccount>>,
+
+        #[template_child]
+        pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) save_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) host_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) auth_toggle_group: TemplateChild<adw::ToggleGroup>,
+        #[template_child]
+        pub(super) username_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) password_entry_row: TemplateChild<adw::PasswordEntryRow>,
+        #[template_child]
+        pub(super) token_entry_row: TemplateChild<adw::PasswordEntryRow>,
+        #[template_child]
+        pub(super) validate_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for RegistryAccountDialog {
+        const NAME: &'static str = "PdsRegistryAccountDialog";
+        type Type = super::RegistryAccountDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_SAVE, None, async |widget, _, _| {
+                widget.save().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for RegistryAccountDialog {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("saved").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.auth_toggle_group.connect_active_name_notify(clone!(
+                #[weak]
+                obj,
+                move |toggle_group| {
+                    let is_basic_enabled = toggle_group.active_name().as_deref() == Some("basic");
+
+                    let imp = obj.imp();
+                    imp.username_entry_row.set_visible(is_basic_enabled);
+                    imp.password_entry_row.set_visible(is_basic_enabled);
+                    imp.token_entry_row.set_visible(!is_basic_enabled);
+
+                    imp.on_entry_row_changed();
+                }
+            ));
+
+            if let Some(account) = obj.account() {
+                self.window_title
+                    .set_title(&gettext("Edit Registry Account"));
+
+                self.host_entry_row.set_text(&account.host());
+                self.host_entry_row.set_sensitive(false);
+
+                match &*account.credentials() {
+                    engine::auth::Credentials::BasicAuth { username, password } => {
+                        self.auth_toggle_group.set_active_name(Some("basic"));
+                        self.username_entry_row.set_text(username);
+                        self.password_entry_row.set_text(password);
+                    }
+                    engine::auth::Credentials::IdentityToken(token) => {
+                        self.auth_toggle_group.set_active_name(Some("token"));
+                        self.token_entry_row.set_text(token);
+                    }
+                }
+            }
+
+            self.on_entry_row_changed();
+        }
+    }
+
+    impl WidgetImpl for RegistryAccountDialog {
+        fn map(&self) {
+            self.parent_map();
+
+            if self.host_entry_row.is_sensitive() {
+                self.host_entry_row.grab_focus();
+            } else {
+                self.username_entry_row.grab_focus();
+            }
+        }
+    }
+
+    impl AdwDialogImpl for RegistryAccountDialog {}
+
+    #[gtk::template_callbacks]
+    impl RegistryAccountDialog {
+        #[template_callback]
+        fn on_entry_row_changed(&self) {
+            let is_basic_enabled = self.auth_toggle_group.active_name().as_deref() == Some("basic");
+
+            self.obj().action_set_enabled(
+                ACTION_SAVE,
+                !self.host_entry_row.text().trim().is_empty()
+                    && if is_basic_enabled {
+                        !self.username_entry_row.text().is_empty()
+                    } else {
+                        !self.token_entry_row.text().is_empty()
+                    },
+            );
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct RegistryAccountDialog(ObjectSubclass<imp::RegistryAccountDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl From<Option<&model::RegistryAccount>> for RegistryAccountDialog {
+    fn from(account: Option<&model::RegistryAccount>) -> Self {
+        glib::Object::builder().property("account", account).build()
+    }
+}
+
+impl RegistryAccountDialog {
+    async fn save(&self) {
+        let imp = self.imp();
+
+        let host = imp.host_entry_row.text().trim().to_owned();
+        let credentials = if imp.auth_toggle_group.active_name().as_deref() == Some("basic") {
+            engine::auth::Credentials::BasicAuth {
+                username: imp.username_entry_row.text().into(),
+                password: imp.password_entry_row.text().into(),
+            }
+        } else {
+            engine::auth::Credentials::IdentityToken(imp.token_entry_row.text().into())
+        };
+
+        if imp.validate_switch_row.is_active() {
+            imp.save_stack.set_visible_child_name("validating");
+            self.action_set_enabled(ACTION_SAVE, false);
+
+            let result = registry::validate_credentials(&host, &credentials).await;
+
+            imp.save_stack.set_visible_child_name("save");
+            self.action_set_enabled(ACTION_SAVE, true);
+
+            if let Err(e) = result {
+                utils::show_error_toast(
+                    &*imp.toast_overlay,
+                    &gettext("Error on validating credentials"),
+                    &e.to_string(),
+                );
+                return;
+            }
+        }
+
+        match rt::Promise::new(async move {
+            keyring::store_registry_credentials(&host, &credentials).await
+        })
+        .exec()
+        .await
+        {
+            Ok(()) => {
+                self.emit_by_name::<()>("saved", &[]);
+                self.close();
+            }
+            Err(e) => {
+                log::error!("Error on saving credentials: {e}");
+                utils::show_error_toast(
+                    &*imp.toast_overlay,
+                    &gettext("Error saving credentials"),
+                    &e.to_string(),
+                );
+            }
+        }
+    }
+
+    pub(crate) fn connect_saved<F: Fn(&Self) + 'static>(&self, f: F) -> glib::SignalHandlerId {
+        self.connect_local("saved", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
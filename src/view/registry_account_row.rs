@@ -0,0 +1,116 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::registry;
+
+const ACTION_VALIDATE: &str = "registry-account-row.validate";
+const ACTION_REMOVE: &str = "registry-account-row.remove";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::RegistryAccountRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/registry_account_row.ui")]
+    pub(crate) struct RegistryAccountRow {
+        #[property(get, set, construct_only)]
+        pub(super) account: OnceCell<model::RegistryAccount>,
+
+        #[template_child]
+        pub(super) validation_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) invalid_image: TemplateChild<gtk::Image>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for RegistryAccountRow {
+        const NAME: &'static str = "PdsRegistryAccountRow";
+        type Type = super::RegistryAccountRow;
+        type ParentType = adw::ActionRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action_async(ACTION_VALIDATE, None, async |widget, _, _| {
+                widget.validate().await;
+            });
+            klass.install_action(ACTION_REMOVE, None, |widget, _, _| {
+                widget.account().remove_request();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for RegistryAccountRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            obj.set_subtitle(
+                &obj.account()
+                    .username()
+                    .unwrap_or_else(|| gettext("Identity Token")),
+            );
+        }
+    }
+
+    impl WidgetImpl for RegistryAccountRow {}
+    impl ListBoxRowImpl for RegistryAccountRow {}
+    impl PreferencesRowImpl for RegistryAccountRow {}
+    impl ActionRowImpl for RegistryAccountRow {}
+}
+
+glib::wrapper! {
+    pub(crate) struct RegistryAccountRow(ObjectSubclass<imp::RegistryAccountRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ActionRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::RegistryAccount> for RegistryAccountRow {
+    fn from(account: &model::RegistryAccount) -> Self {
+        glib::Object::builder().property("account", account).build()
+    }
+}
+
+impl RegistryAccountRow {
+    async fn validate(&self) {
+        let imp = self.imp();
+        let account = self.account();
+
+        imp.validation_stack.set_visible_child_name("validating");
+
+        match registry::validate_credentials(&account.host(), &account.credentials()).await {
+            Ok(()) => imp.validation_stack.set_visible_child_name("valid"),
+            Err(e) => {
+                log::warn!(
+                    "Error on validating credentials for {}: {e}",
+                    account.host()
+                );
+                imp.invalid_image.set_tooltip_text(Some(&e.to_string()));
+                imp.validation_stack.set_visible_child_name("invalid");
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::keyring;
+use crate::model;
+use crate::registry;
+use crate::rt;
+use crate::utils;
+use crate::view;
+
+const ACTION_ADD: &str = "registry-accounts-dialog.add";
+const ACTION_IMPORT_DOCKER: &str = "registry-accounts-dialog.import-docker";
+const ACTION_IMPORT_PODMAN: &str = "registry-accounts-dialog.import-podman";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/registry_accounts_dialog.ui")]
+    pub(crate) struct RegistryAccountsDialog {
+        pub(super) accounts: OnceCell<gio::ListStore>,
+
+        #[template_child]
+        pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for RegistryAccountsDialog {
+        const NAME: &'static str = "PdsRegistryAccountsDialog";
+        type Type = super::RegistryAccountsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_ADD, None, |widget, _, _| {
+                widget.add_account();
+            });
+            klass.install_action(ACTION_IMPORT_DOCKER, None, |widget, _, _| {
+                widget.import(registry::AuthFile::Docker);
+            });
+            klass.install_action(ACTION_IMPORT_PODMAN, None, |widget, _, _| {
+                widget.import(registry::AuthFile::Podman);
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for RegistryAccountsDialog {
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            if crate::KEYRING.get().is_none() {
+                self.stack.set_visible_child_name("unavailable");
+                obj.action_set_enabled(ACTION_ADD, false);
+                obj.action_set_enabled(ACTION_IMPORT_DOCKER, false);
+                obj.action_set_enabled(ACTION_IMPORT_PODMAN, false);
+                return;
+            }
+
+            let accounts = self.accounts();
+
+            self.list_box.bind_model(Some(accounts), |item| {
+                view::RegistryAccountRow::from(
+                    item.downcast_ref::<model::RegistryAccount>().unwrap(),
+                )
+                .upcast()
+            });
+
+            accounts.connect_items_changed(clone!(
+                #[weak]
+                obj,
+                move |accounts, _, _, _| {
+                    obj.imp()
+                        .stack
+                        .set_visible_child_name(if accounts.n_items() > 0 {
+                            "accounts"
+                        } else {
+                            "empty"
+                        });
+                }
+            ));
+
+            self.stack.set_visible_child_name("empty");
+            obj.reload();
+        }
+    }
+
+    impl WidgetImpl for RegistryAccountsDialog {}
+    impl AdwDialogImpl for RegistryAccountsDialog {}
+
+    #[gtk::template_callbacks]
+    impl RegistryAccountsDialog {
+        #[template_callback]
+        fn on_list_box_row_activated(&self, row: &gtk::ListBoxRow) {
+            let Some(row) = row.downcast_ref::<view::RegistryAccountRow>() else {
+                return;
+            };
+
+            self.obj().edit_account(Some(&row.account()));
+        }
+    }
+
+    impl RegistryAccountsDialog {
+        pub(super) fn accounts(&self) -> &gio::ListStore {
+            self.accounts
+                .get_or_init(gio::ListStore::new::<model::RegistryAccount>)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct RegistryAccountsDialog(ObjectSubclass<imp::RegistryAccountsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl Default for RegistryAccountsDialog {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
+impl RegistryAccountsDialog {
+    fn reload(&self) {
+        rt::Promise::new(keyring::registry_accounts()).defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok(accounts) => {
+                    let accounts = accounts
+                        .into_iter()
+                        .map(|(host, credentials)| {
+                            let account = model::RegistryAccount::new(&host, credentials);
+                            account.connect_remove_request(clone!(
+                                #[weak]
+                                obj,
+                                move |account| obj.remove_account(account)
+                            ));
+                            account
+                        })
+                        .collect::<Vec<_>>();
+
+                    let store = obj.imp().accounts();
+                    store.splice(0, store.n_items(), &accounts);
+                }
+                Err(e) => {
+                    log::error!("Error on reading registry accounts: {e}");
+                    utils::show_error_toast(
+                        &*obj.imp().toast_overlay,
+                        &gettext("Error on reading registry accounts"),
+                        &e.to_string(),
+                    );
+                }
+            }
+        ));
+    }
+
+    fn add_account(&self) {
+        self.edit_account(None);
+    }
+
+    fn edit_account(&self, account: Option<&model::RegistryAccount>) {
+        let dialog = view::RegistryAccountDialog::from(account);
+        dialog.connect_saved(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |_| obj.reload()
+        ));
+        dialog.present(Some(self));
+    }
+
+    fn remove_account(&self, account: &model::RegistryAccount) {
+        let host = account.host();
+
+        rt::Promise::new(async move { keyring::delete_registry_credentials(&host).await }).defer(
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| match result {
+                    Ok(()) => obj.reload(),
+                    Err(e) => {
+                        log::error!("Error on removing registry account: {e}");
+                        utils::show_error_toast(
+                            &*obj.imp().toast_overlay,
+                            &gettext("Error on removing registry account"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+            ),
+        );
+    }
+
+    fn import(&self, auth_file: registry::AuthFile) {
+        rt::Promise::new(async move {
+            let accounts = auth_file.read().await?;
+            for (host, credentials) in &accounts {
+                keyring::store_registry_credentials(host, credentials).await?;
+            }
+            Ok::<_, anyhow::Error>(accounts.len() as u32)
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                let imp = obj.imp();
+
+                match result {
+                    Ok(count) => {
+                        utils::show_toast(
+                            &*imp.toast_overlay,
+                            ngettext!("Imported {} account", "Imported {} accounts", count, count),
+                        );
+                        obj.reload();
+                    }
+                    Err(e) => {
+                        log::error!("Error on importing registry accounts: {e}");
+                        utils::show_error_toast(
+                            &*imp.toast_overlay,
+                            &gettext("Error on importing registry accounts"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+            }
+        ));
+    }
+}
@@ -8,8 +8,6 @@ use glib::Properties;
 use glib::clone;
 use gtk::CompositeTemplate;
 use gtk::glib;
-use serde::Deserialize;
-use serde::Serialize;
 
 use crate::engine;
 use crate::model;
@@ -19,12 +17,6 @@ use crate::view;
 
 const ACTION_PUSH: &str = "repo-tag-push-opts-dialog.push";
 
-#[derive(Debug, Serialize, Deserialize)]
-enum RegistryAuth {
-    Basic { username: String, password: String },
-    Token(String),
-}
-
 mod imp {
     use super::*;
 
@@ -129,7 +121,7 @@ mod imp {
                                     let item = items.first()?;
                                     Some(item.secret().await.map_err(anyhow::Error::from).and_then(
                                         |secret| {
-                                            serde_json::from_slice::<RegistryAuth>(
+                                            serde_json::from_slice::<engine::auth::Credentials>(
                                                 secret.as_bytes(),
                                             )
                                             .map_err(anyhow::Error::from)
@@ -154,13 +146,16 @@ mod imp {
                                             imp.save_credentials_switch_row.set_active(true);
 
                                             match auth {
-                                                RegistryAuth::Basic { username, password } => {
+                                                engine::auth::Credentials::BasicAuth {
+                                                    username,
+                                                    password,
+                                                } => {
                                                     imp.auth_toggle_group
                                                         .set_active_name(Some("basic"));
                                                     imp.username_entry_row.set_text(&username);
                                                     imp.password_entry_row.set_text(&password);
                                                 }
-                                                RegistryAuth::Token(token) => {
+                                                engine::auth::Credentials::IdentityToken(token) => {
                                                     imp.auth_toggle_group
                                                         .set_active_name(Some("token"));
                                                     imp.token_entry_row.set_text(&token);
@@ -266,26 +261,31 @@ impl RepoTagPushOptsDialog {
         let credentials = if imp.login_switch.is_active() {
             let repo_tag = repo_tag.full();
 
+            let credentials = if imp.auth_toggle_group.active_name().as_deref() == Some("basic") {
+                engine::auth::Credentials::BasicAuth {
+                    username: imp.username_entry_row.text().into(),
+                    password: imp.password_entry_row.text().into(),
+                }
+            } else {
+                engine::auth::Credentials::IdentityToken(imp.token_entry_row.text().into())
+            };
+
             if imp.save_credentials_switch_row.is_active() {
                 match crate::KEYRING.get() {
                     Some(keyring) => {
-                        let secret =
-                            if imp.auth_toggle_group.active_name().as_deref() == Some("basic") {
-                                RegistryAuth::Basic {
-                                    username: imp.username_entry_row.text().into(),
-                                    password: imp.password_entry_row.text().into(),
-                                }
-                            } else {
-                                RegistryAuth::Token(imp.token_entry_row.text().into())
-                            };
+                        let secret = serde_json::to_vec(&credentials).unwrap();
 
                         rt::Promise::new({
                             async move {
+                                let registry = engine::auth::registry_host(&repo_tag).to_owned();
                                 keyring
                                     .create_item(
                                         &repo_tag,
-                                        &HashMap::from([("repo-tag", &repo_tag)]),
-                                        serde_json::to_vec(&secret).unwrap(),
+                                        &HashMap::from([
+                                            ("repo-tag", repo_tag.as_str()),
+                                            (crate::keyring::REGISTRY_ATTRIBUTE, registry.as_str()),
+                                        ]),
+                                        secret,
                                         true,
                                     )
                                     .await
@@ -317,16 +317,7 @@ impl RepoTagPushOptsDialog {
                 .spawn();
             }
 
-            Some(
-                if imp.auth_toggle_group.active_name().as_deref() == Some("basic") {
-                    engine::auth::Credentials::BasicAuth {
-                        username: imp.username_entry_row.text().into(),
-                        password: imp.password_entry_row.text().into(),
-                    }
-                } else {
-                    engine::auth::Credentials::IdentityToken(imp.token_entry_row.text().into())
-                },
-            )
+            Some(credentials)
         } else {
             None
         };
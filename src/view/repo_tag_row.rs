@@ -178,6 +178,7 @@ impl RepoTagRow {
 
         action_list.pull_image(engine::opts::ImagePullOpts {
             reference: repo_tag.full(),
+            ..Default::default()
         });
     }
 
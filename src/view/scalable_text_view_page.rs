@@ -1,9 +1,11 @@
 use std::borrow::Cow;
 use std::cell::OnceCell;
+use std::cell::RefCell;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
 use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::OpenFileRequest;
 use ashpd::desktop::file_chooser::SaveFileRequest;
 use gettextrs::gettext;
 use gtk::CompositeTemplate;
@@ -22,6 +24,7 @@ use crate::widget;
 const ACTION_TOGGLE_SEARCH: &str = "source-view-page.toggle-search";
 const ACTION_EXIT_SEARCH: &str = "source-view-page.exit-search";
 const ACTION_SAVE_TO_FILE: &str = "source-view-page.save-to-file";
+const ACTION_INSTALL_UNITS: &str = "source-view-page.install-units";
 const ACTION_ZOOM_OUT: &str = "source-view-page.zoom-out";
 const ACTION_ZOOM_IN: &str = "source-view-page.zoom-in";
 const ACTION_ZOOM_NORMAL: &str = "source-view-page.zoom-normal";
@@ -38,6 +41,8 @@ pub(crate) enum Entity {
         mode: Mode,
     },
     Volume(glib::WeakRef<model::Volume>),
+    Network(glib::WeakRef<model::Network>),
+    Secret(glib::WeakRef<model::Secret>),
     Info(glib::WeakRef<model::Info>),
 }
 impl Entity {
@@ -57,23 +62,82 @@ impl Entity {
             Self::Volume(volume) => {
                 Cow::Owned(format!("{}.json", volume.upgrade().unwrap().name()))
             }
+            Self::Network(network) => {
+                Cow::Owned(format!("{}.json", network.upgrade().unwrap().name()))
+            }
+            Self::Secret(secret) => {
+                Cow::Owned(format!("{}.json", secret.upgrade().unwrap().name()))
+            }
             Self::Info(_) => Cow::Borrowed("info.json"),
         }
     }
+
+    fn client(&self) -> Option<model::Client> {
+        match self {
+            Self::Container { container, .. } => container
+                .upgrade()
+                .and_then(|container| container.container_list())
+                .and_then(|container_list| container_list.client()),
+            Self::Pod { pod, .. } => pod
+                .upgrade()
+                .and_then(|pod| pod.pod_list())
+                .and_then(|pod_list| pod_list.client()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum Mode {
     Inspect,
     Kube,
+    Quadlet,
+    Systemd,
 }
 impl Mode {
     fn file_ext(&self) -> &str {
         match self {
             Self::Inspect => "json",
             Self::Kube => "yaml",
+            Self::Quadlet => "container",
+            Self::Systemd => "service",
         }
     }
+
+    fn language(&self) -> &str {
+        match self {
+            Self::Inspect => "json",
+            Self::Kube => "yaml",
+            Self::Quadlet | Self::Systemd => "ini",
+        }
+    }
+
+    /// The directory below the home directory to install generated units into.
+    fn install_dir(&self) -> Option<&str> {
+        match self {
+            Self::Inspect | Self::Kube => None,
+            Self::Quadlet => Some(engine::quadlet::QUADLET_DIR),
+            Self::Systemd => Some(engine::quadlet::SYSTEMD_USER_DIR),
+        }
+    }
+}
+
+/// The loaded content, which is either plain text or a set of generated unit files.
+enum Content {
+    Text(String),
+    Units(Vec<engine::dto::UnitFile>),
+}
+
+impl From<String> for Content {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<engine::dto::UnitFile>> for Content {
+    fn from(value: Vec<engine::dto::UnitFile>) -> Self {
+        Self::Units(value)
+    }
 }
 
 mod imp {
@@ -83,6 +147,7 @@ mod imp {
     #[template(resource = "/com/github/marhkb/Pods/ui/view/scalable_text_view_page.ui")]
     pub(crate) struct ScalableTextViewPage {
         pub(super) entity: OnceCell<Entity>,
+        pub(super) units: RefCell<Vec<engine::dto::UnitFile>>,
         #[template_child]
         pub(super) zoom_control: TemplateChild<widget::ZoomControl>,
         #[template_child]
@@ -135,6 +200,10 @@ mod imp {
                 widget.save_to_file().await;
             });
 
+            klass.install_action(ACTION_INSTALL_UNITS, None, |widget, _, _| {
+                widget.install_units();
+            });
+
             klass.install_action(ACTION_ZOOM_OUT, None, |widget, _, _| {
                 widget.imp().source_view.zoom_out();
             });
@@ -281,6 +350,8 @@ impl From<Entity> for ScalableTextViewPage {
                 imp.window_title.set_title(&match mode {
                     Mode::Inspect => gettext("Container Inspection"),
                     Mode::Kube => gettext("Container Kube Generation"),
+                    Mode::Quadlet => gettext("Container Quadlet Generation"),
+                    Mode::Systemd => gettext("Container Systemd Generation"),
                 });
                 model::Container::this_expression("name").bind(
                     &*imp.window_title,
@@ -292,6 +363,8 @@ impl From<Entity> for ScalableTextViewPage {
                 imp.window_title.set_title(&match mode {
                     Mode::Inspect => gettext("Pod Inspection"),
                     Mode::Kube => gettext("Pod Kube Generation"),
+                    Mode::Quadlet => gettext("Pod Quadlet Generation"),
+                    Mode::Systemd => gettext("Pod Systemd Generation"),
                 });
                 if let Some(pod) = pod.upgrade() {
                     imp.window_title.set_subtitle(&pod.name());
@@ -304,27 +377,36 @@ impl From<Entity> for ScalableTextViewPage {
                         .set_subtitle(utils::format_volume_name(&volume.name()));
                 }
             }
+            Entity::Network(network) => {
+                imp.window_title.set_title(&gettext("Network Inspection"));
+                if let Some(network) = network.upgrade() {
+                    imp.window_title.set_subtitle(&network.name());
+                }
+            }
+            Entity::Secret(secret) => {
+                imp.window_title.set_title(&gettext("Secret Inspection"));
+                if let Some(secret) = secret.upgrade() {
+                    imp.window_title.set_subtitle(&secret.name());
+                }
+            }
             Entity::Info(info) => {
                 imp.window_title.set_title(&gettext("Detailed Information"));
                 if let Some(info) = info.upgrade().and_then(|info| info.client()) {
                     imp.window_title.set_subtitle(&match *info.engine() {
                         engine::Engine::Docker(_) => gettext("Docker"),
-                        engine::Engine::Podman(_) => gettext("Podman"),
+                        engine::Engine::Podman(..) => gettext("Podman"),
                     });
                 }
             }
         }
 
         let language = match &entity {
-            Entity::Image(_) | Entity::Volume(_) | Entity::Info(_) => "json",
-            Entity::Container { mode, .. } => match mode {
-                Mode::Inspect => "json",
-                Mode::Kube => "yaml",
-            },
-            Entity::Pod { mode, .. } => match mode {
-                Mode::Inspect => "json",
-                Mode::Kube => "yaml",
-            },
+            Entity::Image(_)
+            | Entity::Volume(_)
+            | Entity::Network(_)
+            | Entity::Secret(_)
+            | Entity::Info(_) => "json",
+            Entity::Container { mode, .. } | Entity::Pod { mode, .. } => mode.language(),
         };
 
         match sourceview5::LanguageManager::default().language(language) {
@@ -346,41 +428,108 @@ impl From<Entity> for ScalableTextViewPage {
                 }
             }
             Entity::Container { container, mode } => {
-                if let Some(api) = container.upgrade().and_then(|container| container.api()) {
-                    rt::Promise::new(async move {
-                        match mode {
-                            Mode::Inspect => api.json().await,
-                            Mode::Kube => api.generate_kube_yaml(false).await,
+                if let Some(container) = container.upgrade() {
+                    match mode {
+                        Mode::Inspect | Mode::Kube | Mode::Systemd => {
+                            if let Some(api) = container.api() {
+                                rt::Promise::new(async move {
+                                    match mode {
+                                        Mode::Inspect => api.json().await.map(Content::from),
+                                        Mode::Kube => {
+                                            api.generate_kube_yaml(false).await.map(Content::from)
+                                        }
+                                        _ => api.generate_systemd_units().await.map(Content::from),
+                                    }
+                                })
+                                .defer(clone!(
+                                    #[weak]
+                                    obj,
+                                    move |result| obj.init(result, mode)
+                                ));
+                            }
                         }
-                    })
-                    .defer(clone!(
+                        Mode::Quadlet => {
+                            if let Some(client) = entity.client() {
+                                let engine = (*client.engine()).clone();
+                                let id = container.id();
+                                rt::Promise::new(async move {
+                                    engine
+                                        .generate_container_quadlet(&id)
+                                        .await
+                                        .map(Content::from)
+                                })
+                                .defer(clone!(
+                                    #[weak]
+                                    obj,
+                                    move |result| obj.init(result, mode)
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+            Entity::Pod { pod, mode } => {
+                if let Some(pod) = pod.upgrade() {
+                    match mode {
+                        Mode::Inspect | Mode::Kube | Mode::Systemd => {
+                            if let Some(api) = pod.api() {
+                                rt::Promise::new(async move {
+                                    match mode {
+                                        Mode::Inspect => api.json().await.map(Content::from),
+                                        Mode::Kube => {
+                                            api.generate_kube_yaml(false).await.map(Content::from)
+                                        }
+                                        _ => api.generate_systemd_units().await.map(Content::from),
+                                    }
+                                })
+                                .defer(clone!(
+                                    #[weak]
+                                    obj,
+                                    move |result| obj.init(result, mode)
+                                ));
+                            }
+                        }
+                        Mode::Quadlet => {
+                            if let Some(client) = entity.client() {
+                                let engine = (*client.engine()).clone();
+                                let id = pod.id();
+                                rt::Promise::new(async move {
+                                    engine.generate_pod_quadlet(&id).await.map(Content::from)
+                                })
+                                .defer(clone!(
+                                    #[weak]
+                                    obj,
+                                    move |result| obj.init(result, mode)
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+            Entity::Volume(volume) => {
+                if let Some(api) = volume.upgrade().and_then(|volume| volume.api()) {
+                    rt::Promise::new(async move { api.json().await }).defer(clone!(
                         #[weak]
                         obj,
-                        move |result| obj.init(result, mode)
+                        move |result| obj.init(result, Mode::Inspect,)
                     ));
                 }
             }
-            Entity::Pod { pod, mode } => {
-                if let Some(api) = pod.upgrade().and_then(|pod| pod.api()) {
-                    rt::Promise::new(async move {
-                        match mode {
-                            Mode::Inspect => api.json().await,
-                            Mode::Kube => api.generate_kube_yaml(false).await,
-                        }
-                    })
-                    .defer(clone!(
+            Entity::Network(network) => {
+                if let Some(api) = network.upgrade().and_then(|network| network.api()) {
+                    rt::Promise::new(async move { api.json().await }).defer(clone!(
                         #[weak]
                         obj,
-                        move |result| obj.init(result, mode)
+                        move |result| obj.init(result, Mode::Inspect)
                     ));
                 }
             }
-            Entity::Volume(volume) => {
-                if let Some(api) = volume.upgrade().and_then(|volume| volume.api()) {
+            Entity::Secret(secret) => {
+                if let Some(api) = secret.upgrade().and_then(|secret| secret.api()) {
                     rt::Promise::new(async move { api.json().await }).defer(clone!(
                         #[weak]
                         obj,
-                        move |result| obj.init(result, Mode::Inspect,)
+                        move |result| obj.init(result, Mode::Inspect)
                     ));
                 }
             }
@@ -396,16 +545,40 @@ impl From<Entity> for ScalableTextViewPage {
         };
 
         imp.entity.set(entity).unwrap();
+        obj.action_set_enabled(ACTION_INSTALL_UNITS, false);
 
         obj
     }
 }
 
 impl ScalableTextViewPage {
-    fn init(&self, result: anyhow::Result<String>, mode: Mode) {
+    fn init(&self, result: anyhow::Result<impl Into<Content>>, mode: Mode) {
         let imp = self.imp();
-        match result {
-            Ok(text) => {
+        match result.map(Into::into) {
+            Ok(Content::Text(text)) => {
+                imp.source_buffer.set_text(&text);
+                imp.stack.set_visible_child_name("loaded");
+            }
+            Ok(Content::Units(units)) => {
+                let text = match units.as_slice() {
+                    [unit] => unit.content.clone(),
+                    units => units
+                        .iter()
+                        .map(|unit| format!("# {}\n{}", unit.name, unit.content))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                };
+
+                let installable = !units.is_empty()
+                    && imp
+                        .entity
+                        .get()
+                        .and_then(Entity::client)
+                        .map(|client| client.connection().is_local())
+                        .unwrap_or(false);
+                self.action_set_enabled(ACTION_INSTALL_UNITS, installable);
+
+                imp.units.replace(units);
                 imp.source_buffer.set_text(&text);
                 imp.stack.set_visible_child_name("loaded");
             }
@@ -416,6 +589,8 @@ impl ScalableTextViewPage {
                     &match mode {
                         Mode::Inspect => gettext("Inspection error"),
                         Mode::Kube => gettext("Kube generation error"),
+                        Mode::Quadlet => gettext("Quadlet generation error"),
+                        Mode::Systemd => gettext("Systemd generation error"),
                     },
                     &e.to_string(),
                 );
@@ -427,9 +602,19 @@ impl ScalableTextViewPage {
     async fn save_to_file(&self) {
         let imp = self.imp();
 
+        if imp.units.borrow().len() > 1 {
+            self.save_units_to_folder().await;
+            return;
+        }
+
+        let filename = match imp.units.borrow().first() {
+            Some(unit) => unit.name.clone(),
+            None => imp.entity.get().unwrap().filename().into_owned(),
+        };
+
         let request = SaveFileRequest::default()
             .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
-            .current_name(&*imp.entity.get().unwrap().filename())
+            .current_name(filename.as_str())
             .modal(true);
 
         utils::show_save_file_dialog(
@@ -471,6 +656,118 @@ impl ScalableTextViewPage {
         .await;
     }
 
+    /// Writes each unit into its own file, as the units of a pod only work as separate files.
+    async fn save_units_to_folder(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Folder for Unit Files").as_str())
+            .directory(true)
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let Some(dir) = gio::File::for_uri(files.uris()[0].as_str()).path() else {
+                        return;
+                    };
+
+                    let result =
+                        obj.imp().units.borrow().iter().try_for_each(|unit| {
+                            std::fs::write(dir.join(&unit.name), &unit.content)
+                        });
+
+                    match result {
+                        Ok(_) => utils::show_toast(&obj, gettext("Unit files saved")),
+                        Err(e) => utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on saving unit files"),
+                            &e.to_string(),
+                        ),
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn install_units(&self) {
+        let imp = self.imp();
+
+        let Some(Entity::Container { mode, .. } | Entity::Pod { mode, .. }) = imp.entity.get()
+        else {
+            return;
+        };
+        let Some(install_dir) = mode.install_dir() else {
+            return;
+        };
+        let dir = glib::home_dir().join(install_dir);
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Install Unit Files?"))
+            .body_use_markup(true)
+            .body(gettext!(
+                "The following files will be written to <b>{}</b>. Existing files will be overwritten.\n\n{}",
+                glib::markup_escape_text(&dir.to_string_lossy()),
+                glib::markup_escape_text(
+                    &imp.units
+                        .borrow()
+                        .iter()
+                        .map(|unit| unit.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ),
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("install", &gettext("_Install")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("install", adw::ResponseAppearance::Suggested);
+
+        dialog.connect_response(
+            None,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |_, response| {
+                    if response != "install" {
+                        return;
+                    }
+
+                    let result = std::fs::create_dir_all(&dir).and_then(|_| {
+                        obj.imp()
+                            .units
+                            .borrow()
+                            .iter()
+                            .try_for_each(|unit| {
+                                std::fs::write(dir.join(&unit.name), &unit.content)
+                            })
+                    });
+
+                    match result {
+                        Ok(_) => utils::show_toast(
+                            &obj,
+                            gettext("Unit files installed, run 'systemctl --user daemon-reload' to load them"),
+                        ),
+                        Err(e) => utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on installing unit files"),
+                            &e.to_string(),
+                        ),
+                    }
+                }
+            ),
+        );
+
+        dialog.present(Some(self));
+    }
+
     pub(crate) fn set_search_mode(&self, value: bool) {
         self.imp().search_bar.set_search_mode(value);
     }
@@ -0,0 +1,196 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::utils;
+use crate::widget;
+
+const ACTION_CREATE_SECRET: &str = "secret-create-opts-dialog.create-secret";
+const ACTION_SELECT_FILE: &str = "secret-create-opts-dialog.select-file";
+const ACTION_CLEAR_FILE: &str = "secret-create-opts-dialog.clear-file";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretCreateOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secret_create_opts_dialog.ui")]
+    pub(crate) struct SecretCreateOptsDialog {
+        pub(super) file: RefCell<Option<gio::File>>,
+
+        #[property(get, set, construct_only, nullable)]
+        pub(super) secret_list: glib::WeakRef<model::SecretList>,
+
+        #[template_child]
+        pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<widget::RandomNameEntryRow>,
+        #[template_child]
+        pub(super) data_entry_row: TemplateChild<adw::PasswordEntryRow>,
+        #[template_child]
+        pub(super) file_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) clear_file_button: TemplateChild<gtk::Button>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretCreateOptsDialog {
+        const NAME: &'static str = "PdsSecretCreateOptsDialog";
+        type Type = super::SecretCreateOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_CREATE_SECRET, None, |widget, _, _| async move {
+                widget.create().await;
+            });
+            klass.install_action_async(ACTION_SELECT_FILE, None, |widget, _, _| async move {
+                widget.select_file().await;
+            });
+            klass.install_action(ACTION_CLEAR_FILE, None, |widget, _, _| {
+                widget.set_file(None);
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretCreateOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+
+    impl WidgetImpl for SecretCreateOptsDialog {
+        fn map(&self) {
+            self.parent_map();
+            self.name_entry_row.grab_focus();
+        }
+    }
+
+    impl AdwDialogImpl for SecretCreateOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl SecretCreateOptsDialog {
+        #[template_callback]
+        fn on_name_entry_row_notify_text(&self) {
+            self.obj()
+                .action_set_enabled(ACTION_CREATE_SECRET, !self.name_entry_row.text().is_empty());
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretCreateOptsDialog(ObjectSubclass<imp::SecretCreateOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl From<&model::SecretList> for SecretCreateOptsDialog {
+    fn from(secret_list: &model::SecretList) -> Self {
+        glib::Object::builder()
+            .property("secret-list", secret_list)
+            .build()
+    }
+}
+
+impl SecretCreateOptsDialog {
+    fn set_file(&self, file: Option<gio::File>) {
+        let imp = self.imp();
+
+        match file.as_ref().and_then(gio::File::path) {
+            Some(path) => {
+                imp.file_row.set_subtitle(&path.to_string_lossy());
+                imp.clear_file_button.set_visible(true);
+            }
+            None => {
+                imp.file_row.set_subtitle(&gettext("No file selected"));
+                imp.clear_file_button.set_visible(false);
+            }
+        }
+
+        imp.file.replace(file);
+    }
+
+    async fn select_file(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Secret File").as_str())
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    obj.set_file(Some(gio::File::for_uri(files.uris()[0].as_str())));
+                }
+            ),
+        )
+        .await;
+    }
+
+    async fn create(&self) {
+        let Some(secret_list) = self.secret_list() else {
+            return;
+        };
+
+        let imp = self.imp();
+
+        let file = imp.file.borrow().clone();
+        let data = match file {
+            Some(file) => match file.load_contents_future().await {
+                Ok((content, _)) => content.to_vec(),
+                Err(e) => {
+                    utils::show_error_toast(
+                        &*imp.toast_overlay,
+                        &gettext("Error on reading secret file"),
+                        &e.to_string(),
+                    );
+                    return;
+                }
+            },
+            None => imp.data_entry_row.text().as_bytes().to_vec(),
+        };
+
+        let opts = engine::opts::SecretCreateOpts {
+            name: imp.name_entry_row.text().into(),
+            data,
+            ..Default::default()
+        };
+
+        match secret_list.create_secret(opts).await {
+            Ok(_) => self.close(),
+            Err(e) => utils::show_error_toast(
+                &*imp.toast_overlay,
+                &gettext("Error on creating secret"),
+                &e.to_string(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::ExpanderRowImpl;
+use adw::subclass::prelude::PreferencesRowImpl;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+
+const ACTION_REMOVE: &str = "secret-ref-row.remove";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretRefRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secret_ref_row.ui")]
+    pub(crate) struct SecretRefRow {
+        #[property(get, set = Self::set_secret_ref, construct, explicit_notify, nullable)]
+        pub(super) secret_ref: RefCell<Option<model::SecretRef>>,
+        pub(super) bindings: RefCell<Vec<glib::Binding>>,
+        #[template_child]
+        pub(super) secret_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) target_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) secret_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) file_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) env_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) target_entry_row: TemplateChild<adw::EntryRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretRefRow {
+        const NAME: &'static str = "PdsSecretRefRow";
+        type Type = super::SecretRefRow;
+        type ParentType = adw::ExpanderRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_REMOVE, None, |widget, _, _| {
+                if let Some(secret_ref) = widget.secret_ref() {
+                    secret_ref.remove_request();
+                }
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretRefRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let secret_ref_expr = Self::Type::this_expression("secret-ref");
+            let secret_expr = secret_ref_expr.chain_property::<model::SecretRef>("secret");
+            let env_expr = secret_ref_expr.chain_property::<model::SecretRef>("env");
+
+            secret_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, secret: &str| {
+                    if secret.is_empty() { "?" } else { secret }.to_owned()
+                }))
+                .bind(&self.secret_label.get(), "label", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [
+                    &secret_expr,
+                    &env_expr,
+                    &secret_ref_expr.chain_property::<model::SecretRef>("target"),
+                ],
+                closure!(|_: Self::Type, secret: &str, env: bool, target: &str| {
+                    let target = target.trim();
+                    if env {
+                        format!("${}", if target.is_empty() { "?" } else { target })
+                    } else if target.is_empty() {
+                        format!("/run/secrets/{secret}")
+                    } else {
+                        target.to_owned()
+                    }
+                }),
+            )
+            .bind(&self.target_label.get(), "label", Some(obj));
+
+            env_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, env: bool| {
+                    if env {
+                        gettext("Variable Name")
+                    } else {
+                        gettext("Target Path (optional)")
+                    }
+                }))
+                .bind(&self.target_entry_row.get(), "title", Some(obj));
+        }
+    }
+
+    impl WidgetImpl for SecretRefRow {}
+    impl ListBoxRowImpl for SecretRefRow {}
+    impl PreferencesRowImpl for SecretRefRow {}
+    impl ExpanderRowImpl for SecretRefRow {}
+
+    #[gtk::template_callbacks]
+    impl SecretRefRow {
+        #[template_callback]
+        fn on_secret_combo_row_notify_selected_item(&self) {
+            let Some(secret) = self
+                .secret_combo_row
+                .selected_item()
+                .and_downcast::<model::Secret>()
+            else {
+                return;
+            };
+
+            if let Some(secret_ref) = self.obj().secret_ref() {
+                secret_ref.set_secret(secret.name());
+            }
+        }
+
+        pub(super) fn set_secret_ref(&self, value: Option<model::SecretRef>) {
+            let obj = &*self.obj();
+
+            if obj.secret_ref() == value {
+                return;
+            }
+
+            let mut bindings = self.bindings.borrow_mut();
+
+            while let Some(binding) = bindings.pop() {
+                binding.unbind();
+            }
+
+            if let Some(ref secret_ref) = value {
+                let secret = secret_ref.secret();
+
+                let model = secret_ref
+                    .client()
+                    .and_then(|client| client.secret_list())
+                    .map(|secret_list| {
+                        gtk::SortListModel::new(
+                            Some(secret_list),
+                            Some(
+                                gtk::StringSorter::builder()
+                                    .expression(model::Secret::this_expression("name"))
+                                    .build(),
+                            ),
+                        )
+                    });
+                self.secret_combo_row.set_model(model.as_ref());
+
+                // Preselect the first secret for new references.
+                let position = model
+                    .as_ref()
+                    .and_then(|model| {
+                        model
+                            .iter::<glib::Object>()
+                            .map(Result::unwrap)
+                            .map(|item| item.downcast::<model::Secret>().unwrap())
+                            .position(|item| item.name() == secret)
+                    })
+                    .map(|position| position as u32)
+                    .unwrap_or(if secret.is_empty() {
+                        0
+                    } else {
+                        gtk::INVALID_LIST_POSITION
+                    });
+                self.secret_combo_row.set_selected(position);
+
+                let binding = secret_ref
+                    .bind_property("env", &*self.env_button, "active")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+                bindings.push(binding);
+
+                let binding = secret_ref
+                    .bind_property("env", &*self.file_button, "active")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .invert_boolean()
+                    .build();
+                bindings.push(binding);
+
+                let binding = secret_ref
+                    .bind_property("target", &*self.target_entry_row, "text")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+                bindings.push(binding);
+            }
+
+            drop(bindings);
+
+            self.secret_ref.replace(value);
+            self.on_secret_combo_row_notify_selected_item();
+
+            obj.notify_secret_ref();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretRefRow(ObjectSubclass<imp::SecretRefRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ExpanderRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::SecretRef> for SecretRefRow {
+    fn from(secret_ref: &model::SecretRef) -> Self {
+        glib::Object::builder()
+            .property("secret-ref", secret_ref)
+            .build()
+    }
+}
@@ -0,0 +1,208 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_INSPECT_SECRET: &str = "secret-row.inspect-secret";
+const ACTION_DELETE_SECRET: &str = "secret-row.delete-secret";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secret_row.ui")]
+    pub(crate) struct SecretRow {
+        #[property(get, set, construct, nullable)]
+        pub(super) secret: RefCell<Option<model::Secret>>,
+        #[template_child]
+        pub(super) name_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) age_label: TemplateChild<gtk::Label>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretRow {
+        const NAME: &'static str = "PdsSecretRow";
+        type Type = super::SecretRow;
+        type ParentType = gtk::ListBoxRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_INSPECT_SECRET, None, |widget, _, _| {
+                widget.show_inspection();
+            });
+
+            klass.install_action_async(ACTION_DELETE_SECRET, None, async |widget, _, _| {
+                widget.delete_secret().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let ticks_expr = Self::Type::this_expression("root")
+                .chain_property::<gtk::Window>("application")
+                .chain_property::<crate::Application>("ticks");
+
+            let secret_expr = Self::Type::this_expression("secret");
+            let secret_to_be_deleted_expr =
+                secret_expr.chain_property::<model::Secret>("to-be-deleted");
+
+            gtk::ClosureExpression::new::<String>(
+                [
+                    secret_expr
+                        .chain_property::<model::Secret>("name")
+                        .upcast_ref(),
+                    secret_to_be_deleted_expr.upcast_ref(),
+                ],
+                closure!(|_: Self::Type, name: String, to_be_deleted: bool| {
+                    let name = glib::markup_escape_text(&name);
+                    if to_be_deleted {
+                        format!("<s>{name}</s>")
+                    } else {
+                        name.to_string()
+                    }
+                }),
+            )
+            .bind(&*self.name_label, "label", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [
+                    &ticks_expr,
+                    &secret_expr.chain_property::<model::Secret>("driver"),
+                    &secret_expr.chain_property::<model::Secret>("created-at"),
+                ],
+                closure!(
+                    |_: Self::Type, _ticks: u64, driver: String, created_at: i64| {
+                        // Translators: This will resolve to sth. like "file, {a few minutes} old".
+                        gettext!(
+                            "{}, {} old",
+                            driver,
+                            utils::human_friendly_timespan(utils::timespan_now(created_at))
+                        )
+                    }
+                ),
+            )
+            .bind(&*self.age_label, "label", Some(obj));
+
+            secret_to_be_deleted_expr.watch(
+                Some(obj),
+                clone!(
+                    #[weak]
+                    obj,
+                    #[strong]
+                    secret_to_be_deleted_expr,
+                    move || {
+                        let to_be_deleted = secret_to_be_deleted_expr
+                            .evaluate_as::<bool, _>(Some(&obj))
+                            .unwrap();
+                        obj.action_set_enabled(ACTION_INSPECT_SECRET, !to_be_deleted);
+                        obj.action_set_enabled(ACTION_DELETE_SECRET, !to_be_deleted);
+                    }
+                ),
+            );
+        }
+    }
+
+    impl WidgetImpl for SecretRow {}
+    impl ListBoxRowImpl for SecretRow {}
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretRow(ObjectSubclass<imp::SecretRow>)
+        @extends gtk::Widget, gtk::ListBoxRow,
+        @implements gtk::Accessible, gtk::Buildable, gtk::Actionable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Secret> for SecretRow {
+    fn from(secret: &model::Secret) -> Self {
+        glib::Object::builder().property("secret", secret).build()
+    }
+}
+
+impl SecretRow {
+    pub(crate) fn show_inspection(&self) {
+        let Some(secret) = self.secret() else {
+            return;
+        };
+
+        let weak_ref = glib::WeakRef::new();
+        weak_ref.set(Some(&secret));
+
+        utils::navigation_view(self).push(
+            &adw::NavigationPage::builder()
+                .child(&view::ScalableTextViewPage::from(view::Entity::Secret(
+                    weak_ref,
+                )))
+                .build(),
+        );
+    }
+
+    pub(crate) async fn delete_secret(&self) {
+        let Some(secret) = self.secret() else {
+            return;
+        };
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Confirm Secret Deletion"))
+            .body_use_markup(true)
+            .body(gettext!(
+                // Translators: The "{}" is a placeholder for the secret name.
+                "Containers referencing <b>{}</b> can no longer be created or started.",
+                glib::markup_escape_text(&secret.name()),
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("delete", &gettext("_Delete")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+        if "delete" != dialog.choose_future(Some(self)).await {
+            return;
+        }
+
+        if let Err(e) = secret.delete().await {
+            utils::show_error_toast(
+                self,
+                // Translators: The "{}" is a placeholder for the secret name.
+                &gettext!("Error on deleting secret '{}'", &secret.name()),
+                &e.to_string(),
+            );
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::ngettext;
+use glib::Properties;
+use glib::clone;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::gdk;
+use gtk::gio;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_CREATE_SECRET: &str = "secrets-panel.create-secret";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretsPanel)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secrets_panel.ui")]
+    pub(crate) struct SecretsPanel {
+        pub(super) filter: OnceCell<gtk::Filter>,
+        pub(super) search_term: RefCell<String>,
+        #[property(get, set = Self::set_secret_list, nullable)]
+        pub(super) secret_list: glib::WeakRef<model::SecretList>,
+        #[template_child]
+        pub(super) main_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) search_bar: TemplateChild<gtk::SearchBar>,
+        #[template_child]
+        pub(super) search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) filter_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretsPanel {
+        const NAME: &'static str = "PdsSecretsPanel";
+        type Type = super::SecretsPanel;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.add_binding_action(
+                gdk::Key::N,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_CREATE_SECRET,
+            );
+            klass.install_action(ACTION_CREATE_SECRET, None, |widget, _, _| {
+                widget.show_create_dialog();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretsPanel {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let secret_list_expr = Self::Type::this_expression("secret-list");
+            let secret_list_len_expr = secret_list_expr.chain_property::<model::SecretList>("len");
+
+            gtk::ClosureExpression::new::<Option<String>>(
+                [
+                    &secret_list_len_expr,
+                    &secret_list_expr.chain_property::<model::SecretList>("listing"),
+                    &secret_list_expr.chain_property::<model::SecretList>("initialized"),
+                ],
+                closure!(
+                    |_: Self::Type, len: u32, listing: bool, initialized: bool| {
+                        if len == 0 {
+                            if initialized {
+                                Some("empty")
+                            } else if listing {
+                                Some("spinner")
+                            } else {
+                                None
+                            }
+                        } else {
+                            Some("secrets")
+                        }
+                    }
+                ),
+            )
+            .bind(&self.main_stack.get(), "visible-child-name", Some(obj));
+
+            secret_list_len_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, len: u32| {
+                    ngettext!("{} secret", "{} secrets", len, len)
+                }))
+                .bind(&self.window_title.get(), "subtitle", Some(obj));
+
+            let filter = gtk::CustomFilter::new(clone!(
+                #[weak]
+                obj,
+                #[upgrade_or]
+                false,
+                move |item| {
+                    let term = &*obj.imp().search_term.borrow();
+                    item.downcast_ref::<model::Secret>()
+                        .unwrap()
+                        .name()
+                        .to_lowercase()
+                        .contains(term)
+                }
+            ));
+
+            self.filter.set(filter.upcast()).unwrap();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for SecretsPanel {}
+
+    #[gtk::template_callbacks]
+    impl SecretsPanel {
+        #[template_callback]
+        fn on_notify_search_mode_enabled(&self) {
+            if self.search_bar.is_search_mode() {
+                self.search_entry.grab_focus();
+            }
+        }
+
+        #[template_callback]
+        fn on_search_changed(&self) {
+            let term = self.search_entry.text().trim().to_lowercase();
+
+            let filter_change = if self.search_term.borrow().contains(&term) {
+                gtk::FilterChange::LessStrict
+            } else {
+                gtk::FilterChange::MoreStrict
+            };
+
+            self.search_term.replace(term);
+            if let Some(filter) = self.filter.get() {
+                filter.changed(filter_change);
+            }
+        }
+
+        pub(super) fn set_secret_list(&self, value: Option<&model::SecretList>) {
+            let obj = &*self.obj();
+            if obj.secret_list().as_ref() == value {
+                return;
+            }
+
+            self.list_box
+                .bind_model(gio::ListModel::NONE, |_| unreachable!());
+
+            if let Some(value) = value {
+                let model = gtk::SortListModel::new(
+                    Some(gtk::FilterListModel::new(
+                        Some(value.to_owned()),
+                        self.filter.get().cloned(),
+                    )),
+                    Some(gtk::CustomSorter::new(|item1, item2| {
+                        let secret1 = item1.downcast_ref::<model::Secret>().unwrap();
+                        let secret2 = item2.downcast_ref::<model::Secret>().unwrap();
+
+                        secret1
+                            .name()
+                            .to_lowercase()
+                            .cmp(&secret2.name().to_lowercase())
+                            .into()
+                    })),
+                );
+
+                self.list_box.bind_model(Some(&model), |item| {
+                    view::SecretRow::from(item.downcast_ref().unwrap()).upcast()
+                });
+
+                self.set_filter_stack_visible_child(value, &model);
+                model.connect_items_changed(clone!(
+                    #[weak]
+                    obj,
+                    #[weak]
+                    value,
+                    move |model, _, _, _| {
+                        obj.imp().set_filter_stack_visible_child(&value, model);
+                    }
+                ));
+            }
+
+            self.secret_list.set(value);
+            obj.notify_secret_list();
+        }
+
+        fn set_filter_stack_visible_child(
+            &self,
+            secret_list: &model::SecretList,
+            model: &impl IsA<gio::ListModel>,
+        ) {
+            self.filter_stack.set_visible_child_name(
+                if model.n_items() > 0 || !secret_list.initialized() {
+                    "list"
+                } else {
+                    "empty"
+                },
+            );
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretsPanel(ObjectSubclass<imp::SecretsPanel>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for SecretsPanel {
+    fn default() -> Self {
+        glib::Object::new()
+    }
+}
+
+impl SecretsPanel {
+    pub(crate) fn set_search_mode(&self, value: bool) {
+        self.imp().search_bar.set_search_mode(value);
+    }
+
+    pub(crate) fn toggle_search_mode(&self) {
+        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    }
+
+    pub(crate) fn show_create_dialog(&self) {
+        if let Some(secret_list) = self.secret_list() {
+            view::SecretCreateOptsDialog::from(&secret_list).present(Some(self));
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use std::cell::OnceCell;
+use std::collections::HashMap;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
+use gtk::gio;
 use gtk::glib;
 
 use crate::engine;
@@ -11,6 +15,8 @@ use crate::model;
 use crate::view;
 use crate::widget;
 
+const ACTION_ADD_OPTION: &str = "volume-create-opts-dialog.add-option";
+const ACTION_ADD_LABEL: &str = "volume-create-opts-dialog.add-label";
 const ACTION_CREATE_VOLUME: &str = "volume-create-opts-dialog.create-volume";
 
 mod imp {
@@ -20,6 +26,9 @@ mod imp {
     #[properties(wrapper_type = super::VolumeCreateOptsDialog)]
     #[template(resource = "/com/github/marhkb/Pods/ui/view/volume_create_opts_dialog.ui")]
     pub(crate) struct VolumeCreateOptsDialog {
+        pub(super) options: OnceCell<gio::ListStore>,
+        pub(super) labels: OnceCell<gio::ListStore>,
+
         #[property(get, set, construct_only)]
         pub(super) client: glib::WeakRef<model::Client>,
         #[property(get, set, construct_only)]
@@ -27,6 +36,12 @@ mod imp {
 
         #[template_child]
         pub(super) name_entry_row: TemplateChild<widget::RandomNameEntryRow>,
+        #[template_child]
+        pub(super) driver_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) options_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
     }
 
     #[glib::object_subclass]
@@ -38,6 +53,12 @@ mod imp {
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
 
+            klass.install_action(ACTION_ADD_OPTION, None, |widget, _, _| {
+                widget.add_option(None);
+            });
+            klass.install_action(ACTION_ADD_LABEL, None, |widget, _, _| {
+                widget.add_label(None);
+            });
             klass.install_action(ACTION_CREATE_VOLUME, None, |widget, _, _| {
                 widget.close_and_create();
             });
@@ -64,9 +85,48 @@ mod imp {
         fn constructed(&self) {
             self.parent_constructed();
 
-            if let Some(name) = self.obj().opts().name.as_deref() {
+            let obj = &*self.obj();
+
+            bind_model(
+                &self.options_list_box,
+                self.options(),
+                |item| {
+                    view::KeyValRow::new(
+                        &gettext("Option"),
+                        &gettext("Value"),
+                        item.downcast_ref::<model::KeyVal>().unwrap(),
+                    )
+                    .upcast()
+                },
+                ACTION_ADD_OPTION,
+                &gettext("Add Option"),
+            );
+
+            bind_model(
+                &self.labels_list_box,
+                self.labels(),
+                |item| {
+                    view::KeyValRow::from(item.downcast_ref::<model::KeyVal>().unwrap()).upcast()
+                },
+                ACTION_ADD_LABEL,
+                &gettext("Add Label"),
+            );
+
+            let opts = obj.opts();
+
+            if let Some(name) = opts.name.as_deref() {
                 self.name_entry_row.set_text(name);
             }
+            if let Some(driver) = opts.driver.as_deref() {
+                self.driver_entry_row.set_text(driver);
+            }
+
+            opts.options.iter().for_each(|(key, val)| {
+                obj.add_option(Some(model::KeyVal::from((key.as_str(), val.as_str()))))
+            });
+            opts.labels.iter().for_each(|(key, val)| {
+                obj.add_label(Some(model::KeyVal::from((key.as_str(), val.as_str()))))
+            });
         }
     }
 
@@ -78,6 +138,18 @@ mod imp {
     }
 
     impl AdwDialogImpl for VolumeCreateOptsDialog {}
+
+    impl VolumeCreateOptsDialog {
+        pub(super) fn options(&self) -> &gio::ListStore {
+            self.options
+                .get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+
+        pub(super) fn labels(&self) -> &gio::ListStore {
+            self.labels
+                .get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+    }
 }
 
 glib::wrapper! {
@@ -107,11 +179,77 @@ impl VolumeCreateOptsDialog {
             return;
         };
 
+        let imp = self.imp();
+
         let opts = engine::opts::VolumeCreateOpts {
-            name: Some(self.imp().name_entry_row.text().into())
-                .filter(|name: &String| !name.is_empty()),
+            name: Some(imp.name_entry_row.text().into()).filter(|name: &String| !name.is_empty()),
+            driver: Some(imp.driver_entry_row.text().trim().to_owned())
+                .filter(|driver| !driver.is_empty()),
+            options: key_vals(imp.options()),
+            labels: key_vals(imp.labels()),
         };
 
         view::ActionDialog::from(&action_list.create_volume(opts)).present(Some(self));
     }
+
+    fn add_option(&self, option: Option<model::KeyVal>) {
+        add_key_val(self.imp().options(), option);
+    }
+
+    fn add_label(&self, label: Option<model::KeyVal>) {
+        add_key_val(self.imp().labels(), label);
+    }
+}
+
+fn bind_model<F>(
+    list_box: &gtk::ListBox,
+    model: &gio::ListStore,
+    widget_func: F,
+    action_name: &str,
+    label: &str,
+) where
+    F: Fn(&glib::Object) -> gtk::Widget + 'static,
+{
+    list_box.bind_model(Some(model), widget_func);
+    list_box.append(
+        &gtk::ListBoxRow::builder()
+            .action_name(action_name)
+            .selectable(false)
+            .child(
+                &gtk::Label::builder()
+                    .label(label)
+                    .margin_top(12)
+                    .margin_bottom(12)
+                    .build(),
+            )
+            .build(),
+    );
+}
+
+fn add_key_val(model: &gio::ListStore, key_val: Option<model::KeyVal>) -> model::KeyVal {
+    let key_val = key_val.unwrap_or_default();
+
+    key_val.connect_remove_request(clone!(
+        #[weak]
+        model,
+        move |key_val| {
+            if let Some(pos) = model.find(key_val) {
+                model.remove(pos);
+            }
+        }
+    ));
+
+    model.append(&key_val);
+
+    key_val
+}
+
+/// Collects the entries with a non-empty key.
+fn key_vals(model: &gio::ListStore) -> HashMap<String, String> {
+    model
+        .iter::<model::KeyVal>()
+        .map(Result::unwrap)
+        .map(|entry| (entry.key().trim().to_owned(), entry.value()))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
 }
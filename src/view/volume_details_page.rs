@@ -1,4 +1,6 @@
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
@@ -16,6 +18,9 @@ use crate::view;
 
 const ACTION_INSPECT_VOLUME: &str = "volume-details-page.inspect-volume";
 const ACTION_DELETE_VOLUME: &str = "volume-details-page.delete-volume";
+const ACTION_SHOW_FILES: &str = "volume-details-page.show-files";
+const ACTION_EXPORT_VOLUME: &str = "volume-details-page.export-volume";
+const ACTION_IMPORT_VOLUME: &str = "volume-details-page.import-volume";
 
 mod imp {
     use super::*;
@@ -37,6 +42,14 @@ mod imp {
         pub(super) driver_row: TemplateChild<adw::ActionRow>,
         #[template_child]
         pub(super) mountpoint_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) options_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) options_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) labels_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
     }
 
     #[glib::object_subclass]
@@ -56,6 +69,18 @@ mod imp {
                 widget.delete_volume().await;
             });
 
+            klass.install_action_async(ACTION_SHOW_FILES, None, async |widget, _, _| {
+                widget.show_files().await;
+            });
+
+            klass.install_action_async(ACTION_EXPORT_VOLUME, None, async |widget, _, _| {
+                widget.export_volume().await;
+            });
+
+            klass.install_action_async(ACTION_IMPORT_VOLUME, None, async |widget, _, _| {
+                widget.import_volume().await;
+            });
+
             klass.add_binding_action(
                 gdk::Key::N,
                 gdk::ModifierType::CONTROL_MASK,
@@ -181,12 +206,47 @@ mod imp {
                 self.handler_id.replace(Some(handler_id));
             }
 
+            set_key_val_rows(
+                &self.options_group,
+                &self.options_list_box,
+                value.map(|volume| volume.options()).as_deref(),
+            );
+            set_key_val_rows(
+                &self.labels_group,
+                &self.labels_list_box,
+                value.map(|volume| volume.labels()).as_deref(),
+            );
+
             self.volume.set(value);
             obj.notify("volume");
         }
     }
 }
 
+fn set_key_val_rows(
+    group: &adw::PreferencesGroup,
+    list_box: &gtk::ListBox,
+    map: Option<&HashMap<String, String>>,
+) {
+    list_box.remove_all();
+
+    let map = map.into_iter().flatten().collect::<BTreeMap<_, _>>();
+
+    map.iter().for_each(|(key, value)| {
+        list_box.append(
+            &adw::ActionRow::builder()
+                .css_classes(["property"])
+                .title(*key)
+                .subtitle(*value)
+                .subtitle_selectable(true)
+                .use_markup(false)
+                .build(),
+        );
+    });
+
+    group.set_visible(!map.is_empty());
+}
+
 glib::wrapper! {
     pub(crate) struct VolumeDetailsPage(ObjectSubclass<imp::VolumeDetailsPage>)
         @extends gtk::Widget,
@@ -220,6 +280,37 @@ impl VolumeDetailsPage {
         .await;
     }
 
+    pub(crate) async fn show_files(&self) {
+        self.exec_action(async || {
+            if let Some(volume) = self.volume() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::VolumeFilesPage::from(&volume))
+                        .build(),
+                );
+            }
+        })
+        .await;
+    }
+
+    pub(crate) async fn export_volume(&self) {
+        self.exec_action(async || {
+            if let Some(volume) = self.volume() {
+                view::VolumeExportOptsDialog::new(&volume, "").present(Some(self));
+            }
+        })
+        .await;
+    }
+
+    pub(crate) async fn import_volume(&self) {
+        self.exec_action(async || {
+            if let Some(volume) = self.volume() {
+                view::VolumeImportOptsDialog::new(&volume, "").present(Some(self));
+            }
+        })
+        .await;
+    }
+
     pub(crate) async fn delete_volume(&self) {
         self.exec_action(async || {
             view::volume::delete_volume_show_confirmation(self, self.volume().as_ref()).await;
@@ -0,0 +1,289 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::engine;
+use crate::model;
+use crate::rt;
+use crate::utils;
+
+const ACTION_GO_UP: &str = "volume-files-page.go-up";
+const ACTION_REFRESH: &str = "volume-files-page.refresh";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::VolumeFilesPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/volume_files_page.ui")]
+    pub(crate) struct VolumeFilesPage {
+        /// the container mounting the volume, which lives as long as the page
+        pub(super) helper: RefCell<Option<Arc<engine::volume_files::VolumeHelper>>>,
+        /// the directory that is currently shown, relative to the volume
+        pub(super) path: RefCell<String>,
+        pub(super) files: RefCell<Vec<engine::dto::ContainerFile>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) volume: glib::WeakRef<model::Volume>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) error_status_page: TemplateChild<adw::StatusPage>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for VolumeFilesPage {
+        const NAME: &'static str = "PdsVolumeFilesPage";
+        type Type = super::VolumeFilesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_GO_UP, None, |widget, _, _| {
+                widget.go_up();
+            });
+            klass.install_action(ACTION_REFRESH, None, |widget, _, _| {
+                widget.load();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for VolumeFilesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.path.replace(String::from("/"));
+            self.window_title.set_subtitle("/");
+            obj.action_set_enabled(ACTION_GO_UP, false);
+            obj.action_set_enabled(ACTION_REFRESH, false);
+
+            obj.create_helper();
+        }
+
+        fn dispose(&self) {
+            // Dropping the helper removes its container.
+            self.helper.take();
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for VolumeFilesPage {}
+
+    #[gtk::template_callbacks]
+    impl VolumeFilesPage {
+        #[template_callback]
+        fn on_list_box_row_activated(&self, row: &gtk::ListBoxRow) {
+            let Some(file) = usize::try_from(row.index())
+                .ok()
+                .and_then(|index| self.files.borrow().get(index).cloned())
+            else {
+                return;
+            };
+
+            if file.is_dir {
+                let path = join_path(&self.path.borrow(), &file.name);
+                self.obj().navigate(path);
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct VolumeFilesPage(ObjectSubclass<imp::VolumeFilesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Volume> for VolumeFilesPage {
+    fn from(volume: &model::Volume) -> Self {
+        glib::Object::builder().property("volume", volume).build()
+    }
+}
+
+impl VolumeFilesPage {
+    fn create_helper(&self) {
+        let Some(volume) = self.volume() else {
+            return;
+        };
+        let Some(engine) = volume
+            .volume_list()
+            .and_then(|volume_list| volume_list.client())
+            .map(|client| engine::Engine::clone(&client.engine()))
+        else {
+            return;
+        };
+
+        rt::Promise::new({
+            let name = volume.name();
+            async move {
+                engine::volume_files::VolumeHelper::new(&engine, &name)
+                    .await
+                    .map(Arc::new)
+            }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok(helper) => {
+                    obj.imp().helper.replace(Some(helper));
+                    obj.action_set_enabled(ACTION_REFRESH, true);
+                    obj.load();
+                }
+                Err(e) => obj.set_files(Err(e)),
+            }
+        ));
+    }
+
+    fn navigate(&self, path: String) {
+        let imp = self.imp();
+
+        imp.window_title.set_subtitle(&path);
+        self.action_set_enabled(ACTION_GO_UP, path != "/");
+        imp.path.replace(path);
+
+        self.load();
+    }
+
+    fn go_up(&self) {
+        let parent = parent_path(&self.imp().path.borrow());
+        self.navigate(parent);
+    }
+
+    fn load(&self) {
+        let imp = self.imp();
+
+        let Some(helper) = imp.helper.borrow().clone() else {
+            return;
+        };
+
+        imp.stack.set_visible_child_name("loading");
+
+        let path = imp.path.borrow().clone();
+
+        rt::Promise::new({
+            let path = format!("{}{path}", engine::volume_files::VOLUME_MOUNT_PATH);
+            async move { helper.container().list_dir(path, false).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                // Ignore results of directories that have been left in the meantime.
+                if *obj.imp().path.borrow() == path {
+                    obj.set_files(result);
+                }
+            }
+        ));
+    }
+
+    fn set_files(&self, result: anyhow::Result<Vec<engine::dto::ContainerFile>>) {
+        let imp = self.imp();
+
+        imp.list_box.remove_all();
+
+        match result {
+            Ok(files) => {
+                files
+                    .iter()
+                    .for_each(|file| imp.list_box.append(&file_row(file)));
+
+                imp.stack
+                    .set_visible_child_name(if files.is_empty() { "empty" } else { "loaded" });
+                imp.files.replace(files);
+            }
+            Err(e) => {
+                log::error!("Error on listing volume directory: {e}");
+
+                imp.files.replace(Vec::new());
+                imp.error_status_page.set_description(Some(&e.to_string()));
+                imp.stack.set_visible_child_name("error");
+            }
+        }
+    }
+}
+
+fn file_row(file: &engine::dto::ContainerFile) -> adw::ActionRow {
+    let mut details = Vec::new();
+    if !file.is_dir {
+        details.push(glib::format_size(file.size).to_string());
+    }
+    details.push(file.permissions());
+    details.push(format!("{}:{}", file.user, file.group));
+    if let Some(modified) = utils::date_time_from_unix_local(file.modified) {
+        details.push(
+            modified
+                // Translators: This is a date time format (https://valadoc.org/glib-2.0/GLib.DateTime.format.html)
+                .format(&gettext("%x %X"))
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    let row = adw::ActionRow::builder()
+        .title(&file.name)
+        .subtitle(details.join(" · "))
+        .use_markup(false)
+        .activatable(file.is_dir)
+        .build();
+
+    row.add_prefix(&gtk::Image::from_icon_name(
+        if file.kind == engine::dto::ContainerFileKind::Symlink {
+            "emblem-symbolic-link-symbolic"
+        } else if file.is_dir {
+            "folder-symbolic"
+        } else {
+            "text-x-generic-symbolic"
+        },
+    ));
+    if file.is_dir {
+        row.add_suffix(&gtk::Image::from_icon_name("go-next-symbolic"));
+    }
+
+    row
+}
+
+fn join_path(dir: &str, name: &str) -> String {
+    if dir.ends_with('/') {
+        format!("{dir}{name}")
+    } else {
+        format!("{dir}/{name}")
+    }
+}
+
+fn parent_path(path: &str) -> String {
+    match path.trim_end_matches('/').rsplit_once('/') {
+        Some((parent, _)) if !parent.is_empty() => parent.to_owned(),
+        _ => String::from("/"),
+    }
+}
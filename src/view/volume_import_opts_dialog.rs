@@ -0,0 +1,162 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_IMPORT: &str = "volume-import-opts-dialog.import";
+const ACTION_SELECT_HOST_PATH: &str = "volume-import-opts-dialog.select-host-path";
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::VolumeImportOptsDialog)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/volume_import_opts_dialog.ui")]
+    pub(crate) struct VolumeImportOptsDialog {
+        #[property(get, set, construct_only)]
+        pub(super) volume: glib::WeakRef<model::Volume>,
+        #[property(get, set, construct_only)]
+        pub(super) host_path: OnceCell<String>,
+
+        #[template_child]
+        pub(super) host_path_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for VolumeImportOptsDialog {
+        const NAME: &'static str = "PdsVolumeImportOptsDialog";
+        type Type = super::VolumeImportOptsDialog;
+        type ParentType = adw::Dialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_IMPORT, None, |widget, _, _| {
+                widget.close_and_import();
+            });
+            klass.install_action_async(ACTION_SELECT_HOST_PATH, None, async |widget, _, _| {
+                widget.select_path().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for VolumeImportOptsDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            self.host_path_row.set_subtitle(&self.obj().host_path());
+
+            self.on_host_path_row_changed();
+        }
+    }
+
+    impl WidgetImpl for VolumeImportOptsDialog {}
+    impl AdwDialogImpl for VolumeImportOptsDialog {}
+
+    #[gtk::template_callbacks]
+    impl VolumeImportOptsDialog {
+        #[template_callback]
+        fn on_host_path_row_changed(&self) {
+            let enabled = self
+                .host_path_row
+                .subtitle()
+                .map(|s| !s.is_empty())
+                .unwrap_or(false);
+
+            self.obj().action_set_enabled(ACTION_IMPORT, enabled);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct VolumeImportOptsDialog(ObjectSubclass<imp::VolumeImportOptsDialog>)
+        @extends gtk::Widget, adw::Dialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::ShortcutManager;
+}
+
+impl VolumeImportOptsDialog {
+    pub(crate) fn new(volume: &model::Volume, host_path: &str) -> Self {
+        glib::Object::builder()
+            .property("volume", volume)
+            .property("host-path", host_path)
+            .build()
+    }
+
+    async fn select_path(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Volume Archive").as_str())
+            .filter(FileFilter::new("Tar Archive").mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        obj.imp().host_path_row.set_subtitle(path.to_str().unwrap());
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
+    fn close_and_import(&self) {
+        self.close();
+
+        let Some(volume) = self.volume() else {
+            return;
+        };
+
+        let Some(action_list) = volume
+            .volume_list()
+            .and_then(|volume_list| volume_list.client())
+            .map(|client| client.action_list())
+        else {
+            return;
+        };
+
+        let Some(host_path) = self.imp().host_path_row.subtitle() else {
+            return;
+        };
+
+        view::ActionDialog::from(&action_list.import_volume(&volume, host_path.as_str()))
+            .present(Some(self));
+    }
+}
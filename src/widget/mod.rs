@@ -4,6 +4,7 @@ mod circular_progress_bar;
 mod count_badge;
 mod date_time_row;
 mod main_menu_button;
+mod platform_entry_row;
 mod property_row;
 mod property_widget_row;
 mod random_name_entry_row;
@@ -23,6 +24,7 @@ export_gobjects![
     pub(crate) use self::date_time_row::DateTimeRow;
     pub(crate) use self::main_menu_button::MainMenuButton;
     pub(crate) use self::property_row::PropertyRow;
+    pub(crate) use self::platform_entry_row::PlatformEntryRow;
     pub(crate) use self::property_widget_row::PropertyWidgetRow;
     pub(crate) use self::random_name_entry_row::RandomNameEntryRow;
     pub(crate) use self::scalable_text_view::ScalableTextView;
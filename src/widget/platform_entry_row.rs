@@ -0,0 +1,87 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::engine;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/widget/platform_entry_row.ui")]
+    pub(crate) struct PlatformEntryRow;
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for PlatformEntryRow {
+        const NAME: &'static str = "PdsPlatformEntryRow";
+        type Type = super::PlatformEntryRow;
+        type ParentType = adw::EntryRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(
+                "platform-entry-row.select",
+                Some(glib::VariantTy::STRING),
+                move |widget, _, target| {
+                    widget.set_text(&target.unwrap().get::<String>().unwrap());
+                },
+            );
+            klass.install_action("platform-entry-row.clear", None, move |widget, _, _| {
+                widget.set_text("");
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for PlatformEntryRow {
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            obj.connect_changed(|obj| {
+                if obj.text().trim().is_empty() || obj.platform().is_some() {
+                    obj.remove_css_class("error");
+                } else {
+                    obj.add_css_class("error");
+                }
+            });
+        }
+    }
+
+    impl WidgetImpl for PlatformEntryRow {}
+    impl ListBoxRowImpl for PlatformEntryRow {}
+    impl PreferencesRowImpl for PlatformEntryRow {}
+    impl EntryRowImpl for PlatformEntryRow {}
+}
+
+glib::wrapper! {
+    pub(crate) struct PlatformEntryRow(ObjectSubclass<imp::PlatformEntryRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::EntryRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget, gtk::Editable;
+}
+
+impl Default for PlatformEntryRow {
+    fn default() -> Self {
+        glib::Object::new()
+    }
+}
+
+impl PlatformEntryRow {
+    /// Returns the entered platform or `None` if the text is empty or not a valid platform.
+    pub(crate) fn platform(&self) -> Option<engine::opts::Platform> {
+        self.text().parse().ok()
+    }
+
+    pub(crate) fn set_platform(&self, platform: Option<&engine::opts::Platform>) {
+        self.set_text(
+            &platform
+                .map(engine::opts::Platform::to_string)
+                .unwrap_or_default(),
+        );
+    }
+}